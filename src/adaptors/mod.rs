@@ -4,6 +4,9 @@
 //! option. This file may not be copied, modified, or distributed
 //! except according to those terms.
 
+mod multi_interleave;
+#[cfg(feature = "use_std")]
+pub use self::multi_interleave::*;
 mod multi_product;
 #[cfg(feature = "use_std")]
 pub use self::multi_product::*;
@@ -13,6 +16,7 @@ use std::mem::replace;
 use std::iter::{Fuse, Peekable, FromIterator};
 use std::marker::PhantomData;
 use size_hint;
+use cons_tuples_impl::ConsTuples;
 
 macro_rules! clone_fields {
     ($name:ident, $base:expr, $($field:ident),+) => (
@@ -86,6 +90,12 @@ impl<I, J> Iterator for Interleave<I, J>
     }
 }
 
+#[cfg(feature = "nightly")]
+unsafe impl<I, J> ::std::iter::TrustedLen for Interleave<I, J>
+    where I: ::std::iter::TrustedLen,
+          J: ::std::iter::TrustedLen<Item = I::Item>
+{}
+
 /// An iterator adaptor that alternates elements from the two iterators until
 /// one of them runs out.
 ///
@@ -179,6 +189,74 @@ impl<I, J> Iterator for InterleaveShortest<I, J>
     }
 }
 
+/// An iterator adaptor that takes elements from two iterators in a repeating
+/// `a_weight:b_weight` pattern until both run out.
+///
+/// This iterator is *fused*.
+///
+/// See [`.interleave_weighted()`](../trait.Itertools.html#method.interleave_weighted)
+/// for more information.
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct InterleaveWeighted<I, J> {
+    a: Fuse<I>,
+    b: Fuse<J>,
+    a_weight: usize,
+    b_weight: usize,
+    /// Number of elements taken in the current turn
+    taken: usize,
+    phase: bool, // false ==> a, true ==> b
+}
+
+/// Create a new `InterleaveWeighted` iterator.
+pub fn interleave_weighted<I, J>(a: I, b: J, a_weight: usize, b_weight: usize)
+    -> InterleaveWeighted<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>
+{
+    assert!(a_weight != 0 && b_weight != 0,
+            "interleave_weighted: the weights must be non-zero");
+    InterleaveWeighted {
+        a: a.fuse(),
+        b: b.fuse(),
+        a_weight,
+        b_weight,
+        taken: 0,
+        phase: false,
+    }
+}
+
+impl<I, J> Iterator for InterleaveWeighted<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let weight = if self.phase { self.b_weight } else { self.a_weight };
+        if self.taken == weight {
+            self.phase = !self.phase;
+            self.taken = 0;
+        }
+        self.taken += 1;
+        if self.phase {
+            match self.b.next() {
+                None => self.a.next(),
+                r => r,
+            }
+        } else {
+            match self.a.next() {
+                None => self.b.next(),
+                r => r,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint::add(self.a.size_hint(), self.b.size_hint())
+    }
+}
+
 #[derive(Clone, Debug)]
 /// An iterator adaptor that allows putting back a single
 /// item to the front of the iterator.
@@ -282,6 +360,15 @@ pub struct Product<I, J>
     b_orig: J,
 }
 
+/// An iterator adaptor that iterates over the cartesian product of an
+/// iterator of tuples `I` and an iterator `J`, yielding flat tuples.
+///
+/// Iterator element type is `(A, B, ..., J::Item)`.
+///
+/// See [`.cartesian_product_flat()`](../trait.Itertools.html#method.cartesian_product_flat)
+/// for more information.
+pub type ProductFlat<I, J> = ConsTuples<Product<I, J>, (<I as Iterator>::Item, <J as Iterator>::Item)>;
+
 /// Create a new cartesian product iterator
 ///
 /// Iterator element type is `(I::Item, J::Item)`.
@@ -298,6 +385,38 @@ pub fn cartesian_product<I, J>(mut i: I, j: J) -> Product<I, J>
     }
 }
 
+impl<I, J> Product<I, J>
+    where I: Iterator,
+          J: Clone + Iterator,
+{
+    /// Skip the remaining pairs of the current row, i.e. those with the
+    /// same left element as the pair last returned by `.next()`.
+    ///
+    /// If no pair has been returned yet, the first row is skipped.
+    /// This lets a search that rejects a left element stop enumerating
+    /// its pairs early.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut it = (0..3).cartesian_product(0..3);
+    /// let mut found = Vec::new();
+    /// while let Some((a, b)) = it.next() {
+    ///     if a == 1 {
+    ///         // nothing to find in this row
+    ///         it.skip_row();
+    ///         continue;
+    ///     }
+    ///     found.push((a, b));
+    /// }
+    /// assert_eq!(found, vec![(0, 0), (0, 1), (0, 2), (2, 0), (2, 1), (2, 2)]);
+    /// ```
+    pub fn skip_row(&mut self) {
+        self.b = self.b_orig.clone();
+        self.a_cur = self.a.next();
+    }
+}
+
 
 impl<I, J> Iterator for Product<I, J>
     where I: Iterator,
@@ -463,6 +582,78 @@ impl<I> ExactSizeIterator for Step<I>
     where I: ExactSizeIterator
 {}
 
+/// An iterator adaptor that skips a number of elements of the base iterator,
+/// then yields every `step`-th element.
+///
+/// See [`.stride()`](../trait.Itertools.html#method.stride) for more information.
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct Stride<I> {
+    iter: Fuse<I>,
+    /// The number of elements to skip before the next one to yield
+    skip: usize,
+    step: usize,
+}
+
+/// Create a `Stride` iterator.
+///
+/// **Panics** if the step is 0.
+pub fn stride<I>(iter: I, offset: usize, step: usize) -> Stride<I>
+    where I: Iterator
+{
+    assert!(step != 0, "stride: the step must be non-zero");
+    Stride {
+        iter: iter.fuse(),
+        skip: offset,
+        step,
+    }
+}
+
+impl<I> Iterator for Stride<I>
+    where I: Iterator
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        let elt = self.iter.nth(self.skip);
+        self.skip = self.step - 1;
+        elt
+    }
+
+    fn nth(&mut self, n: usize) -> Option<I::Item> {
+        // skip the elements of the n strides at once, if it doesn't overflow
+        match n.checked_mul(self.step).and_then(|x| x.checked_add(self.skip)) {
+            Some(skip) => {
+                self.skip = skip;
+                self.next()
+            }
+            None => {
+                for _ in 0..n {
+                    self.next()?;
+                }
+                self.next()
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.iter.size_hint();
+        let div = |x: usize| {
+            if x <= self.skip {
+                0
+            } else {
+                1 + (x - self.skip - 1) / self.step
+            }
+        };
+        (div(low), high.map(div))
+    }
+}
+
+impl<I> ExactSizeIterator for Stride<I>
+    where I: ExactSizeIterator
+{}
+
 pub trait MergePredicate<T> {
     fn merge_pred(&mut self, a: &T, b: &T) -> bool;
 }
@@ -848,6 +1039,61 @@ impl<'a, I, F> Iterator for TakeWhileRef<'a, I, F>
     }
 }
 
+/// An iterator adaptor that takes items while the predicate returns `true`,
+/// and the first item for which it returns `false`.
+///
+/// See [`.take_while_inclusive()`](../trait.Itertools.html#method.take_while_inclusive)
+/// for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct TakeWhileInclusive<I, F> {
+    iter: I,
+    predicate: F,
+    done: bool,
+}
+
+impl<I, F> fmt::Debug for TakeWhileInclusive<I, F>
+    where I: Iterator + fmt::Debug,
+{
+    debug_fmt_fields!(TakeWhileInclusive, iter, done);
+}
+
+/// Create a new `TakeWhileInclusive` from an iterator and a predicate.
+pub fn take_while_inclusive<I, F>(iter: I, predicate: F) -> TakeWhileInclusive<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> bool
+{
+    TakeWhileInclusive { iter, predicate, done: false }
+}
+
+impl<I, F> Iterator for TakeWhileInclusive<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> bool
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.done {
+            return None;
+        }
+        let elt = self.iter.next();
+        let keep = match elt {
+            Some(ref elt) => (self.predicate)(elt),
+            None => false,
+        };
+        self.done = !keep;
+        elt
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
 /// An iterator adaptor that filters `Option<A>` iterator elements
 /// and produces `A`. Stops on the first `None` encountered.
 ///
@@ -1068,6 +1314,13 @@ where
     I::Item: Into<R>,
 {}
 
+#[cfg(feature = "nightly")]
+unsafe impl<I, R> ::std::iter::TrustedLen for MapInto<I, R>
+where
+    I: ::std::iter::TrustedLen,
+    I::Item: Into<R>,
+{}
+
 /// An iterator adapter to apply a transformation within a nested `Result`.
 ///
 /// See [`.map_results()`](../trait.Itertools.html#method.map_results) for more information.
@@ -1117,6 +1370,75 @@ impl<I, F, T, U, E> Iterator for MapResults<I, F>
     }
 }
 
+#[cfg(feature = "nightly")]
+unsafe impl<I, F, T, U, E> ::std::iter::TrustedLen for MapResults<I, F>
+    where I: ::std::iter::TrustedLen<Item = Result<T, E>>,
+          F: FnMut(T) -> U,
+{}
+
+/// An iterator adapter to flatten the iterables produced from the `Ok` values
+/// of a `Result` iterator.
+///
+/// See [`.flat_map_ok()`](../trait.Itertools.html#method.flat_map_ok) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct FlatMapOk<I, F, J>
+    where J: IntoIterator
+{
+    iter: I,
+    f: F,
+    inner: Option<J::IntoIter>,
+}
+
+/// Create a new `FlatMapOk` iterator.
+pub fn flat_map_ok<I, F, T, J, E>(iter: I, f: F) -> FlatMapOk<I, F, J>
+    where I: Iterator<Item = Result<T, E>>,
+          F: FnMut(T) -> J,
+          J: IntoIterator,
+{
+    FlatMapOk {
+        iter,
+        f,
+        inner: None,
+    }
+}
+
+impl<I, F, T, J, E> Iterator for FlatMapOk<I, F, J>
+    where I: Iterator<Item = Result<T, E>>,
+          F: FnMut(T) -> J,
+          J: IntoIterator,
+{
+    type Item = Result<J::Item, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ref mut inner) = self.inner {
+                if let Some(x) = inner.next() {
+                    return Some(Ok(x));
+                }
+            }
+            match self.iter.next() {
+                Some(Ok(v)) => self.inner = Some((self.f)(v).into_iter()),
+                Some(Err(e)) => {
+                    self.inner = None;
+                    return Some(Err(e));
+                }
+                None => {
+                    self.inner = None;
+                    return None;
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.inner.as_ref().map_or((0, Some(0)), |inner| inner.size_hint());
+        match self.iter.size_hint() {
+            (_, Some(0)) => (lo, hi),
+            _ => (lo, None),
+        }
+    }
+}
+
 /// An iterator adapter to get the positions of each element that matches a predicate.
 ///
 /// See [`.positions()`](../trait.Itertools.html#method.positions) for more information.
@@ -1235,6 +1557,13 @@ where
     F: FnMut(&mut I::Item),
 {}
 
+#[cfg(feature = "nightly")]
+unsafe impl<I, F> ::std::iter::TrustedLen for Update<I, F>
+where
+    I: ::std::iter::TrustedLen,
+    F: FnMut(&mut I::Item),
+{}
+
 impl<I, F> DoubleEndedIterator for Update<I, F>
 where
     I: DoubleEndedIterator,
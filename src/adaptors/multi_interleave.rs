@@ -0,0 +1,201 @@
+#![cfg(feature = "use_std")]
+
+use std::iter::Fuse;
+
+use size_hint;
+
+/// An iterator adaptor that takes one element from each of multiple
+/// iterators in turn, until all of them run out.
+///
+/// This iterator is *fused*.
+///
+/// See [`.multi_interleave()`](../trait.Itertools.html#method.multi_interleave)
+/// for more information.
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct MultiInterleave<I> {
+    /// The iterators that have not run out yet, in their original order
+    iters: Vec<I>,
+    /// Index of the iterator to take the next element from
+    index: usize,
+}
+
+/// Create a new `MultiInterleave` iterator.
+pub fn multi_interleave<H>(iters: H) -> MultiInterleave<<H::Item as IntoIterator>::IntoIter>
+    where H: Iterator,
+          H::Item: IntoIterator,
+{
+    MultiInterleave {
+        iters: iters.map(|i| i.into_iter()).collect(),
+        index: 0,
+    }
+}
+
+impl<I> Iterator for MultiInterleave<I>
+    where I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.iters.is_empty() {
+            match self.iters[self.index].next() {
+                None => {
+                    self.iters.remove(self.index);
+                    if self.index == self.iters.len() {
+                        self.index = 0;
+                    }
+                }
+                elt => {
+                    self.index = (self.index + 1) % self.iters.len();
+                    return elt;
+                }
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iters.iter()
+                  .map(|iter| iter.size_hint())
+                  .fold((0, Some(0)), size_hint::add)
+    }
+}
+
+/// An iterator adaptor that takes one element from each of multiple
+/// iterators in turn, until one of them runs out.
+///
+/// This iterator is *fused*.
+///
+/// See [`.multi_interleave_shortest()`](../trait.Itertools.html#method.multi_interleave_shortest)
+/// for more information.
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct MultiInterleaveShortest<I> {
+    iters: Vec<I>,
+    /// Index of the iterator to take the next element from
+    index: usize,
+    done: bool,
+}
+
+/// Create a new `MultiInterleaveShortest` iterator.
+pub fn multi_interleave_shortest<H>(iters: H)
+    -> MultiInterleaveShortest<<H::Item as IntoIterator>::IntoIter>
+    where H: Iterator,
+          H::Item: IntoIterator,
+{
+    let iters: Vec<_> = iters.map(|i| i.into_iter()).collect();
+    MultiInterleaveShortest {
+        done: iters.is_empty(),
+        iters,
+        index: 0,
+    }
+}
+
+impl<I> Iterator for MultiInterleaveShortest<I>
+    where I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.iters[self.index].next() {
+            None => {
+                self.done = true;
+                None
+            }
+            elt => {
+                self.index = (self.index + 1) % self.iters.len();
+                elt
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        // The iterator `k` turns after the current one runs out after
+        // `k + len * n` elements, if it has `len` elements left.
+        let n = self.iters.len();
+        let mut lower = !0;
+        let mut upper = None;
+        for k in 0..n {
+            let (lo, hi) = self.iters[(self.index + k) % n].size_hint();
+            let end = |len: usize| len.checked_mul(n).and_then(|x| x.checked_add(k));
+            lower = ::std::cmp::min(lower, end(lo).unwrap_or(!0));
+            if let Some(hi) = hi.and_then(end) {
+                upper = Some(upper.map_or(hi, |upper: usize| ::std::cmp::min(upper, hi)));
+            }
+        }
+        (lower, upper)
+    }
+}
+
+/// An iterator adaptor that takes elements from multiple iterators in turn,
+/// as many from each as its weight, until all of them run out.
+///
+/// This iterator is *fused*.
+///
+/// See [`.multi_interleave_weighted()`](../trait.Itertools.html#method.multi_interleave_weighted)
+/// for more information.
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct MultiInterleaveWeighted<I> {
+    iters: Vec<(Fuse<I>, usize)>,
+    /// Index of the iterator to take the next element from
+    index: usize,
+    /// Number of elements taken from it in the current turn
+    taken: usize,
+}
+
+/// Create a new `MultiInterleaveWeighted` iterator.
+pub fn multi_interleave_weighted<H, J>(iters: H)
+    -> MultiInterleaveWeighted<J::IntoIter>
+    where H: Iterator<Item = (J, usize)>,
+          J: IntoIterator,
+{
+    let iters: Vec<_> = iters.map(|(i, weight)| {
+        assert!(weight != 0, "multi_interleave_weighted: the weights must be non-zero");
+        (i.into_iter().fuse(), weight)
+    }).collect();
+    MultiInterleaveWeighted {
+        iters,
+        index: 0,
+        taken: 0,
+    }
+}
+
+impl<I> Iterator for MultiInterleaveWeighted<I>
+    where I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.iters.len();
+        if n == 0 {
+            return None;
+        }
+        // Going around once more than the number of iterators is enough to
+        // come back to the current one after its turn ended.
+        for _ in 0..n + 1 {
+            let (ref mut iter, weight) = self.iters[self.index];
+            if self.taken < weight {
+                if let Some(elt) = iter.next() {
+                    self.taken += 1;
+                    return Some(elt);
+                }
+            }
+            self.index = (self.index + 1) % n;
+            self.taken = 0;
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iters.iter()
+                  .map(|pair| pair.0.size_hint())
+                  .fold((0, Some(0)), size_hint::add)
+    }
+}
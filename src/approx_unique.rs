@@ -0,0 +1,55 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// Estimate the number of distinct elements of `iter` with HyperLogLog,
+/// using `2^precision` one-byte registers.
+///
+/// See [`.approx_unique_count()`](../trait.Itertools.html#method.approx_unique_count)
+/// for more information.
+pub fn approx_unique_count<I>(iter: I, precision: u8) -> usize
+    where I: Iterator,
+          I::Item: Hash,
+{
+    assert!(precision >= 4, "approx_unique_count: precision {} is below 4", precision);
+    assert!(precision <= 16, "approx_unique_count: precision {} is above 16", precision);
+    let p = precision as u32;
+    let m = 1usize << p;
+    let mut registers = vec![0u8; m];
+
+    for elt in iter {
+        let mut hasher = DefaultHasher::new();
+        elt.hash(&mut hasher);
+        let hash = hasher.finish();
+        let index = (hash >> (64 - p)) as usize;
+        // the set guard bit bounds the rank by 64 - p + 1
+        let rest = (hash << p) | (1 << (p - 1));
+        let rank = rest.leading_zeros() as u8 + 1;
+        if registers[index] < rank {
+            registers[index] = rank;
+        }
+    }
+
+    let mf = m as f64;
+    let alpha = match m {
+        16 => 0.673,
+        32 => 0.697,
+        64 => 0.709,
+        _ => 0.7213 / (1. + 1.079 / mf),
+    };
+    let mut sum = 0.;
+    let mut zeros = 0;
+    for &r in &registers {
+        sum += 1. / (1u64 << r) as f64;
+        if r == 0 {
+            zeros += 1;
+        }
+    }
+    let estimate = alpha * mf * mf / sum;
+    // small range correction: linear counting is more accurate
+    let estimate = if estimate <= 2.5 * mf && zeros > 0 {
+        mf * (mf / zeros as f64).ln()
+    } else {
+        estimate
+    };
+    estimate.round() as usize
+}
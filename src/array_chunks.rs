@@ -0,0 +1,93 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use array_windows::ArrayCollect;
+
+/// An iterator that groups the items in arrays of a specific size.
+///
+/// See [`.array_chunks()`](../trait.Itertools.html#method.array_chunks) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ArrayChunks<I, A>
+    where I: Iterator
+{
+    iter: I,
+    /// The elements of the next chunk, and of the incomplete last chunk
+    /// once the iterator is exhausted
+    buf: Vec<I::Item>,
+    _array: PhantomData<A>,
+}
+
+impl<I, A> Clone for ArrayChunks<I, A>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        ArrayChunks {
+            iter: self.iter.clone(),
+            buf: self.buf.clone(),
+            _array: PhantomData,
+        }
+    }
+}
+
+impl<I, A> fmt::Debug for ArrayChunks<I, A>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ArrayChunks")
+            .field("iter", &self.iter)
+            .field("remainder", &self.remainder())
+            .finish()
+    }
+}
+
+/// Create a new `ArrayChunks` iterator.
+pub fn array_chunks<I, A>(iter: I) -> ArrayChunks<I, A>
+    where I: Iterator,
+          A: ArrayCollect<Item = I::Item>,
+{
+    assert!(A::LEN != 0, "array_chunks: the chunk size must be non-zero");
+    ArrayChunks {
+        iter,
+        buf: Vec::with_capacity(A::LEN),
+        _array: PhantomData,
+    }
+}
+
+impl<I, A> ArrayChunks<I, A>
+    where I: Iterator,
+{
+    /// Return the elements of the incomplete last chunk, left when the
+    /// iterator was exhausted.
+    ///
+    /// The slice is empty until `.next()` returned `None`, or if the number
+    /// of elements was a multiple of the chunk size.
+    pub fn remainder(&self) -> &[I::Item] {
+        &self.buf
+    }
+}
+
+impl<I, A> Iterator for ArrayChunks<I, A>
+    where I: Iterator,
+          A: ArrayCollect<Item = I::Item>,
+{
+    type Item = A;
+
+    fn next(&mut self) -> Option<A> {
+        let missing = A::LEN - self.buf.len();
+        self.buf.extend(self.iter.by_ref().take(missing));
+        if self.buf.len() < A::LEN {
+            return None;
+        }
+        A::collect_from_iter(self.buf.drain(..))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = A::LEN;
+        let (lower, upper) = self.iter.size_hint();
+        let buffered = self.buf.len();
+        (lower.saturating_add(buffered) / n,
+         upper.and_then(|upper| upper.checked_add(buffered)).map(|upper| upper / n))
+    }
+}
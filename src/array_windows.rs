@@ -0,0 +1,153 @@
+use size_hint;
+
+/// An iterator over all contiguous windows that produces arrays of a specific size.
+///
+/// See [`.array_windows()`](../trait.Itertools.html#method.array_windows) for more
+/// information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct ArrayWindows<I, A> {
+    iter: I,
+    /// The last window, or `None` if the iterator is shorter than a window
+    last: Option<A>,
+    /// Whether `last` is the first window, not yielded yet
+    first: bool,
+}
+
+/// Collect an array from the elements of an iterator.
+///
+/// Implemented for arrays `[T; N]` of up to 32 elements.
+pub trait ArrayCollect: Sized + AsRef<[<Self as ArrayCollect>::Item]>
+                               + AsMut<[<Self as ArrayCollect>::Item]>
+{
+    type Item;
+
+    /// The number of elements of the array.
+    const LEN: usize;
+
+    /// Return the array of the next `N` elements of `iter`, or `None` if
+    /// it has fewer.
+    fn collect_from_iter<I>(iter: I) -> Option<Self>
+        where I: IntoIterator<Item = Self::Item>;
+}
+
+macro_rules! impl_array_collect {
+    ($N:expr; $($x:ident)*) => (
+        impl<T> ArrayCollect for [T; $N] {
+            type Item = T;
+
+            const LEN: usize = $N;
+
+            #[allow(unused_mut, unused_variables)]
+            fn collect_from_iter<I>(iter: I) -> Option<Self>
+                where I: IntoIterator<Item = T>
+            {
+                let mut iter = iter.into_iter();
+                $(
+                    let $x = iter.next()?;
+                )*
+                Some([$($x),*])
+            }
+        }
+    );
+}
+
+impl_array_collect!(0;);
+impl_array_collect!(1; a0);
+impl_array_collect!(2; a0 a1);
+impl_array_collect!(3; a0 a1 a2);
+impl_array_collect!(4; a0 a1 a2 a3);
+impl_array_collect!(5; a0 a1 a2 a3 a4);
+impl_array_collect!(6; a0 a1 a2 a3 a4 a5);
+impl_array_collect!(7; a0 a1 a2 a3 a4 a5 a6);
+impl_array_collect!(8; a0 a1 a2 a3 a4 a5 a6 a7);
+impl_array_collect!(9; a0 a1 a2 a3 a4 a5 a6 a7 a8);
+impl_array_collect!(10; a0 a1 a2 a3 a4 a5 a6 a7 a8 a9);
+impl_array_collect!(11; a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10);
+impl_array_collect!(12; a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11);
+impl_array_collect!(13; a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12);
+impl_array_collect!(14; a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13);
+impl_array_collect!(15; a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14);
+impl_array_collect!(16; a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15);
+impl_array_collect!(17; a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16);
+impl_array_collect!(18; a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16 a17);
+impl_array_collect!(19; a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16 a17 a18);
+impl_array_collect!(20; a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16 a17 a18 a19);
+impl_array_collect!(21; a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16 a17 a18 a19 a20);
+impl_array_collect!(22; a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15
+                     a16 a17 a18 a19 a20 a21);
+impl_array_collect!(23; a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15
+                     a16 a17 a18 a19 a20 a21 a22);
+impl_array_collect!(24; a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15
+                     a16 a17 a18 a19 a20 a21 a22 a23);
+impl_array_collect!(25; a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15
+                     a16 a17 a18 a19 a20 a21 a22 a23 a24);
+impl_array_collect!(26; a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15
+                     a16 a17 a18 a19 a20 a21 a22 a23 a24 a25);
+impl_array_collect!(27; a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15
+                     a16 a17 a18 a19 a20 a21 a22 a23 a24 a25 a26);
+impl_array_collect!(28; a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15
+                     a16 a17 a18 a19 a20 a21 a22 a23 a24 a25 a26 a27);
+impl_array_collect!(29; a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15
+                     a16 a17 a18 a19 a20 a21 a22 a23 a24 a25 a26 a27 a28);
+impl_array_collect!(30; a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15
+                     a16 a17 a18 a19 a20 a21 a22 a23 a24 a25 a26 a27 a28 a29);
+impl_array_collect!(31; a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15
+                     a16 a17 a18 a19 a20 a21 a22 a23 a24 a25 a26 a27 a28 a29 a30);
+impl_array_collect!(32; a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15
+                     a16 a17 a18 a19 a20 a21 a22 a23 a24 a25 a26 a27 a28 a29 a30 a31);
+
+/// Create a new `ArrayWindows` iterator.
+pub fn array_windows<I, A>(mut iter: I) -> ArrayWindows<I, A>
+    where I: Iterator,
+          A: ArrayCollect<Item = I::Item>,
+{
+    let last = A::collect_from_iter(&mut iter);
+    if let Some(ref last) = last {
+        assert!(!last.as_ref().is_empty(), "array_windows: the window size must be non-zero");
+    }
+    ArrayWindows {
+        iter,
+        last,
+        first: true,
+    }
+}
+
+impl<I, A> Iterator for ArrayWindows<I, A>
+    where I: Iterator,
+          A: Clone + AsRef<[I::Item]> + AsMut<[I::Item]>,
+{
+    type Item = A;
+
+    fn next(&mut self) -> Option<A> {
+        let last = self.last.as_mut()?;
+        if self.first {
+            self.first = false;
+            return Some(last.clone());
+        }
+        let new = self.iter.next()?;
+        // shift the elements left and push the new one
+        {
+            let window = last.as_mut();
+            let n = window.len();
+            for i in 1..n {
+                window.swap(i - 1, i);
+            }
+            window[n - 1] = new;
+        }
+        Some(last.clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.last.is_some() {
+            size_hint::add_scalar(self.iter.size_hint(), self.first as usize)
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
+impl<I, A> ExactSizeIterator for ArrayWindows<I, A>
+    where I: ExactSizeIterator,
+          A: Clone + AsRef<[I::Item]> + AsMut<[I::Item]>,
+{}
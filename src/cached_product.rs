@@ -0,0 +1,98 @@
+use std::iter::Fuse;
+
+use size_hint;
+
+/// An iterator adaptor that iterates over the cartesian product of
+/// the element sets of two iterators `I` and `J`, caching the elements of
+/// `J` instead of cloning the iterator.
+///
+/// Iterator element type is `(I::Item, J::Item)`.
+///
+/// See [`.cartesian_product_cached()`](../trait.Itertools.html#method.cartesian_product_cached)
+/// for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct CachedProduct<I, J>
+    where I: Iterator,
+          J: Iterator,
+{
+    a: I,
+    a_cur: Option<I::Item>,
+    /// Read during the first row only
+    b: Fuse<J>,
+    b_cache: Vec<J::Item>,
+    /// Position in `b_cache` after the first row
+    b_index: usize,
+    first_row: bool,
+}
+
+/// Create a new `CachedProduct` iterator.
+pub fn cartesian_product_cached<I, J>(mut i: I, j: J) -> CachedProduct<I, J>
+    where I: Iterator,
+          J: Iterator,
+          I::Item: Clone,
+          J::Item: Clone,
+{
+    CachedProduct {
+        a_cur: i.next(),
+        a: i,
+        b: j.fuse(),
+        b_cache: Vec::new(),
+        b_index: 0,
+        first_row: true,
+    }
+}
+
+impl<I, J> Iterator for CachedProduct<I, J>
+    where I: Iterator,
+          J: Iterator,
+          I::Item: Clone,
+          J::Item: Clone,
+{
+    type Item = (I::Item, J::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let elt_b = if self.a_cur.is_none() {
+            return None;
+        } else if self.first_row {
+            match self.b.next() {
+                Some(x) => {
+                    self.b_cache.push(x.clone());
+                    x
+                }
+                None => {
+                    self.first_row = false;
+                    self.b_index = self.b_cache.len();
+                    return self.next();
+                }
+            }
+        } else {
+            if self.b_index == self.b_cache.len() {
+                if self.b_cache.is_empty() {
+                    self.a_cur = None;
+                    return None;
+                }
+                self.b_index = 0;
+                self.a_cur = self.a.next();
+            }
+            self.b_index += 1;
+            self.b_cache[self.b_index - 1].clone()
+        };
+        self.a_cur.as_ref().map(|a| (a.clone(), elt_b))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.a_cur.is_none() {
+            return (0, Some(0));
+        }
+        if self.first_row {
+            let b_hint = self.b.size_hint();
+            let row = size_hint::add_scalar(b_hint, self.b_cache.len());
+            size_hint::add(b_hint, size_hint::mul(self.a.size_hint(), row))
+        } else {
+            let row = self.b_cache.len();
+            let current = (row - self.b_index, Some(row - self.b_index));
+            size_hint::add(current, size_hint::mul(self.a.size_hint(), (row, Some(row))))
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use size_hint;
+
+/// Which pairs of elements `(x[i], x[j])` a
+/// [`.cartesian_square_with()`](trait.Itertools.html#method.cartesian_square_with)
+/// iterator yields.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SquarePairs {
+    /// All pairs: the full cartesian product.
+    Ordered,
+    /// All pairs with `i != j`, skipping the diagonal.
+    OrderedDistinct,
+    /// The pairs with `i <= j`: each unordered pair once, and the diagonal.
+    Unordered,
+    /// The pairs with `i < j`: each unordered pair of distinct positions once.
+    UnorderedDistinct,
+}
+
+/// An iterator adaptor that iterates over pairs of elements of the same
+/// iterator.
+///
+/// Iterator element type is `(I::Item, I::Item)`.
+///
+/// See [`.cartesian_square_with()`](../trait.Itertools.html#method.cartesian_square_with)
+/// for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct CartesianSquare<I>
+    where I: Iterator
+{
+    outer: I,
+    orig: I,
+    /// The current left element and its index
+    cur: Option<(usize, I::Item)>,
+    /// The right elements to pair with the current left element
+    inner: I,
+    /// Index of the next element of `inner`
+    inner_index: usize,
+    outer_index: usize,
+    pairs: SquarePairs,
+}
+
+/// Create a new `CartesianSquare` iterator.
+pub fn cartesian_square<I>(iter: I, pairs: SquarePairs) -> CartesianSquare<I>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+{
+    CartesianSquare {
+        outer: iter.clone(),
+        orig: iter.clone(),
+        cur: None,
+        inner: iter,
+        inner_index: 0,
+        outer_index: 0,
+        pairs,
+    }
+}
+
+/// `n * (n + 1) / 2`, the number of pairs `i <= j` of `n` elements
+fn triangle(n: usize) -> Option<usize> {
+    if n % 2 == 0 {
+        (n / 2).checked_mul(n + 1)
+    } else {
+        n.checked_mul(n / 2 + 1)
+    }
+}
+
+impl<I> Iterator for CartesianSquare<I>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+{
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.cur.is_none() {
+                let rest = self.outer.clone();
+                let elt = self.outer.next()?;
+                let index = self.outer_index;
+                self.outer_index += 1;
+                match self.pairs {
+                    SquarePairs::Ordered | SquarePairs::OrderedDistinct => {
+                        self.inner = self.orig.clone();
+                        self.inner_index = 0;
+                    }
+                    SquarePairs::Unordered => {
+                        self.inner = rest;
+                        self.inner_index = index;
+                    }
+                    SquarePairs::UnorderedDistinct => {
+                        self.inner = self.outer.clone();
+                        self.inner_index = index + 1;
+                    }
+                }
+                self.cur = Some((index, elt));
+            }
+            match self.inner.next() {
+                Some(right) => {
+                    let j = self.inner_index;
+                    self.inner_index += 1;
+                    if let Some((i, ref left)) = self.cur {
+                        if i != j || self.pairs != SquarePairs::OrderedDistinct {
+                            return Some((left.clone(), right));
+                        }
+                    }
+                }
+                None => self.cur = None,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // pairs with the current left element
+        let mut current = match self.cur {
+            Some(_) => self.inner.size_hint(),
+            None => (0, Some(0)),
+        };
+        if let Some((i, _)) = self.cur {
+            if self.pairs == SquarePairs::OrderedDistinct && self.inner_index <= i {
+                current = size_hint::sub_scalar(current, 1);
+            }
+        }
+        // pairs with the left elements to come
+        let (lo, hi) = self.outer.size_hint();
+        let rest = match self.pairs {
+            SquarePairs::Ordered => size_hint::mul(self.outer.size_hint(), self.orig.size_hint()),
+            SquarePairs::OrderedDistinct => {
+                let others = size_hint::sub_scalar(self.orig.size_hint(), 1);
+                size_hint::mul(self.outer.size_hint(), others)
+            }
+            SquarePairs::Unordered => {
+                (triangle(lo).unwrap_or(!0), hi.and_then(triangle))
+            }
+            SquarePairs::UnorderedDistinct => {
+                let lo = lo.saturating_sub(1);
+                let hi = hi.map(|hi| hi.saturating_sub(1));
+                (triangle(lo).unwrap_or(!0), hi.and_then(triangle))
+            }
+        };
+        size_hint::add(current, rest)
+    }
+}
@@ -44,6 +44,27 @@ pub fn combinations<I>(iter: I, n: usize) -> Combinations<I>
     }
 }
 
+impl<I: Iterator> Combinations<I> {
+    /// The length of the combinations.
+    pub(crate) fn k(&self) -> usize {
+        self.n
+    }
+
+    /// The number of elements of the source buffered so far.
+    pub(crate) fn pool_len(&self) -> usize {
+        self.pool.len()
+    }
+
+    /// Restart the iteration with combinations of length `k`, reusing the
+    /// elements already buffered.
+    pub(crate) fn reset(&mut self, k: usize) {
+        self.n = k;
+        self.indices = (0..k).collect();
+        self.first = true;
+        while self.pool.len() < k && self.pool.get_next() {}
+    }
+}
+
 impl<I> Iterator for Combinations<I>
     where I: Iterator,
           I::Item: Clone
@@ -6,6 +6,7 @@ use super::lazy_buffer::LazyBuffer;
 ///
 /// See [`.combinations_with_replacement()`](../trait.Itertools.html#method.combinations_with_replacement) for more information.
 #[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 pub struct CombinationsWithReplacement<I>
 where
     I: Iterator,
@@ -24,7 +25,7 @@ where
     I: Iterator + fmt::Debug,
     I::Item: fmt::Debug + Clone,
 {
-    debug_fmt_fields!(Combinations, n, indices, max_index, pool, first);
+    debug_fmt_fields!(CombinationsWithReplacement, n, indices, max_index, pool, first);
 }
 
 impl<I> CombinationsWithReplacement<I>
@@ -1,16 +1,13 @@
 
 macro_rules! impl_cons_iter(
-    ($_A:ident, $_B:ident, ) => (); // stop
-
-    ($A:ident, $($B:ident,)*) => (
-        impl_cons_iter!($($B,)*);
+    ([$($B:ident)*] [$($X:ident)*]) => (
         #[allow(non_snake_case)]
-        impl<X, Iter, $($B),*> Iterator for ConsTuples<Iter, (($($B,)*), X)>
-            where Iter: Iterator<Item = (($($B,)*), X)>,
+        impl<Iter, $($B,)* $($X),*> Iterator for ConsTuples<Iter, (($($B,)*), $($X,)*)>
+            where Iter: Iterator<Item = (($($B,)*), $($X,)*)>,
         {
-            type Item = ($($B,)* X, );
+            type Item = ($($B,)* $($X,)*);
             fn next(&mut self) -> Option<Self::Item> {
-                self.iter.next().map(|(($($B,)*), x)| ($($B,)* x, ))
+                self.iter.next().map(|(($($B,)*), $($X,)*)| ($($B,)* $($X,)*))
             }
 
             fn size_hint(&self) -> (usize, Option<usize>) {
@@ -19,28 +16,48 @@ macro_rules! impl_cons_iter(
             fn fold<Acc, Fold>(self, accum: Acc, mut f: Fold) -> Acc
                 where Fold: FnMut(Acc, Self::Item) -> Acc,
             {
-                self.iter.fold(accum, move |acc, (($($B,)*), x)| f(acc, ($($B,)* x, )))
+                self.iter.fold(accum, move |acc, (($($B,)*), $($X,)*)| f(acc, ($($B,)* $($X,)*)))
             }
         }
 
         #[allow(non_snake_case)]
-        impl<X, Iter, $($B),*> DoubleEndedIterator for ConsTuples<Iter, (($($B,)*), X)>
-            where Iter: DoubleEndedIterator<Item = (($($B,)*), X)>,
+        impl<Iter, $($B,)* $($X),*> DoubleEndedIterator for ConsTuples<Iter, (($($B,)*), $($X,)*)>
+            where Iter: DoubleEndedIterator<Item = (($($B,)*), $($X,)*)>,
         {
             fn next_back(&mut self) -> Option<Self::Item> {
-                self.iter.next().map(|(($($B,)*), x)| ($($B,)* x, ))
+                self.iter.next_back().map(|(($($B,)*), $($X,)*)| ($($B,)* $($X,)*))
             }
         }
+    );
+);
 
+// Implement `ConsTuples` for each split of the flat tuple between the inner
+// tuple, of 2 elements or more, and the elements that follow it.
+macro_rules! impl_cons_iters(
+    (@inner [$($B:ident)*] []) => (); // stop
+    (@inner [$($B:ident)*] [$Y:ident $($Z:ident)*]) => (
+        impl_cons_iters!(@outer [$($B)*] [] [$Y $($Z)*]);
+        impl_cons_iters!(@inner [$($B)* $Y] [$($Z)*]);
+    );
+    (@outer [$($B:ident)*] [$($X:ident)*] []) => (); // stop
+    (@outer [$($B:ident)*] [$($X:ident)*] [$Y:ident $($Z:ident)*]) => (
+        impl_cons_iter!([$($B)*] [$($X)* $Y]);
+        impl_cons_iters!(@outer [$($B)*] [$($X)* $Y] [$($Z)*]);
     );
 );
 
-impl_cons_iter!(A, B, C, D, E, F, G, H,);
+impl_cons_iters!(@inner [A B] [C D E F G H I J K L]);
 
 /// An iterator that maps an iterator of tuples like
-/// `((A, B), C)` to an iterator of `(A, B, C)`.
+/// `((A, B), C)` or `((A, B), C, D)` to an iterator of `(A, B, C)` or
+/// `(A, B, C, D)`.
+///
+/// The inner tuple is the first element, and the flat tuples produced have
+/// up to 12 elements.
 ///
 /// Used by the `iproduct!()` macro.
+///
+/// See [`.cons_tuples()`](../trait.Itertools.html#method.cons_tuples) for more information.
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 #[derive(Debug)]
 pub struct ConsTuples<I, J>
@@ -59,10 +76,23 @@ impl<I, J> Clone for ConsTuples<I, J>
     }
 }
 
+impl<I, J> ExactSizeIterator for ConsTuples<I, J>
+    where I: ExactSizeIterator<Item=J>,
+          ConsTuples<I, J>: Iterator,
+{}
+
+#[cfg(feature = "nightly")]
+unsafe impl<I, J> ::std::iter::TrustedLen for ConsTuples<I, J>
+    where I: ::std::iter::TrustedLen<Item=J>,
+          ConsTuples<I, J>: Iterator,
+{}
+
 /// Create an iterator that maps for example iterators of
 /// `((A, B), C)` to `(A, B, C)`.
-pub fn cons_tuples<I, J>(iterable: I) -> ConsTuples<I, J>
-    where I: Iterator<Item=J>
+///
+/// See [`.cons_tuples()`](trait.Itertools.html#method.cons_tuples) for more information.
+pub fn cons_tuples<I, J>(iterable: I) -> ConsTuples<I::IntoIter, J>
+    where I: IntoIterator<Item=J>
 {
     ConsTuples { iter: iterable.into_iter() }
 }
@@ -0,0 +1,79 @@
+use std::fmt;
+use std::iter::Sum;
+use std::ops::Add;
+
+/// An iterator adaptor that yields the successive accumulator values of a
+/// fold.
+///
+/// See [`.cumulative_fold()`](../trait.Itertools.html#method.cumulative_fold) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct CumulativeFold<I, B, F> {
+    iter: I,
+    /// Always `Some`, but moved out while `f` is called
+    acc: Option<B>,
+    f: F,
+}
+
+impl<I, B, F> fmt::Debug for CumulativeFold<I, B, F>
+    where I: fmt::Debug,
+          B: fmt::Debug,
+{
+    debug_fmt_fields!(CumulativeFold, iter, acc);
+}
+
+/// An iterator adaptor that yields the running sums of the elements.
+///
+/// See [`.cumsum()`](../trait.Itertools.html#method.cumsum) for more information.
+pub type CumSum<I> = CumulativeFold<I, <I as Iterator>::Item,
+                                    fn(<I as Iterator>::Item, <I as Iterator>::Item) -> <I as Iterator>::Item>;
+
+/// Create a new `CumulativeFold` iterator.
+pub fn cumulative_fold<I, B, F>(iter: I, init: B, f: F) -> CumulativeFold<I, B, F>
+    where I: Iterator,
+          B: Clone,
+          F: FnMut(B, I::Item) -> B,
+{
+    CumulativeFold {
+        iter,
+        acc: Some(init),
+        f,
+    }
+}
+
+/// Create a new `CumSum` iterator.
+pub fn cumsum<I>(iter: I) -> CumSum<I>
+    where I: Iterator,
+          I::Item: Clone + Add<Output = I::Item> + Sum,
+{
+    fn add<T: Add<Output = T>>(acc: T, elt: T) -> T {
+        acc + elt
+    }
+    let zero = None.into_iter().sum();
+    cumulative_fold(iter, zero, add)
+}
+
+impl<I, B, F> Iterator for CumulativeFold<I, B, F>
+    where I: Iterator,
+          B: Clone,
+          F: FnMut(B, I::Item) -> B,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<B> {
+        let elt = self.iter.next()?;
+        let acc = self.acc.take().map(|acc| (self.f)(acc, elt));
+        self.acc = acc.clone();
+        acc
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, B, F> ExactSizeIterator for CumulativeFold<I, B, F>
+    where I: ExactSizeIterator,
+          B: Clone,
+          F: FnMut(B, I::Item) -> B,
+{}
@@ -0,0 +1,85 @@
+use std::fmt;
+use std::iter::Fuse;
+use std::ops::Sub;
+
+use size_hint;
+
+/// An iterator adaptor that maps each pair of consecutive elements with a
+/// closure.
+///
+/// See [`.diffs_by()`](../trait.Itertools.html#method.diffs_by) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct DiffsBy<I, F>
+    where I: Iterator
+{
+    iter: Fuse<I>,
+    prev: Option<I::Item>,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for DiffsBy<I, F>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(DiffsBy, iter, prev);
+}
+
+/// An iterator adaptor that yields the differences between consecutive
+/// elements.
+///
+/// See [`.diffs()`](../trait.Itertools.html#method.diffs) for more information.
+pub type Diffs<I, D> = DiffsBy<I, fn(&<I as Iterator>::Item, &<I as Iterator>::Item) -> D>;
+
+/// Create a new `DiffsBy` iterator.
+pub fn diffs_by<I, F, R>(iter: I, f: F) -> DiffsBy<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item, &I::Item) -> R,
+{
+    DiffsBy {
+        iter: iter.fuse(),
+        prev: None,
+        f,
+    }
+}
+
+/// Create a new `Diffs` iterator.
+pub fn diffs<I, D>(iter: I) -> Diffs<I, D>
+    where I: Iterator,
+          for<'a> &'a I::Item: Sub<Output = D>,
+{
+    fn sub<T, D>(prev: &T, next: &T) -> D
+        where for<'a> &'a T: Sub<Output = D>,
+    {
+        next - prev
+    }
+    diffs_by(iter, sub)
+}
+
+impl<I, F, R> Iterator for DiffsBy<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item, &I::Item) -> R,
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<R> {
+        let prev = match self.prev.take() {
+            Some(prev) => prev,
+            None => self.iter.next()?,
+        };
+        let next = self.iter.next()?;
+        let diff = (self.f)(&prev, &next);
+        self.prev = Some(next);
+        Some(diff)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let sh = size_hint::add_scalar(self.iter.size_hint(), self.prev.is_some() as usize);
+        size_hint::sub_scalar(sh, 1)
+    }
+}
+
+impl<I, F, R> ExactSizeIterator for DiffsBy<I, F>
+    where I: ExactSizeIterator,
+          F: FnMut(&I::Item, &I::Item) -> R,
+{}
@@ -0,0 +1,61 @@
+use std::collections::VecDeque;
+use std::iter::Fuse;
+
+use size_hint;
+
+/// An iterator adaptor that yields all but the last `n` elements of the
+/// underlying iterator.
+///
+/// See [`.drop_last()`](../trait.Itertools.html#method.drop_last) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct DropLast<I>
+    where I: Iterator
+{
+    iter: Fuse<I>,
+    buf: VecDeque<I::Item>,
+    n: usize,
+}
+
+/// Create a new `DropLast` iterator.
+pub fn drop_last<I>(iter: I, n: usize) -> DropLast<I>
+    where I: Iterator
+{
+    DropLast {
+        iter: iter.fuse(),
+        buf: VecDeque::new(),
+        n,
+    }
+}
+
+impl<I> Iterator for DropLast<I>
+    where I: Iterator
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Keep `n` elements buffered ahead of the one we yield
+        while self.buf.len() < self.n {
+            match self.iter.next() {
+                Some(x) => self.buf.push_back(x),
+                None => return None,
+            }
+        }
+        match self.iter.next() {
+            Some(x) => {
+                self.buf.push_back(x);
+                self.buf.pop_front()
+            }
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let sh = size_hint::add_scalar(self.iter.size_hint(), self.buf.len());
+        size_hint::sub_scalar(sh, self.n)
+    }
+}
+
+impl<I> ExactSizeIterator for DropLast<I>
+    where I: ExactSizeIterator
+{}
@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+/// An iterator adapter to find the positions of repeated elements.
+///
+/// Iterator element type is `(usize, usize, I::Item)`.
+///
+/// See [`.duplicate_positions()`](../trait.Itertools.html#method.duplicate_positions)
+/// for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct DuplicatePositions<I>
+    where I: Iterator
+{
+    iter: I,
+    /// The index of the first occurrence of each element seen so far.
+    first_seen: HashMap<I::Item, usize>,
+    index: usize,
+}
+
+impl<I> Clone for DuplicatePositions<I>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        DuplicatePositions {
+            iter: self.iter.clone(),
+            first_seen: self.first_seen.clone(),
+            index: self.index,
+        }
+    }
+}
+
+impl<I> fmt::Debug for DuplicatePositions<I>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug + Hash + Eq,
+{
+    debug_fmt_fields!(DuplicatePositions, iter, first_seen, index);
+}
+
+/// Create a new `DuplicatePositions` iterator.
+pub fn duplicate_positions<I>(iter: I) -> DuplicatePositions<I>
+    where I: Iterator,
+          I::Item: Clone + Eq + Hash,
+{
+    DuplicatePositions {
+        iter,
+        first_seen: HashMap::new(),
+        index: 0,
+    }
+}
+
+impl<I> Iterator for DuplicatePositions<I>
+    where I: Iterator,
+          I::Item: Clone + Eq + Hash,
+{
+    type Item = (usize, usize, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for elt in &mut self.iter {
+            let index = self.index;
+            self.index += 1;
+            if let Some(&first) = self.first_seen.get(&elt) {
+                return Some((first, index, elt));
+            }
+            self.first_seen.insert(elt.clone(), index);
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, hi) = self.iter.size_hint();
+        (0, hi)
+    }
+}
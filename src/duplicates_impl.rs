@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::hash::Hash;
+use std::fmt;
+
+/// An iterator adapter to yield the elements whose key was already seen,
+/// once per key.
+///
+/// See [`.duplicates_by()`](../trait.Itertools.html#method.duplicates_by) for more information.
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct DuplicatesBy<I, K, F> {
+    iter: I,
+    /// For each key seen, whether an element with that key was yielded
+    used: HashMap<K, bool>,
+    f: F,
+}
+
+impl<I, K, F> fmt::Debug for DuplicatesBy<I, K, F>
+    where I: fmt::Debug,
+          K: fmt::Debug + Hash + Eq,
+{
+    debug_fmt_fields!(DuplicatesBy, iter, used);
+}
+
+/// Create a new `DuplicatesBy` iterator.
+pub fn duplicates_by<I, K, F>(iter: I, f: F) -> DuplicatesBy<I, K, F>
+    where I: Iterator,
+          K: Eq + Hash,
+          F: FnMut(&I::Item) -> K,
+{
+    DuplicatesBy {
+        iter,
+        used: HashMap::new(),
+        f,
+    }
+}
+
+impl<I, K, F> Iterator for DuplicatesBy<I, K, F>
+    where I: Iterator,
+          K: Eq + Hash,
+          F: FnMut(&I::Item) -> K,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        for v in &mut self.iter {
+            match self.used.entry((self.f)(&v)) {
+                Entry::Vacant(entry) => {
+                    entry.insert(false);
+                }
+                Entry::Occupied(mut entry) => {
+                    if !*entry.get() {
+                        entry.insert(true);
+                        return Some(v);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// An iterator adapter to yield the elements equal to an element already
+/// seen, once per distinct element.
+///
+/// See [`.duplicates()`](../trait.Itertools.html#method.duplicates) for more information.
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct Duplicates<I: Iterator> {
+    iter: I,
+    /// For each element seen, whether an element equal to it was yielded
+    used: HashMap<I::Item, bool>,
+}
+
+impl<I> fmt::Debug for Duplicates<I>
+    where I: Iterator + fmt::Debug,
+          I::Item: Hash + Eq + fmt::Debug,
+{
+    debug_fmt_fields!(Duplicates, iter, used);
+}
+
+/// Create a new `Duplicates` iterator.
+pub fn duplicates<I>(iter: I) -> Duplicates<I>
+    where I: Iterator,
+          I::Item: Eq + Hash,
+{
+    Duplicates {
+        iter,
+        used: HashMap::new(),
+    }
+}
+
+impl<I> Iterator for Duplicates<I>
+    where I: Iterator,
+          I::Item: Eq + Hash,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        for v in &mut self.iter {
+            // the first element is kept as the key, and the second one
+            // yielded, so that elements don't need to be cloned
+            let first_duplicate = match self.used.get_mut(&v) {
+                Some(yielded) => {
+                    let first_duplicate = !*yielded;
+                    *yielded = true;
+                    Some(first_duplicate)
+                }
+                None => None,
+            };
+            match first_duplicate {
+                Some(true) => return Some(v),
+                Some(false) => {}
+                None => {
+                    self.used.insert(v, false);
+                }
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
@@ -38,6 +38,47 @@ impl<A, B> EitherOrBoth<A, B> {
         }
     }
 
+    /// Converts into a pair of options: `Left(a)` becomes `(Some(a), None)`,
+    /// `Right(b)` becomes `(None, Some(b))` and `Both(a, b)` becomes
+    /// `(Some(a), Some(b))`.
+    ///
+    /// ```
+    /// use itertools::EitherOrBoth::{Both, Left};
+    /// use itertools::EitherOrBoth;
+    ///
+    /// assert_eq!(Left::<_, char>(1).into_options(), (Some(1), None));
+    /// assert_eq!(Both(1, 'a').into_options(), (Some(1), Some('a')));
+    /// ```
+    pub fn into_options(self) -> (Option<A>, Option<B>) {
+        match self {
+            Left(left) => (Some(left), None),
+            Right(right) => (None, Some(right)),
+            Both(left, right) => (Some(left), Some(right)),
+        }
+    }
+
+    /// Converts from a pair of options, the inverse of
+    /// [`into_options`](#method.into_options).
+    ///
+    /// Return `None` if both options are `None`.
+    ///
+    /// ```
+    /// use itertools::EitherOrBoth::{Both, Right};
+    /// use itertools::EitherOrBoth;
+    ///
+    /// assert_eq!(EitherOrBoth::from_options(None, Some('a')), Some(Right::<i32, _>('a')));
+    /// assert_eq!(EitherOrBoth::from_options(Some(1), Some('a')), Some(Both(1, 'a')));
+    /// assert_eq!(EitherOrBoth::<i32, char>::from_options(None, None), None);
+    /// ```
+    pub fn from_options(left: Option<A>, right: Option<B>) -> Option<Self> {
+        match (left, right) {
+            (Some(left), None) => Some(Left(left)),
+            (None, Some(right)) => Some(Right(right)),
+            (Some(left), Some(right)) => Some(Both(left, right)),
+            (None, None) => None,
+        }
+    }
+
     /// Converts from `&EitherOrBoth<A, B>` to `EitherOrBoth<&A, &B>`.
     pub fn as_ref(&self) -> EitherOrBoth<&A, &B> {
         match *self {
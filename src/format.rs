@@ -6,6 +6,8 @@ use std::cell::RefCell;
 /// The format value can only be formatted once, after that the iterator is
 /// exhausted.
 ///
+/// The flags of the outer formatter are applied to each element.
+///
 /// See [`.format_with()`](../trait.Itertools.html#method.format_with) for more information.
 pub struct FormatWith<'a, I, F> {
     sep: &'a str,
@@ -18,6 +20,8 @@ pub struct FormatWith<'a, I, F> {
 /// The format value can only be formatted once, after that the iterator is
 /// exhausted.
 ///
+/// The flags of the outer formatter are applied to each element.
+///
 /// See [`.format()`](../trait.Itertools.html#method.format)
 /// for more information.
 #[derive(Clone)]
@@ -27,6 +31,35 @@ pub struct Format<'a, I> {
     inner: RefCell<Option<I>>,
 }
 
+/// Format at most a fixed number of iterator elements lazily, separated by
+/// `sep`, followed by an ellipsis and the count of omitted elements.
+///
+/// The format value can only be formatted once, after that the iterator is
+/// exhausted.
+///
+/// See [`.format_truncated()`](../trait.Itertools.html#method.format_truncated)
+/// for more information.
+#[derive(Clone)]
+pub struct FormatTruncated<'a, I> {
+    sep: &'a str,
+    max_items: usize,
+    /// FormatTruncated uses interior mutability because Display::fmt takes &self.
+    inner: RefCell<Option<I>>,
+}
+
+/// Format all iterator elements lazily using their `Debug` implementation,
+/// separated by `sep`.
+///
+/// The format value can only be formatted once, after that the iterator is
+/// exhausted.
+///
+/// See [`.format_debug()`](../trait.Itertools.html#method.format_debug)
+/// for more information.
+#[derive(Clone)]
+pub struct FormatDebug<'a, I> {
+    inner: Format<'a, I>,
+}
+
 pub fn new_format<'a, I, F>(iter: I, separator: &'a str, f: F) -> FormatWith<'a, I, F>
     where I: Iterator,
           F: FnMut(I::Item, &mut FnMut(&fmt::Display) -> fmt::Result) -> fmt::Result
@@ -46,6 +79,25 @@ pub fn new_format_default<'a, I>(iter: I, separator: &'a str) -> Format<'a, I>
     }
 }
 
+pub fn new_format_debug<'a, I>(iter: I, separator: &'a str) -> FormatDebug<'a, I>
+    where I: Iterator,
+{
+    FormatDebug {
+        inner: new_format_default(iter, separator),
+    }
+}
+
+pub fn new_format_truncated<'a, I>(iter: I, separator: &'a str, max_items: usize)
+    -> FormatTruncated<'a, I>
+    where I: Iterator,
+{
+    FormatTruncated {
+        sep: separator,
+        max_items,
+        inner: RefCell::new(Some(iter)),
+    }
+}
+
 impl<'a, I, F> fmt::Display for FormatWith<'a, I, F>
     where I: Iterator,
           F: FnMut(I::Item, &mut FnMut(&fmt::Display) -> fmt::Result) -> fmt::Result
@@ -94,6 +146,51 @@ impl<'a, I> Format<'a, I>
     }
 }
 
+impl<'a, I> fmt::Display for FormatDebug<'a, I>
+    where I: Iterator,
+          I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.format(f, fmt::Debug::fmt)
+    }
+}
+
+impl<'a, I> FormatTruncated<'a, I>
+    where I: Iterator,
+{
+    fn format<F>(&self, f: &mut fmt::Formatter, mut cb: F) -> fmt::Result
+        where F: FnMut(&I::Item, &mut fmt::Formatter) -> fmt::Result,
+    {
+        let mut iter = match self.inner.borrow_mut().take() {
+            Some(t) => t,
+            None => panic!("FormatTruncated: was already formatted once"),
+        };
+
+        let mut written = 0;
+        while written < self.max_items {
+            match iter.next() {
+                Some(elt) => {
+                    if written > 0 && !self.sep.is_empty() {
+                        f.write_str(self.sep)?;
+                    }
+                    cb(&elt, f)?;
+                    written += 1;
+                }
+                None => return Ok(()),
+            }
+        }
+
+        let omitted = iter.count();
+        if omitted > 0 {
+            if written > 0 {
+                f.write_str(self.sep)?;
+            }
+            write!(f, "... ({} more)", omitted)?;
+        }
+        Ok(())
+    }
+}
+
 macro_rules! impl_format {
     ($($fmt_trait:ident)*) => {
         $(
@@ -105,6 +202,15 @@ macro_rules! impl_format {
                     self.format(f, fmt::$fmt_trait::fmt)
                 }
             }
+
+            impl<'a, I> fmt::$fmt_trait for FormatTruncated<'a, I>
+                where I: Iterator,
+                      I::Item: fmt::$fmt_trait,
+            {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    self.format(f, fmt::$fmt_trait::fmt)
+                }
+            }
         )*
     }
 }
@@ -22,8 +22,10 @@ pub use put_back_n_impl::put_back_n;
 #[cfg(feature = "use_std")]
 pub use multipeek_impl::multipeek;
 #[cfg(feature = "use_std")]
+pub use peek_nth::peek_nth;
+#[cfg(feature = "use_std")]
 pub use kmerge_impl::kmerge;
-pub use zip_eq_impl::zip_eq;
+pub use zip_eq_impl::{try_zip_eq, zip_eq};
 pub use merge_join::merge_join_by;
 #[cfg(feature = "use_std")]
 pub use rciter_impl::rciter;
@@ -1,6 +1,8 @@
 #![cfg(feature = "use_std")]
 
 use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::fmt;
 use std::hash::Hash;
 use std::iter::Iterator;
 
@@ -19,4 +21,126 @@ pub fn into_group_map<I, K, V>(iter: I) -> HashMap<K, Vec<V>>
     }
 
     lookup
-}
\ No newline at end of file
+}
+
+/// The error returned by [`.index_by()`](trait.Itertools.html#method.index_by)
+/// when two elements have the same key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DuplicateKeyError<K, V> {
+    /// The key shared by the two elements.
+    pub key: K,
+    /// The element that was indexed first.
+    pub first: V,
+    /// The element with the same key that was found next.
+    pub second: V,
+}
+
+impl<K, V> fmt::Display for DuplicateKeyError<K, V>
+    where K: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "duplicate key: {:?}", self.key)
+    }
+}
+
+/// Return a `HashMap` of the elements indexed by their key, or an error on
+/// the first key shared by two elements.
+///
+/// See [`.index_by()`](../trait.Itertools.html#method.index_by)
+/// for more information.
+pub fn index_by<I, K, F>(iter: I, mut key: F)
+    -> Result<HashMap<K, I::Item>, DuplicateKeyError<K, I::Item>>
+    where I: Iterator,
+          K: Hash + Eq,
+          F: FnMut(&I::Item) -> K,
+{
+    let mut lookup = HashMap::new();
+
+    for val in iter {
+        match lookup.entry(key(&val)) {
+            Entry::Vacant(entry) => {
+                entry.insert(val);
+            }
+            Entry::Occupied(entry) => {
+                let (key, first) = entry.remove_entry();
+                return Err(DuplicateKeyError { key, first, second: val });
+            }
+        }
+    }
+
+    Ok(lookup)
+}
+
+/// How [`.into_lookup()`](trait.Itertools.html#method.into_lookup) resolves
+/// two values with the same key.
+///
+/// It is implemented by [`KeepFirst`](struct.KeepFirst.html),
+/// [`KeepLast`](struct.KeepLast.html), and by closures
+/// `FnMut(&K, V, V) -> V` that merge the value already in the map with the
+/// new one.
+pub trait LookupPolicy<K, V> {
+    /// Return the value to keep for `key`, given the value already in the
+    /// map and the new one.
+    fn resolve(&mut self, key: &K, old: V, new: V) -> V;
+}
+
+/// A [`LookupPolicy`](trait.LookupPolicy.html) keeping the first value seen
+/// for each key.
+#[derive(Copy, Clone, Debug)]
+pub struct KeepFirst;
+
+/// A [`LookupPolicy`](trait.LookupPolicy.html) keeping the last value seen
+/// for each key, like collecting into a `HashMap`.
+#[derive(Copy, Clone, Debug)]
+pub struct KeepLast;
+
+impl<K, V> LookupPolicy<K, V> for KeepFirst {
+    fn resolve(&mut self, _: &K, old: V, _: V) -> V {
+        old
+    }
+}
+
+impl<K, V> LookupPolicy<K, V> for KeepLast {
+    fn resolve(&mut self, _: &K, _: V, new: V) -> V {
+        new
+    }
+}
+
+impl<K, V, F> LookupPolicy<K, V> for F
+    where F: FnMut(&K, V, V) -> V
+{
+    fn resolve(&mut self, key: &K, old: V, new: V) -> V {
+        self(key, old, new)
+    }
+}
+
+/// Return a `HashMap` of keys mapped to their value, resolving duplicate
+/// keys with `policy`.
+///
+/// See [`.into_lookup()`](../trait.Itertools.html#method.into_lookup)
+/// for more information.
+pub fn into_lookup<I, K, V, P>(iter: I, mut policy: P) -> HashMap<K, V>
+    where I: Iterator<Item=(K, V)>,
+          K: Hash + Eq,
+          P: LookupPolicy<K, V>,
+{
+    let mut lookup = HashMap::new();
+
+    for (key, val) in iter {
+        // the policy takes the old value by value, so it is removed from the
+        // map and the resolved one is inserted back once the entry is gone
+        let conflict = match lookup.entry(key) {
+            Entry::Vacant(entry) => {
+                entry.insert(val);
+                None
+            }
+            Entry::Occupied(entry) => Some((entry.remove_entry(), val)),
+        };
+        if let Some(((key, old), val)) = conflict {
+            let val = policy.resolve(&key, old, val);
+            lookup.insert(key, val);
+        }
+    }
+
+    lookup
+}
@@ -272,6 +272,66 @@ impl<K, I, F> GroupInner<K, I, F>
     }
 }
 
+impl<I> GroupInner<usize, I, ChunkIndex>
+    where I: Iterator,
+{
+    /// Number of elements taken from the iterator so far.
+    fn chunk_elements_taken(&self) -> usize {
+        self.key.key * self.key.size + self.key.index
+    }
+
+    /// Skip the `n` chunks starting at `first`, the next chunk index to be
+    /// handed out, with a single `nth` call on the iterator.
+    ///
+    /// Return `false` (doing nothing) if that would lose elements of a chunk
+    /// iterator that is still alive; these must be buffered the slow way.
+    fn skip_chunks(&mut self, first: usize, n: usize) -> bool {
+        if n == 0 || self.done {
+            return false;
+        }
+        // The only elements of yielded chunks left in the iterator belong
+        // to the top group; it's fine to drop them once it was dropped.
+        let top_dropped = self.dropped_group != !0 && self.dropped_group >= self.top_group;
+        if !(first == 0 || self.top_group >= first || top_dropped) {
+            return false;
+        }
+        let target = match (first + n).checked_mul(self.key.size) {
+            Some(target) => target,
+            None => return false,
+        };
+        let skip = target - self.chunk_elements_taken();
+        self.current_elt = None;
+        self.current_key = None;
+        if skip > 0 && self.iter.nth(skip - 1).is_none() {
+            self.done = true;
+        }
+        // Continue as if the skipped chunks were dropped while iterating.
+        self.key.key = first + n;
+        self.key.index = 0;
+        self.top_group = first + n;
+        if self.buffer.is_empty() {
+            self.bottom_group = self.top_group;
+            self.oldest_buffered_group = self.top_group;
+        }
+        true
+    }
+
+    /// Bounds on the number of chunks from `first` on.
+    fn chunks_size_hint(&self, first: usize) -> (usize, Option<usize>) {
+        let taken = self.chunk_elements_taken();
+        let size = self.key.size;
+        let (lower, upper) = self.iter.size_hint();
+        let chunks = |total: usize| {
+            (total / size + (total % size != 0) as usize).saturating_sub(first)
+        };
+        if self.done {
+            return (chunks(taken), Some(chunks(taken)));
+        }
+        (chunks(taken.saturating_add(lower)),
+         upper.and_then(|upper| taken.checked_add(upper)).map(chunks))
+    }
+}
+
 /// `GroupBy` is the storage for the lazy grouping operation.
 ///
 /// If the groups are consumed in their original order, or if each
@@ -533,8 +593,29 @@ impl<'a, I> Iterator for Chunks<'a, I>
             }
         })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.parent.inner.borrow().chunks_size_hint(self.parent.index.get())
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let index = self.parent.index.get();
+        if self.parent.inner.borrow_mut().skip_chunks(index, n) {
+            self.parent.index.set(index + n);
+        } else {
+            for _ in 0..n {
+                self.next()?;
+            }
+        }
+        self.next()
+    }
 }
 
+impl<'a, I> ExactSizeIterator for Chunks<'a, I>
+    where I: ExactSizeIterator,
+          I::Item: 'a,
+{}
+
 /// An iterator for the elements in a single chunk.
 ///
 /// Iterator element type is `I::Item`.
@@ -0,0 +1,2083 @@
+#![cfg(feature = "use_std")]
+
+use std::cmp::{self, Ordering};
+use std::fmt::{self, Write};
+use std::collections::{btree_map, hash_map, BTreeMap, HashMap, HashSet};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash};
+use std::iter::Iterator;
+use std::ops::{Add, Mul};
+
+use MinMaxResult;
+use kmerge_impl::{heapify, sift_down};
+#[cfg(all(feature = "rand", feature = "use_std"))]
+use rand::Rng;
+
+/// Integer types with overflow-checked arithmetic, as used by
+/// [`GroupingMap::checked_sum`](struct.GroupingMap.html#method.checked_sum)
+/// and [`GroupingMap::checked_product`](struct.GroupingMap.html#method.checked_product).
+///
+/// Implemented for all the primitive integer types.
+pub trait CheckedArith: Sized {
+    /// Addition, returning `None` on overflow.
+    fn checked_add(self, other: Self) -> Option<Self>;
+    /// Multiplication, returning `None` on overflow.
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_arith {
+    ($($t:ty)*) => {
+        $(
+            impl CheckedArith for $t {
+                #[inline]
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+                #[inline]
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+            }
+        )*
+    }
+}
+
+impl_checked_arith!(u8 u16 u32 u64 usize i8 i16 i32 i64 isize);
+
+/// Numeric types that can be converted to an `f64`, possibly losing
+/// precision, as used by [`GroupingMap::mean`](struct.GroupingMap.html#method.mean)
+/// and [`.rolling_mean()`](trait.Itertools.html#method.rolling_mean).
+///
+/// Implemented for all the primitive integer and floating point types.
+pub trait ToF64 {
+    /// Convert to the nearest `f64`.
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_to_f64 {
+    ($($t:ty)*) => {
+        $(
+            impl ToF64 for $t {
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    }
+}
+
+impl_to_f64!(u8 u16 u32 u64 usize i8 i16 i32 i64 isize f32 f64);
+
+/// A map that the results of a `GroupingMap` can be stored in, as used by
+/// [`GroupingMap::aggregate_in`](struct.GroupingMap.html#method.aggregate_in)
+/// and the other `_in` methods.
+///
+/// Implemented for `HashMap`, with any hasher, and `BTreeMap`, and for
+/// mutable references to a `DestinationMap`.
+pub trait DestinationMap<K, V> {
+    /// Insert `value` for `key`, returning the previous value if there was one.
+    fn insert(&mut self, key: K, value: V) -> Option<V>;
+    /// Remove the value for `key` and return it, if there is one.
+    fn remove(&mut self, key: &K) -> Option<V>;
+    /// Return the value for `key`, inserting `V::default()` first if there is none.
+    fn entry_or_default(&mut self, key: K) -> &mut V
+        where V: Default;
+}
+
+impl<K, V, S> DestinationMap<K, V> for HashMap<K, V, S>
+    where K: Hash + Eq,
+          S: BuildHasher,
+{
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        HashMap::insert(self, key, value)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        HashMap::remove(self, key)
+    }
+
+    fn entry_or_default(&mut self, key: K) -> &mut V
+        where V: Default
+    {
+        match self.entry(key) {
+            hash_map::Entry::Occupied(entry) => entry.into_mut(),
+            hash_map::Entry::Vacant(entry) => entry.insert(V::default()),
+        }
+    }
+}
+
+impl<'a, K, V, M> DestinationMap<K, V> for &'a mut M
+    where M: DestinationMap<K, V>,
+{
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        (**self).insert(key, value)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        (**self).remove(key)
+    }
+
+    fn entry_or_default(&mut self, key: K) -> &mut V
+        where V: Default
+    {
+        (**self).entry_or_default(key)
+    }
+}
+
+impl<K, V> DestinationMap<K, V> for BTreeMap<K, V>
+    where K: Ord,
+{
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        BTreeMap::insert(self, key, value)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        BTreeMap::remove(self, key)
+    }
+
+    fn entry_or_default(&mut self, key: K) -> &mut V
+        where V: Default
+    {
+        match self.entry(key) {
+            btree_map::Entry::Occupied(entry) => entry.into_mut(),
+            btree_map::Entry::Vacant(entry) => entry.insert(V::default()),
+        }
+    }
+}
+
+/// An iterator that pairs each element with its key, as computed by a
+/// closure, for a [`GroupingMapBy`](type.GroupingMapBy.html).
+#[derive(Clone)]
+pub struct MapForGrouping<I, F>(I, F);
+
+/// Creates a new `MapForGrouping` from `iter` and `key_mapper`
+pub fn new_map_for_grouping<K, I, F>(iter: I, key_mapper: F) -> MapForGrouping<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> K,
+{
+    MapForGrouping(iter, key_mapper)
+}
+
+impl<K, V, I, F> Iterator for MapForGrouping<I, F>
+    where I: Iterator<Item = V>,
+          F: FnMut(&V) -> K,
+{
+    type Item = (K, V);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|val| ((self.1)(&val), val))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// An iterator that maps the value of each `(key, value)` pair, for
+/// [`GroupingMap::map_values`](struct.GroupingMap.html#method.map_values).
+#[derive(Clone)]
+pub struct MapValues<I, F>(I, F);
+
+impl<K, V, W, I, F> Iterator for MapValues<I, F>
+    where I: Iterator<Item = (K, V)>,
+          F: FnMut(&K, V) -> W,
+{
+    type Item = (K, W);
+    fn next(&mut self) -> Option<Self::Item> {
+        let f = &mut self.1;
+        self.0.next().map(|(key, val)| {
+            let val = f(&key, val);
+            (key, val)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// An iterator that filters the `(key, value)` pairs, for
+/// [`GroupingMap::filter`](struct.GroupingMap.html#method.filter).
+#[derive(Clone)]
+pub struct FilterValues<I, P>(I, P);
+
+impl<K, V, I, P> Iterator for FilterValues<I, P>
+    where I: Iterator<Item = (K, V)>,
+          P: FnMut(&K, &V) -> bool,
+{
+    type Item = (K, V);
+    fn next(&mut self) -> Option<Self::Item> {
+        for (key, val) in &mut self.0 {
+            if (self.1)(&key, &val) {
+                return Some((key, val));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.0.size_hint().1)
+    }
+}
+
+/// An iterator that filters and maps the value of each `(key, value)` pair,
+/// for [`GroupingMap::filter_map_values`](struct.GroupingMap.html#method.filter_map_values).
+#[derive(Clone)]
+pub struct FilterMapValues<I, F>(I, F);
+
+impl<K, V, W, I, F> Iterator for FilterMapValues<I, F>
+    where I: Iterator<Item = (K, V)>,
+          F: FnMut(&K, V) -> Option<W>,
+{
+    type Item = (K, W);
+    fn next(&mut self) -> Option<Self::Item> {
+        for (key, val) in &mut self.0 {
+            if let Some(val) = (self.1)(&key, val) {
+                return Some((key, val));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.0.size_hint().1)
+    }
+}
+
+/// `GroupingMapBy` is an intermediate struct for efficient group-and-fold operations.
+///
+/// See [`GroupingMap`](struct.GroupingMap.html) for more informations.
+pub type GroupingMapBy<I, F> = GroupingMap<MapForGrouping<I, F>>;
+
+/// `GroupingMap` is an intermediate struct for efficient group-and-fold operations.
+/// It groups elements by their key and at the same time fold each group
+/// using some aggregating operation.
+///
+/// The `HashMap`s it returns use the hasher `S`, which is the default
+/// `RandomState` unless the `GroupingMap` was made with
+/// [`.into_grouping_map_with_hasher()`](../trait.Itertools.html#method.into_grouping_map_with_hasher).
+/// They are created with room for as many keys as the lower bound of the
+/// size hint of the source, up to a limit, unless a capacity is given with
+/// [`.with_capacity()`](#method.with_capacity).
+///
+/// Most methods on this struct perform no temporary allocations: they only
+/// allocate the resulting map and what it contains. The exceptions are:
+/// - [`mean`](#method.mean), [`variance`](#method.variance),
+///   [`std_dev`](#method.std_dev) and [`sample`](#method.sample), which
+///   aggregate each group in a first `HashMap`, then move the results into
+///   a second one;
+/// - [`collect_unique`](#method.collect_unique), which also keeps a
+///   `HashSet` of the elements seen in each group;
+/// - [`join`](#method.join), which collects the elements in a `Vec` and
+///   counts them in a `HashMap` to reserve the room for each group;
+/// - [`collect_into_sorted_vec`](#method.collect_into_sorted_vec) and
+///   [`collect_into_sorted_vec_by`](#method.collect_into_sorted_vec_by),
+///   which collect the groups in a `HashMap` before sorting them in a `Vec`.
+#[derive(Clone, Debug)]
+#[must_use = "GroupingMap is lazy and do nothing unless consumed"]
+pub struct GroupingMap<I, S = RandomState> {
+    iter: I,
+    hasher: S,
+    /// The capacity of the resulting map, if given
+    capacity: Option<usize>,
+}
+
+/// The largest capacity of the resulting map guessed from the size hint of
+/// the source: since groups have several elements, it overestimates the
+/// number of keys.
+const MAX_HINTED_CAPACITY: usize = 1024;
+
+/// Creates a new `GroupingMap` from `iter`
+pub fn new<I, K, V>(iter: I) -> GroupingMap<I>
+    where I: Iterator<Item = (K, V)>,
+{
+    new_with_hasher(iter, RandomState::new())
+}
+
+/// Creates a new `GroupingMap` from `iter`, whose results use `hasher`
+pub fn new_with_hasher<I, K, V, S>(iter: I, hasher: S) -> GroupingMap<I, S>
+    where I: Iterator<Item = (K, V)>,
+          S: BuildHasher,
+{
+    GroupingMap { iter, hasher, capacity: None }
+}
+
+impl<I, K, V, S> GroupingMap<I, S>
+    where I: Iterator<Item = (K, V)>,
+{
+    /// Set the capacity of the resulting `HashMap`s, for when the number of
+    /// keys is known, instead of guessing it from the size hint of the source.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = (0..100_000)
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .with_capacity(3)
+    ///     .max();
+    ///
+    /// assert_eq!(lookup[&0], 99_999);
+    /// assert_eq!(lookup[&2], 99_998);
+    /// ```
+    pub fn with_capacity(self, capacity: usize) -> Self {
+        GroupingMap { capacity: Some(capacity), ..self }
+    }
+
+    /// Return a `GroupingMap` whose values are the result of `f` on the
+    /// values of this one, with a reference to their key.
+    ///
+    /// Like the other operations, this is lazy: `f` is only called when
+    /// the resulting `GroupingMap` is consumed.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let words = vec!["apple", "avocado", "banana", "blueberry", "cherry"];
+    /// let lookup = words.into_iter()
+    ///     .into_grouping_map_by(|w| w.chars().next().unwrap())
+    ///     .map_values(|_initial, w| w.len())
+    ///     .sum();
+    ///
+    /// assert_eq!(lookup[&'a'], 5 + 7);
+    /// assert_eq!(lookup[&'b'], 6 + 9);
+    /// assert_eq!(lookup[&'c'], 6);
+    /// ```
+    pub fn map_values<F, W>(self, f: F) -> GroupingMap<MapValues<I, F>, S>
+        where F: FnMut(&K, V) -> W,
+    {
+        GroupingMap {
+            iter: MapValues(self.iter, f),
+            hasher: self.hasher,
+            capacity: self.capacity,
+        }
+    }
+
+    /// Return a `GroupingMap` of the values of this one for which
+    /// `predicate` returns `true`, with a reference to their key.
+    ///
+    /// A key none of whose values satisfy `predicate` won't be in the
+    /// results of the operations.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = (1..10)
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .filter(|_key, &n| n > 3)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(lookup[&0], vec![6, 9]);
+    /// assert_eq!(lookup[&1], vec![4, 7]);
+    /// assert_eq!(lookup[&2], vec![5, 8]);
+    /// ```
+    pub fn filter<P>(self, predicate: P) -> GroupingMap<FilterValues<I, P>, S>
+        where P: FnMut(&K, &V) -> bool,
+    {
+        GroupingMap {
+            iter: FilterValues(self.iter, predicate),
+            hasher: self.hasher,
+            capacity: self.capacity,
+        }
+    }
+
+    /// Return a `GroupingMap` whose values are the values returned in a
+    /// `Some` by `f` on the values of this one, with a reference to their
+    /// key.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let readings = vec![("a", "12"), ("b", "n/a"), ("a", "30"), ("b", "7")];
+    /// let lookup = readings.into_iter()
+    ///     .into_grouping_map()
+    ///     .filter_map_values(|_sensor, s| s.parse::<u32>().ok())
+    ///     .max();
+    ///
+    /// assert_eq!(lookup["a"], 30);
+    /// assert_eq!(lookup["b"], 7);
+    /// ```
+    pub fn filter_map_values<F, W>(self, f: F) -> GroupingMap<FilterMapValues<I, F>, S>
+        where F: FnMut(&K, V) -> Option<W>,
+    {
+        GroupingMap {
+            iter: FilterMapValues(self.iter, f),
+            hasher: self.hasher,
+            capacity: self.capacity,
+        }
+    }
+
+    /// Like [`aggregate`](#method.aggregate), but stores the results in
+    /// `map`, which can be a `BTreeMap` or a `HashMap` with a custom hasher,
+    /// and returns it.
+    ///
+    /// Entries already in `map` are used as the initial accumulator of
+    /// their group.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let lookup = vec![2, 8, 5, 7, 9, 0, 4, 10].into_iter()
+    ///     .map(|n| (n % 4, n))
+    ///     .into_grouping_map()
+    ///     .aggregate_in(|acc, _key, val| {
+    ///         if val == 0 || val == 10 {
+    ///             None
+    ///         } else {
+    ///             Some(acc.unwrap_or(0) + val)
+    ///         }
+    ///     }, BTreeMap::new());
+    ///
+    /// assert_eq!(lookup.into_iter().collect::<Vec<_>>(), vec![(0, 4), (1, 5 + 9), (3, 7)]);
+    /// ```
+    pub fn aggregate_in<FO, R, M>(self, mut operation: FO, mut map: M) -> M
+        where FO: FnMut(Option<R>, &K, V) -> Option<R>,
+              M: DestinationMap<K, R>,
+    {
+        for (key, val) in self.iter {
+            let acc = map.remove(&key);
+            if let Some(op_res) = operation(acc, &key, val) {
+                map.insert(key, op_res);
+            }
+        }
+
+        map
+    }
+
+    /// Like [`fold_with`](#method.fold_with), but stores the results in
+    /// `map` and returns it.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let lookup = (1..8)
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .fold_with_in(|&key, _val| key * 100, |acc, _key, val| acc + val, BTreeMap::new());
+    ///
+    /// assert_eq!(lookup.into_iter().collect::<Vec<_>>(),
+    ///            vec![(0, 3 + 6), (1, 100 + 1 + 4 + 7), (2, 200 + 2 + 5)]);
+    /// ```
+    pub fn fold_with_in<FI, FO, R, M>(self, mut init: FI, mut operation: FO, map: M) -> M
+        where FI: FnMut(&K, &V) -> R,
+              FO: FnMut(R, &K, V) -> R,
+              M: DestinationMap<K, R>,
+    {
+        self.aggregate_in(|acc, key, val| {
+            let acc = acc.unwrap_or_else(|| init(key, &val));
+            Some(operation(acc, key, val))
+        }, map)
+    }
+
+    /// Like [`fold`](#method.fold), but stores the results in `map` and
+    /// returns it.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let lookup = (1..8)
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .fold_in(0, |acc, _key, val| acc + val, BTreeMap::new());
+    ///
+    /// assert_eq!(lookup.into_iter().collect::<Vec<_>>(), vec![(0, 3 + 6), (1, 1 + 4 + 7), (2, 2 + 5)]);
+    /// ```
+    pub fn fold_in<FO, R, M>(self, init: R, operation: FO, map: M) -> M
+        where R: Clone,
+              FO: FnMut(R, &K, V) -> R,
+              M: DestinationMap<K, R>,
+    {
+        self.fold_with_in(|_, _| init.clone(), operation, map)
+    }
+
+    /// Like [`fold_first`](#method.fold_first), but stores the results in
+    /// `map` and returns it.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let lookup = (1..8)
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .fold_first_in(|acc, _key, val| acc + val, BTreeMap::new());
+    ///
+    /// assert_eq!(lookup.into_iter().collect::<Vec<_>>(), vec![(0, 3 + 6), (1, 1 + 4 + 7), (2, 2 + 5)]);
+    /// ```
+    pub fn fold_first_in<FO, M>(self, mut operation: FO, map: M) -> M
+        where FO: FnMut(V, &K, V) -> V,
+              M: DestinationMap<K, V>,
+    {
+        self.aggregate_in(|acc, key, val| {
+            Some(match acc {
+                Some(acc) => operation(acc, key, val),
+                None => val,
+            })
+        }, map)
+    }
+
+    /// Like [`collect`](#method.collect), but stores the collections in
+    /// `map` and returns it.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let lookup = vec![0, 1, 2, 3, 4, 5, 6, 2, 3, 6].into_iter()
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .collect_in::<Vec<_>, _>(BTreeMap::new());
+    ///
+    /// assert_eq!(lookup.into_iter().collect::<Vec<_>>(),
+    ///            vec![(0, vec![0, 3, 6, 3, 6]), (1, vec![1, 4]), (2, vec![2, 5, 2])]);
+    /// ```
+    pub fn collect_in<C, M>(self, mut map: M) -> M
+        where C: Default + Extend<V>,
+              M: DestinationMap<K, C>,
+    {
+        for (key, val) in self.iter {
+            map.entry_or_default(key).extend(Some(val));
+        }
+
+        map
+    }
+
+    /// Like [`aggregate`](#method.aggregate), but continues the aggregation
+    /// into an existing `map`: the entries already in it are used as the
+    /// initial accumulator of their group.
+    ///
+    /// This allows to aggregate several batches of elements in the same map.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut lookup = HashMap::new();
+    /// for batch in vec![vec![1, 2, 3], vec![4, 5], vec![6]] {
+    ///     batch.into_iter()
+    ///         .map(|n| (n % 2, n))
+    ///         .into_grouping_map()
+    ///         .aggregate_into(&mut lookup, |acc, _key, val| Some(acc.unwrap_or(0) + val));
+    /// }
+    ///
+    /// assert_eq!(lookup[&0], 2 + 4 + 6);
+    /// assert_eq!(lookup[&1], 1 + 3 + 5);
+    /// ```
+    pub fn aggregate_into<FO, R, M>(self, map: &mut M, operation: FO)
+        where FO: FnMut(Option<R>, &K, V) -> Option<R>,
+              M: DestinationMap<K, R>,
+    {
+        self.aggregate_in(operation, map);
+    }
+
+    /// Like [`fold`](#method.fold), but continues the folding into an
+    /// existing `map`: the entries already in it are used as the
+    /// accumulator of their group instead of `init`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut lookup = HashMap::new();
+    /// (1..4).map(|n| (n % 2, n)).into_grouping_map().fold_into(&mut lookup, 0, |acc, _key, val| acc + val);
+    /// (4..7).map(|n| (n % 2, n)).into_grouping_map().fold_into(&mut lookup, 0, |acc, _key, val| acc + val);
+    ///
+    /// assert_eq!(lookup[&0], 2 + 4 + 6);
+    /// assert_eq!(lookup[&1], 1 + 3 + 5);
+    /// ```
+    pub fn fold_into<FO, R, M>(self, map: &mut M, init: R, operation: FO)
+        where R: Clone,
+              FO: FnMut(R, &K, V) -> R,
+              M: DestinationMap<K, R>,
+    {
+        self.fold_in(init, operation, map);
+    }
+
+    /// Like [`collect`](#method.collect), but extends the collections of an
+    /// existing `map`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut lookup: HashMap<_, Vec<_>> = HashMap::new();
+    /// (1..4).map(|n| (n % 2, n)).into_grouping_map().collect_into(&mut lookup);
+    /// (4..7).map(|n| (n % 2, n)).into_grouping_map().collect_into(&mut lookup);
+    ///
+    /// assert_eq!(lookup[&0], vec![2, 4, 6]);
+    /// assert_eq!(lookup[&1], vec![1, 3, 5]);
+    /// ```
+    pub fn collect_into<C, M>(self, map: &mut M)
+        where C: Default + Extend<V>,
+              M: DestinationMap<K, C>,
+    {
+        self.collect_in(map);
+    }
+
+    /// Like [`try_aggregate`](#method.try_aggregate), but stores the results
+    /// in `map` and returns it.
+    ///
+    /// If `operation` fails, the entries of `map` are left as they are, and
+    /// the accumulator of the failing group is removed.
+    pub fn try_aggregate_in<FO, R, E, M>(self, mut operation: FO, mut map: M) -> Result<M, E>
+        where FO: FnMut(Option<R>, &K, V) -> Result<Option<R>, E>,
+              M: DestinationMap<K, R>,
+    {
+        for (key, val) in self.iter {
+            let acc = map.remove(&key);
+            if let Some(op_res) = operation(acc, &key, val)? {
+                map.insert(key, op_res);
+            }
+        }
+
+        Ok(map)
+    }
+
+    /// Like [`try_fold`](#method.try_fold), but stores the results in `map`
+    /// and returns it.
+    pub fn try_fold_in<FO, R, E, M>(self, init: R, mut operation: FO, map: M) -> Result<M, E>
+        where R: Clone,
+              FO: FnMut(R, &K, V) -> Result<R, E>,
+              M: DestinationMap<K, R>,
+    {
+        self.try_aggregate_in(|acc, key, val| {
+            let acc = acc.unwrap_or_else(|| init.clone());
+            operation(acc, key, val).map(Some)
+        }, map)
+    }
+}
+
+impl<I, K, V, S> GroupingMap<I, S>
+    where I: Iterator<Item = (K, V)>,
+          K: Hash + Eq,
+          S: BuildHasher,
+{
+    /// Split into the same `GroupingMap` without its hasher, and an empty
+    /// `HashMap` using the hasher.
+    fn into_destination<R>(self) -> (GroupingMap<I, ()>, HashMap<K, R, S>) {
+        let GroupingMap { iter, hasher, capacity } = self;
+        let capacity = capacity.unwrap_or_else(|| {
+            cmp::min(iter.size_hint().0, MAX_HINTED_CAPACITY)
+        });
+        let map = HashMap::with_capacity_and_hasher(capacity, hasher);
+        (GroupingMap { iter, hasher: (), capacity: None }, map)
+    }
+
+    /// This is the generic way to perform any operation on a `GroupingMap`.
+    /// It's suggested to use this method only to implement custom operations
+    /// when the already provided ones are not enough.
+    ///
+    /// Groups elements from the `GroupingMap` source by key and applies `operation` to the elements
+    /// of each group sequentially, passing the previously accumulated value, a reference to the key
+    /// and the current element as arguments, and stores the results in an `HashMap`.
+    ///
+    /// The `operation` function is invoked on each element with the following parameters:
+    ///  - the current value of the accumulator of the group if there is currently one;
+    ///  - a reference to the key of the group this element belongs to;
+    ///  - the element from the source being aggregated;
+    ///
+    /// If `operation` returns `Some(element)` then the accumulator is updated with `element`,
+    /// otherwise the previous accumulation is discarded.
+    ///
+    /// Return a `HashMap` associating the key of each group with the result of aggregation of
+    /// that group's elements. If the aggregation of the last element of a group discards the
+    /// accumulator then there won't be an entry associated to that group's key.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![2, 8, 5, 7, 9, 0, 4, 10];
+    /// let lookup = data.into_iter()
+    ///     .map(|n| (n % 4, n))
+    ///     .into_grouping_map()
+    ///     .aggregate(|acc, _key, val| {
+    ///         if val == 0 || val == 10 {
+    ///             None
+    ///         } else {
+    ///             Some(acc.unwrap_or(0) + val)
+    ///         }
+    ///     });
+    ///
+    /// assert_eq!(lookup[&0], 4);        // 0 resets the accumulator so only 4 is summed
+    /// assert_eq!(lookup[&1], 5 + 9);
+    /// assert_eq!(lookup.get(&2), None); // 10 resets the accumulator and nothing is summed afterward
+    /// assert_eq!(lookup[&3], 7);
+    /// assert_eq!(lookup.len(), 3);      // The final keys are only 0, 1 and 3
+    /// ```
+    pub fn aggregate<FO, R>(self, operation: FO) -> HashMap<K, R, S>
+        where FO: FnMut(Option<R>, &K, V) -> Option<R>,
+    {
+        let (grouping_map, map) = self.into_destination();
+        grouping_map.aggregate_in(operation, map)
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and applies `operation` to the elements
+    /// of each group sequentially, passing the previously accumulated value, a reference to the key
+    /// and the current element as arguments, and stores the results in a new map.
+    ///
+    /// `init` is called to obtain the initial value of each accumulator, with a reference to the
+    /// key of the group and to its first element, which is then passed to `operation` as usual.
+    ///
+    /// `operation` is a function that is invoked on each element with the following parameters:
+    ///  - the current value of the accumulator of the group;
+    ///  - a reference to the key of the group this element belongs to;
+    ///  - the element from the source being accumulated.
+    ///
+    /// Return a `HashMap` associating the key of each group with the result of folding that group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = (1..8)
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .fold_with(|_key, _val| Vec::with_capacity(3), |mut acc, _key, val| {
+    ///         acc.push(val * 10);
+    ///         acc
+    ///     });
+    ///
+    /// assert_eq!(lookup[&0], vec![30, 60]);
+    /// assert_eq!(lookup[&1], vec![10, 40, 70]);
+    /// assert_eq!(lookup[&2], vec![20, 50]);
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn fold_with<FI, FO, R>(self, init: FI, operation: FO) -> HashMap<K, R, S>
+        where FI: FnMut(&K, &V) -> R,
+              FO: FnMut(R, &K, V) -> R,
+    {
+        let (grouping_map, map) = self.into_destination();
+        grouping_map.fold_with_in(init, operation, map)
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and applies `operation` to the elements
+    /// of each group sequentially, passing the previously accumulated value, a reference to the key
+    /// and the current element as arguments, and stores the results in a new map.
+    ///
+    /// `init` is the value from which will be cloned the initial value of each accumulator.
+    ///
+    /// `operation` is a function that is invoked on each element with the following parameters:
+    ///  - the current value of the accumulator of the group;
+    ///  - a reference to the key of the group this element belongs to;
+    ///  - the element from the source being accumulated.
+    ///
+    /// Return a `HashMap` associating the key of each group with the result of folding that group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = (1..8)
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .fold(0, |acc, _key, val| acc + val);
+    ///
+    /// assert_eq!(lookup[&0], 3 + 6);
+    /// assert_eq!(lookup[&1], 1 + 4 + 7);
+    /// assert_eq!(lookup[&2], 2 + 5);
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn fold<FO, R>(self, init: R, operation: FO) -> HashMap<K, R, S>
+        where R: Clone,
+              FO: FnMut(R, &K, V) -> R,
+    {
+        let (grouping_map, map) = self.into_destination();
+        grouping_map.fold_in(init, operation, map)
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and applies `operation` to the elements
+    /// of each group sequentially, passing the previously accumulated value, a reference to the key
+    /// and the current element as arguments, and stores the results in a new map.
+    ///
+    /// This is similar to [`fold`] but the initial value of the accumulator is the first element of the group.
+    ///
+    /// `operation` is a function that is invoked on each element with the following parameters:
+    ///  - the current value of the accumulator of the group;
+    ///  - a reference to the key of the group this element belongs to;
+    ///  - the element from the source being accumulated.
+    ///
+    /// Return a `HashMap` associating the key of each group with the result of folding that group's elements.
+    ///
+    /// [`fold`]: #method.fold
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = (1..8)
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .fold_first(|acc, _key, val| acc + val);
+    ///
+    /// assert_eq!(lookup[&0], 3 + 6);
+    /// assert_eq!(lookup[&1], 1 + 4 + 7);
+    /// assert_eq!(lookup[&2], 2 + 5);
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn fold_first<FO>(self, operation: FO) -> HashMap<K, V, S>
+        where FO: FnMut(V, &K, V) -> V,
+    {
+        let (grouping_map, map) = self.into_destination();
+        grouping_map.fold_first_in(operation, map)
+    }
+
+    /// Like [`aggregate`](#method.aggregate), but `operation` can fail: the
+    /// aggregation stops at the first error, which is returned, and the rest
+    /// of the source is not consumed.
+    ///
+    /// Return a `HashMap` associating the key of each group with the result of aggregation of
+    /// that group's elements, or the first error.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec!["1", "2", "3", "4"].into_iter()
+    ///     .enumerate()
+    ///     .map(|(i, s)| (i % 2, s))
+    ///     .into_grouping_map()
+    ///     .try_aggregate(|acc, _key, s| {
+    ///         s.parse::<u32>().map(|n| Some(acc.unwrap_or(0) + n))
+    ///     });
+    /// let lookup = lookup.unwrap();
+    /// assert_eq!(lookup[&0], 1 + 3);
+    /// assert_eq!(lookup[&1], 2 + 4);
+    ///
+    /// let lookup = vec!["1", "x", "3"].into_iter()
+    ///     .enumerate()
+    ///     .map(|(i, s)| (i % 2, s))
+    ///     .into_grouping_map()
+    ///     .try_aggregate(|acc, _key, s| {
+    ///         s.parse::<u32>().map(|n| Some(acc.unwrap_or(0) + n))
+    ///     });
+    /// assert!(lookup.is_err());
+    /// ```
+    pub fn try_aggregate<FO, R, E>(self, operation: FO) -> Result<HashMap<K, R, S>, E>
+        where FO: FnMut(Option<R>, &K, V) -> Result<Option<R>, E>,
+    {
+        let (grouping_map, map) = self.into_destination();
+        grouping_map.try_aggregate_in(operation, map)
+    }
+
+    /// Like [`fold`](#method.fold), but `operation` can fail: the folding
+    /// stops at the first error, which is returned, and the rest of the
+    /// source is not consumed.
+    ///
+    /// Return a `HashMap` associating the key of each group with the result of folding that
+    /// group's elements, or the first error.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![(0, 200u8), (1, 10), (0, 60), (1, 20)].into_iter()
+    ///     .into_grouping_map()
+    ///     .try_fold(0u8, |acc, _key, val| acc.checked_add(val).ok_or("overflow"));
+    /// assert_eq!(lookup, Err("overflow"));
+    ///
+    /// let lookup = vec![(0, 200u8), (1, 10), (0, 5), (1, 20)].into_iter()
+    ///     .into_grouping_map()
+    ///     .try_fold(0u8, |acc, _key, val| acc.checked_add(val).ok_or("overflow"))
+    ///     .unwrap();
+    /// assert_eq!(lookup[&0], 205);
+    /// assert_eq!(lookup[&1], 30);
+    /// ```
+    pub fn try_fold<FO, R, E>(self, init: R, operation: FO) -> Result<HashMap<K, R, S>, E>
+        where R: Clone,
+              FO: FnMut(R, &K, V) -> Result<R, E>,
+    {
+        let (grouping_map, map) = self.into_destination();
+        grouping_map.try_fold_in(init, operation, map)
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and collects the elements of each group in
+    /// an instance of `C`. The iteration order is preserved when inserting elements.
+    ///
+    /// Return a `HashMap` associating the key of each group with the collection containing that group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use std::collections::HashSet;
+    ///
+    /// let lookup = vec![0, 1, 2, 3, 4, 5, 6, 2, 3, 6].into_iter()
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .collect::<HashSet<_>>();
+    ///
+    /// assert_eq!(lookup[&0], vec![0, 3, 6].into_iter().collect::<HashSet<_>>());
+    /// assert_eq!(lookup[&1], vec![1, 4].into_iter().collect::<HashSet<_>>());
+    /// assert_eq!(lookup[&2], vec![2, 5].into_iter().collect::<HashSet<_>>());
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn collect<C>(self) -> HashMap<K, C, S>
+        where C: Default + Extend<V>,
+    {
+        let (grouping_map, map) = self.into_destination();
+        grouping_map.collect_in(map)
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and collects the elements of each group in
+    /// an instance of `C`, skipping the elements equal to one already collected for that group.
+    /// The order of the first occurrences is preserved when inserting elements.
+    ///
+    /// Like [`.unique()`](../trait.Itertools.html#method.unique), the elements are cloned to keep
+    /// track of the ones already seen, in a set per group. The sets are then dropped in a second
+    /// map, which needs the hasher to be `Clone`.
+    ///
+    /// Return a `HashMap` associating the key of each group with the collection containing that
+    /// group's distinct elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let visits = vec![(1, "home"), (2, "cart"), (1, "docs"), (1, "home"), (2, "cart"), (1, "faq")];
+    /// let lookup = visits.into_iter()
+    ///     .into_grouping_map()
+    ///     .collect_unique::<Vec<_>>();
+    ///
+    /// assert_eq!(lookup[&1], vec!["home", "docs", "faq"]);
+    /// assert_eq!(lookup[&2], vec!["cart"]);
+    /// ```
+    pub fn collect_unique<C>(self) -> HashMap<K, C, S>
+        where C: Default + Extend<V>,
+              V: Clone + Hash + Eq,
+              S: Clone,
+    {
+        let hasher = self.hasher.clone();
+        let groups = self.aggregate(|acc, _key, val| {
+            let (mut collection, mut seen): (C, HashSet<V>) = acc.unwrap_or_default();
+            if !seen.contains(&val) {
+                seen.insert(val.clone());
+                collection.extend(Some(val));
+            }
+            Some((collection, seen))
+        });
+        let mut collections = HashMap::with_capacity_and_hasher(groups.len(), hasher);
+        collections.extend(groups.into_iter().map(|(key, (collection, _))| (key, collection)));
+        collections
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and counts, in each group, the
+    /// occurrences of the values that `f` returns for its elements.
+    ///
+    /// Return a `HashMap` associating the key of each group with a `HashMap` from each value
+    /// returned by `f` for that group's elements to its number of occurrences.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let orders = vec![("ann", "tea"), ("bob", "coffee"), ("ann", "tea"), ("ann", "juice")];
+    /// let lookup = orders.into_iter()
+    ///     .into_grouping_map()
+    ///     .counts_by(|_name, drink| drink);
+    ///
+    /// assert_eq!(lookup["ann"]["tea"], 2);
+    /// assert_eq!(lookup["ann"]["juice"], 1);
+    /// assert_eq!(lookup["bob"]["coffee"], 1);
+    /// assert_eq!(lookup["bob"].len(), 1);
+    /// ```
+    pub fn counts_by<T, F>(self, mut f: F) -> HashMap<K, HashMap<T, usize>, S>
+        where T: Hash + Eq,
+              F: FnMut(&K, V) -> T,
+    {
+        self.aggregate(|acc, key, val| {
+            let mut counts: HashMap<T, usize> = acc.unwrap_or_else(HashMap::new);
+            *counts.entry(f(key, val)).or_insert(0) += 1;
+            Some(counts)
+        })
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and collects the elements of each group in
+    /// an instance of `C`, like [`collect`].
+    ///
+    /// Return a `Vec` of `(key, collection)` pairs, sorted by key.
+    ///
+    /// [`collect`]: #method.collect
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let groups = vec![5, 1, 7, 2, 4, 3].into_iter()
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .collect_into_sorted_vec::<Vec<_>>();
+    ///
+    /// assert_eq!(groups, vec![(0, vec![3]), (1, vec![1, 7, 4]), (2, vec![5, 2])]);
+    /// ```
+    pub fn collect_into_sorted_vec<C>(self) -> Vec<(K, C)>
+        where K: Ord,
+              C: Default + Extend<V>,
+    {
+        self.collect_into_sorted_vec_by(|a, b| a.0.cmp(&b.0))
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and collects the elements of each group in
+    /// an instance of `C`, like [`collect`].
+    ///
+    /// Return a `Vec` of `(key, collection)` pairs, sorted with the comparator
+    /// `compare`. The sort is stable, but the order of the groups before sorting
+    /// is unspecified: pairs that compare equal are in an arbitrary order.
+    ///
+    /// [`collect`]: #method.collect
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // groups by decreasing size, then by key
+    /// let groups = vec![5, 1, 7, 2, 4, 3].into_iter()
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .collect_into_sorted_vec_by::<Vec<_>, _>(|a, b| {
+    ///         b.1.len().cmp(&a.1.len()).then(a.0.cmp(&b.0))
+    ///     });
+    ///
+    /// assert_eq!(groups, vec![(1, vec![1, 7, 4]), (2, vec![5, 2]), (0, vec![3])]);
+    /// ```
+    pub fn collect_into_sorted_vec_by<C, F>(self, compare: F) -> Vec<(K, C)>
+        where C: Default + Extend<V>,
+              F: FnMut(&(K, C), &(K, C)) -> Ordering,
+    {
+        let mut groups: Vec<_> = self.collect().into_iter().collect();
+        groups.sort_by(compare);
+        groups
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and collects the elements of each
+    /// group in two instances of `C`: one for the elements for which `predicate` returns `true`,
+    /// and one for the others.
+    ///
+    /// Return two `HashMap`s associating the key of each group with the collection containing
+    /// the elements that satisfy `predicate`, and with the collection containing the others.
+    /// A key is only in a map if at least one element of its group went there. The second map
+    /// needs the hasher to be `Clone`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let checks = vec![("db", 20), ("web", 350), ("db", 900), ("web", 120), ("db", 40)];
+    /// let (fast, slow) = checks.into_iter()
+    ///     .into_grouping_map()
+    ///     .partition::<Vec<_>, _>(|_service, &ms| ms < 200);
+    ///
+    /// assert_eq!(fast["db"], vec![20, 40]);
+    /// assert_eq!(fast["web"], vec![120]);
+    /// assert_eq!(slow["db"], vec![900]);
+    /// assert_eq!(slow["web"], vec![350]);
+    /// ```
+    pub fn partition<C, P>(self, mut predicate: P) -> (HashMap<K, C, S>, HashMap<K, C, S>)
+        where C: Default + Extend<V>,
+              P: FnMut(&K, &V) -> bool,
+              S: Clone,
+    {
+        let GroupingMap { iter, hasher, capacity } = self;
+        let capacity = capacity.unwrap_or_else(|| {
+            cmp::min(iter.size_hint().0, MAX_HINTED_CAPACITY)
+        });
+        let mut left: HashMap<K, C, S> = HashMap::with_capacity_and_hasher(capacity, hasher.clone());
+        let mut right = HashMap::with_capacity_and_hasher(capacity, hasher);
+
+        for (key, val) in iter {
+            let map = if predicate(&key, &val) { &mut left } else { &mut right };
+            map.entry_or_default(key).extend(Some(val));
+        }
+
+        (left, right)
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and keeps the first element of each
+    /// group.
+    ///
+    /// This is just a shorthand for `self.fold_first(|acc, _, _| acc)`.
+    ///
+    /// Returns a `HashMap` associating the key of each group with the first of that group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![(1, "a"), (2, "b"), (1, "c"), (2, "d"), (3, "e")].into_iter()
+    ///     .into_grouping_map()
+    ///     .first();
+    ///
+    /// assert_eq!(lookup[&1], "a");
+    /// assert_eq!(lookup[&2], "b");
+    /// assert_eq!(lookup[&3], "e");
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn first(self) -> HashMap<K, V, S> {
+        self.fold_first(|acc, _, _| acc)
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and keeps the last element of each
+    /// group.
+    ///
+    /// This is just a shorthand for `self.fold_first(|_, _, val| val)`.
+    ///
+    /// Returns a `HashMap` associating the key of each group with the last of that group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![(1, "a"), (2, "b"), (1, "c"), (2, "d"), (3, "e")].into_iter()
+    ///     .into_grouping_map()
+    ///     .last();
+    ///
+    /// assert_eq!(lookup[&1], "c");
+    /// assert_eq!(lookup[&2], "d");
+    /// assert_eq!(lookup[&3], "e");
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn last(self) -> HashMap<K, V, S> {
+        self.fold_first(|_, _, val| val)
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and combines the elements of each
+    /// group into one `String`, separated by `sep`, like [`.join()`](../trait.Itertools.html#method.join).
+    ///
+    /// The elements are first counted in each group, to reserve room for the separators of its
+    /// `String`, and for the other elements, guessing they are about as long as the first one.
+    /// Then they are written directly in the `String` of their group.
+    ///
+    /// Returns a `HashMap` associating the key of each group with the joined elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let tags = vec![(1, "red"), (2, "blue"), (1, "green"), (1, "blue")];
+    /// let lookup = tags.into_iter()
+    ///     .into_grouping_map()
+    ///     .join(";");
+    ///
+    /// assert_eq!(lookup[&1], "red;green;blue");
+    /// assert_eq!(lookup[&2], "blue");
+    /// ```
+    pub fn join(self, sep: &str) -> HashMap<K, String, S>
+        where V: fmt::Display,
+    {
+        let (grouping_map, mut map) = self.into_destination();
+        let elements = grouping_map.iter.collect::<Vec<_>>();
+        let lens = {
+            let mut counts = HashMap::new();
+            for elt in &elements {
+                *counts.entry(&elt.0).or_insert(0) += 1;
+            }
+            elements.iter().map(|elt| counts[&elt.0]).collect::<Vec<usize>>()
+        };
+
+        for ((key, val), len) in elements.into_iter().zip(lens) {
+            match map.entry(key) {
+                hash_map::Entry::Vacant(entry) => {
+                    let mut result = String::with_capacity(sep.len() * (len - 1));
+                    write!(&mut result, "{}", val).unwrap();
+                    let first_len = result.len();
+                    result.reserve(first_len * (len - 1));
+                    entry.insert(result);
+                }
+                hash_map::Entry::Occupied(mut entry) => {
+                    let result = entry.get_mut();
+                    result.push_str(sep);
+                    write!(result, "{}", val).unwrap();
+                }
+            }
+        }
+
+        map
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and tests if `predicate` holds for
+    /// all the elements of each group.
+    ///
+    /// Once `predicate` returns `false` for an element of a group, it isn't called for the rest of
+    /// the group's elements.
+    ///
+    /// Returns a `HashMap` associating the key of each group with whether all of that group's
+    /// elements satisfy `predicate`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let requests = vec![("ann", 200), ("bob", 200), ("ann", 404), ("bob", 201)];
+    /// let lookup = requests.into_iter()
+    ///     .into_grouping_map()
+    ///     .all(|_user, status| status < 400);
+    ///
+    /// assert_eq!(lookup["ann"], false);
+    /// assert_eq!(lookup["bob"], true);
+    /// ```
+    pub fn all<P>(self, mut predicate: P) -> HashMap<K, bool, S>
+        where P: FnMut(&K, V) -> bool,
+    {
+        self.fold(true, |acc, key, val| acc && predicate(key, val))
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and tests if `predicate` holds for
+    /// any element of each group.
+    ///
+    /// Once `predicate` returns `true` for an element of a group, it isn't called for the rest of
+    /// the group's elements.
+    ///
+    /// Returns a `HashMap` associating the key of each group with whether any of that group's
+    /// elements satisfies `predicate`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let requests = vec![("ann", 200), ("bob", 200), ("ann", 404), ("bob", 201)];
+    /// let lookup = requests.into_iter()
+    ///     .into_grouping_map()
+    ///     .any(|_user, status| status >= 400);
+    ///
+    /// assert_eq!(lookup["ann"], true);
+    /// assert_eq!(lookup["bob"], false);
+    /// ```
+    pub fn any<P>(self, mut predicate: P) -> HashMap<K, bool, S>
+        where P: FnMut(&K, V) -> bool,
+    {
+        self.fold(false, |acc, key, val| acc || predicate(key, val))
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and sums them.
+    ///
+    /// This is just a shorthand for `self.fold_first(|acc, _, val| acc + val)`.
+    /// It is more limited than `Iterator::sum` since it doesn't use the `Sum` trait.
+    ///
+    /// Returns a `HashMap` associating the key of each group with the sum of that group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![1, 3, 4, 5, 7, 8, 9, 12].into_iter()
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .sum();
+    ///
+    /// assert_eq!(lookup[&0], 3 + 9 + 12);
+    /// assert_eq!(lookup[&1], 1 + 4 + 7);
+    /// assert_eq!(lookup[&2], 5 + 8);
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn sum(self) -> HashMap<K, V, S>
+        where V: Add<V, Output = V>,
+    {
+        self.fold_first(|acc, _, val| acc + val)
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and multiplies them.
+    ///
+    /// This is just a shorthand for `self.fold_first(|acc, _, val| acc * val)`.
+    /// It is more limited than `Iterator::product` since it doesn't use the `Product` trait.
+    ///
+    /// Returns a `HashMap` associating the key of each group with the product of that group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![1, 3, 4, 5, 7, 8, 9, 12].into_iter()
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .product();
+    ///
+    /// assert_eq!(lookup[&0], 3 * 9 * 12);
+    /// assert_eq!(lookup[&1], 1 * 4 * 7);
+    /// assert_eq!(lookup[&2], 5 * 8);
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn product(self) -> HashMap<K, V, S>
+        where V: Mul<V, Output = V>,
+    {
+        self.fold_first(|acc, _, val| acc * val)
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and computes the
+    /// arithmetic mean of each group, in a single pass.
+    ///
+    /// The sum and count of each group are tracked as an `f64` and a `usize`,
+    /// so the elements are converted with `ToF64` as they come. The means are
+    /// then moved into a second map, which needs the hasher to be `Clone`.
+    ///
+    /// Returns a `HashMap` associating the key of each group with the mean of that group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![1, 3, 4, 5, 7, 8, 9, 12].into_iter()
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .mean();
+    ///
+    /// assert_eq!(lookup[&0], 8.);
+    /// assert_eq!(lookup[&1], 4.);
+    /// assert_eq!(lookup[&2], 6.5);
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn mean(self) -> HashMap<K, f64, S>
+        where V: ToF64,
+              S: Clone,
+    {
+        let hasher = self.hasher.clone();
+        let sums = self.fold((0., 0), |(sum, count), _, val| (sum + val.to_f64(), count + 1));
+        let mut means = HashMap::with_capacity_and_hasher(sums.len(), hasher);
+        means.extend(sums.into_iter().map(|(key, (sum, count))| (key, sum / count as f64)));
+        means
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and computes the
+    /// population variance of each group, in a single pass.
+    ///
+    /// This uses Welford's algorithm, which is numerically stable even when
+    /// the variance is small relative to the mean. The variance of a group is
+    /// the mean of the squared deviations from its mean, so it is `0.` for a
+    /// group of one element; multiply it by `n / (n - 1)` for the sample
+    /// variance of a group of `n` elements. Like for [`mean`](#method.mean),
+    /// the results are moved into a second map, which needs the hasher to be
+    /// `Clone`.
+    ///
+    /// Returns a `HashMap` associating the key of each group with the variance of that group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![(0, 2), (0, 4), (0, 4), (0, 4), (0, 5), (0, 5), (0, 7), (0, 9), (1, 3)]
+    ///     .into_iter()
+    ///     .into_grouping_map()
+    ///     .variance();
+    ///
+    /// assert_eq!(lookup[&0], 4.);
+    /// assert_eq!(lookup[&1], 0.);
+    /// ```
+    pub fn variance(self) -> HashMap<K, f64, S>
+        where V: ToF64,
+              S: Clone,
+    {
+        let hasher = self.hasher.clone();
+        let moments = self.fold((0, 0., 0.), |(count, mean, m2), _, val| {
+            let x = val.to_f64();
+            let count = count + 1;
+            let delta = x - mean;
+            let mean = mean + delta / count as f64;
+            (count, mean, m2 + delta * (x - mean))
+        });
+        let mut variances = HashMap::with_capacity_and_hasher(moments.len(), hasher);
+        variances.extend(moments.into_iter().map(|(key, (count, _, m2))| (key, m2 / count as f64)));
+        variances
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and computes the
+    /// population standard deviation of each group, in a single pass.
+    ///
+    /// This is the square root of the [`variance`](#method.variance).
+    ///
+    /// Returns a `HashMap` associating the key of each group with the standard deviation of that
+    /// group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![(0, 2), (0, 4), (0, 4), (0, 4), (0, 5), (0, 5), (0, 7), (0, 9), (1, 3)]
+    ///     .into_iter()
+    ///     .into_grouping_map()
+    ///     .std_dev();
+    ///
+    /// assert_eq!(lookup[&0], 2.);
+    /// assert_eq!(lookup[&1], 0.);
+    /// ```
+    pub fn std_dev(self) -> HashMap<K, f64, S>
+        where V: ToF64,
+              S: Clone,
+    {
+        let mut std_devs = self.variance();
+        for variance in std_devs.values_mut() {
+            *variance = variance.sqrt();
+        }
+        std_devs
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and keeps the `k` smallest elements
+    /// of each group, in a bounded heap.
+    ///
+    /// Returns a `HashMap` associating the key of each group with its `k` smallest elements,
+    /// or all of them if it has fewer, sorted in ascending order. The order of equal elements is
+    /// unspecified.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![1, 3, 4, 5, 7, 8, 9, 12, 13].into_iter()
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .k_smallest(2);
+    ///
+    /// assert_eq!(lookup[&0], vec![3, 9]);
+    /// assert_eq!(lookup[&1], vec![1, 4]);
+    /// assert_eq!(lookup[&2], vec![5, 8]);
+    /// ```
+    pub fn k_smallest(self, k: usize) -> HashMap<K, Vec<V>, S>
+        where V: Ord,
+    {
+        self.k_smallest_by(k, |_, a, b| a.cmp(b))
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and keeps the `k` elements of each
+    /// group that give the smallest values from the specified function, in a bounded heap.
+    ///
+    /// Returns a `HashMap` associating the key of each group with those elements, sorted by
+    /// ascending value of the function.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let purchases = vec![("ann", 30), ("bob", 5), ("ann", 10), ("ann", 20), ("bob", 50)];
+    /// let lookup = purchases.into_iter()
+    ///     .into_grouping_map()
+    ///     .k_smallest_by_key(1, |_name, &amount| amount);
+    ///
+    /// assert_eq!(lookup["ann"], vec![10]);
+    /// assert_eq!(lookup["bob"], vec![5]);
+    /// ```
+    pub fn k_smallest_by_key<F, CK>(self, k: usize, mut f: F) -> HashMap<K, Vec<V>, S>
+        where F: FnMut(&K, &V) -> CK,
+              CK: Ord,
+    {
+        self.k_smallest_by(k, |key, v1, v2| f(key, v1).cmp(&f(key, v2)))
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and keeps the `k` largest elements
+    /// of each group, in a bounded heap.
+    ///
+    /// Returns a `HashMap` associating the key of each group with its `k` largest elements,
+    /// or all of them if it has fewer, sorted in descending order. The order of equal elements is
+    /// unspecified.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![1, 3, 4, 5, 7, 8, 9, 12, 13].into_iter()
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .k_largest(2);
+    ///
+    /// assert_eq!(lookup[&0], vec![12, 9]);
+    /// assert_eq!(lookup[&1], vec![13, 7]);
+    /// assert_eq!(lookup[&2], vec![8, 5]);
+    /// ```
+    pub fn k_largest(self, k: usize) -> HashMap<K, Vec<V>, S>
+        where V: Ord,
+    {
+        self.k_smallest_by(k, |_, a, b| b.cmp(a))
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and keeps the `k` elements of each
+    /// group that give the largest values from the specified function, in a bounded heap.
+    ///
+    /// Returns a `HashMap` associating the key of each group with those elements, sorted by
+    /// descending value of the function.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let purchases = vec![("ann", 30), ("bob", 5), ("ann", 10), ("ann", 20), ("bob", 50)];
+    /// let lookup = purchases.into_iter()
+    ///     .into_grouping_map()
+    ///     .k_largest_by_key(2, |_name, &amount| amount);
+    ///
+    /// assert_eq!(lookup["ann"], vec![30, 20]);
+    /// assert_eq!(lookup["bob"], vec![50, 5]);
+    /// ```
+    pub fn k_largest_by_key<F, CK>(self, k: usize, mut f: F) -> HashMap<K, Vec<V>, S>
+        where F: FnMut(&K, &V) -> CK,
+              CK: Ord,
+    {
+        self.k_smallest_by(k, |key, v1, v2| f(key, v2).cmp(&f(key, v1)))
+    }
+
+    /// Keep the `k` smallest elements of each group with respect to `compare`, sorted.
+    fn k_smallest_by<F>(self, k: usize, mut compare: F) -> HashMap<K, Vec<V>, S>
+        where F: FnMut(&K, &V, &V) -> Ordering,
+    {
+        // Each group is kept in a max-heap once it has k elements, so that
+        // its root is the element to replace by a smaller one.
+        let mut lookup = self.aggregate(|acc, key, val| {
+            let mut heap = acc.unwrap_or_else(Vec::new);
+            if heap.len() < k {
+                heap.push(val);
+                if heap.len() == k {
+                    heapify(&mut heap, |a, b| compare(key, a, b) == Ordering::Greater);
+                }
+            } else if k > 0 && compare(key, &val, &heap[0]) == Ordering::Less {
+                heap[0] = val;
+                sift_down(&mut heap, 0, |a, b| compare(key, a, b) == Ordering::Greater);
+            }
+            Some(heap)
+        });
+        for (key, heap) in lookup.iter_mut() {
+            heap.sort_by(|a, b| compare(key, a, b));
+        }
+        lookup
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and samples up to `k` elements of
+    /// each group uniformly at random, using `rng`.
+    ///
+    /// This is a reservoir sampling: no group is ever stored with more than `k` elements.
+    /// The sample of a group with at most `k` elements is the whole group, in order;
+    /// otherwise the order of the sampled elements is unspecified. The sizes of the groups are
+    /// then dropped in a second map, which needs the hasher to be `Clone`.
+    ///
+    /// Returns a `HashMap` associating the key of each group with the sampled elements.
+    ///
+    /// Requires the crate feature `rand`, with `use_std`.
+    ///
+    /// ```
+    /// extern crate itertools;
+    /// extern crate rand;
+    ///
+    /// use itertools::Itertools;
+    ///
+    /// # fn main() {
+    /// let lookup = (0..1000)
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .sample(5, &mut rand::thread_rng());
+    ///
+    /// for (key, sample) in lookup {
+    ///     assert_eq!(sample.len(), 5);
+    ///     assert!(sample.iter().all(|n| n % 3 == key));
+    /// }
+    /// # }
+    /// ```
+    #[cfg(all(feature = "rand", feature = "use_std"))]
+    pub fn sample<R>(self, k: usize, rng: &mut R) -> HashMap<K, Vec<V>, S>
+        where R: Rng + ?Sized,
+              S: Clone,
+    {
+        let hasher = self.hasher.clone();
+        let reservoirs = self.aggregate(|acc, _key, val| {
+            let (mut sample, seen) = acc.unwrap_or_else(|| (Vec::new(), 0));
+            let seen = seen + 1;
+            if sample.len() < k {
+                sample.push(val);
+            } else {
+                let index = rng.gen_range(0, seen);
+                if index < k {
+                    sample[index] = val;
+                }
+            }
+            Some((sample, seen))
+        });
+        let mut samples = HashMap::with_capacity_and_hasher(reservoirs.len(), hasher);
+        samples.extend(reservoirs.into_iter().map(|(key, (sample, _))| (key, sample)));
+        samples
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and finds the maximum of each group.
+    ///
+    /// If several elements are equally maximum, the last element is picked.
+    ///
+    /// Returns a `HashMap` associating the key of each group with the maximum of that group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![1, 3, 4, 5, 7, 8, 9, 12].into_iter()
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .max();
+    ///
+    /// assert_eq!(lookup[&0], 12);
+    /// assert_eq!(lookup[&1], 7);
+    /// assert_eq!(lookup[&2], 8);
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn max(self) -> HashMap<K, V, S>
+        where V: Ord,
+    {
+        self.max_by(|_, v1, v2| V::cmp(v1, v2))
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and finds the maximum of each group
+    /// with respect to the specified comparison function.
+    ///
+    /// If several elements are equally maximum, the last element is picked.
+    ///
+    /// Returns a `HashMap` associating the key of each group with the maximum of that group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![1, 3, 4, 5, 7, 8, 9, 12].into_iter()
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .max_by(|_key, x, y| y.cmp(x));
+    ///
+    /// assert_eq!(lookup[&0], 3);
+    /// assert_eq!(lookup[&1], 1);
+    /// assert_eq!(lookup[&2], 5);
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn max_by<F>(self, mut compare: F) -> HashMap<K, V, S>
+        where F: FnMut(&K, &V, &V) -> Ordering,
+    {
+        self.fold_first(|acc, key, val| match compare(key, &acc, &val) {
+            Ordering::Less | Ordering::Equal => val,
+            Ordering::Greater => acc,
+        })
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and finds the element of each group
+    /// that gives the maximum from the specified function.
+    ///
+    /// If several elements are equally maximum, the last element is picked.
+    ///
+    /// Returns a `HashMap` associating the key of each group with the maximum of that group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![1, 3, 4, 5, 7, 8, 9, 12].into_iter()
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .max_by_key(|_key, &val| val % 4);
+    ///
+    /// assert_eq!(lookup[&0], 3);
+    /// assert_eq!(lookup[&1], 7);
+    /// assert_eq!(lookup[&2], 5);
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn max_by_key<F, CK>(self, mut f: F) -> HashMap<K, V, S>
+        where F: FnMut(&K, &V) -> CK,
+              CK: Ord,
+    {
+        self.max_by(|key, v1, v2| f(key, v1).cmp(&f(key, v2)))
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and finds the minimum of each group.
+    ///
+    /// If several elements are equally minimum, the first element is picked.
+    ///
+    /// Returns a `HashMap` associating the key of each group with the minimum of that group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![1, 3, 4, 5, 7, 8, 9, 12].into_iter()
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .min();
+    ///
+    /// assert_eq!(lookup[&0], 3);
+    /// assert_eq!(lookup[&1], 1);
+    /// assert_eq!(lookup[&2], 5);
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn min(self) -> HashMap<K, V, S>
+        where V: Ord,
+    {
+        self.min_by(|_, v1, v2| V::cmp(v1, v2))
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and finds the minimum of each group
+    /// with respect to the specified comparison function.
+    ///
+    /// If several elements are equally minimum, the first element is picked.
+    ///
+    /// Returns a `HashMap` associating the key of each group with the minimum of that group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![1, 3, 4, 5, 7, 8, 9, 12].into_iter()
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .min_by(|_key, x, y| y.cmp(x));
+    ///
+    /// assert_eq!(lookup[&0], 12);
+    /// assert_eq!(lookup[&1], 7);
+    /// assert_eq!(lookup[&2], 8);
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn min_by<F>(self, mut compare: F) -> HashMap<K, V, S>
+        where F: FnMut(&K, &V, &V) -> Ordering,
+    {
+        self.fold_first(|acc, key, val| match compare(key, &acc, &val) {
+            Ordering::Less | Ordering::Equal => acc,
+            Ordering::Greater => val,
+        })
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and finds the element of each group
+    /// that gives the minimum from the specified function.
+    ///
+    /// If several elements are equally minimum, the first element is picked.
+    ///
+    /// Returns a `HashMap` associating the key of each group with the minimum of that group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![1, 3, 4, 5, 7, 8, 9, 12].into_iter()
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .min_by_key(|_key, &val| val % 4);
+    ///
+    /// assert_eq!(lookup[&0], 12);
+    /// assert_eq!(lookup[&1], 4);
+    /// assert_eq!(lookup[&2], 8);
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn min_by_key<F, CK>(self, mut f: F) -> HashMap<K, V, S>
+        where F: FnMut(&K, &V) -> CK,
+              CK: Ord,
+    {
+        self.min_by(|key, v1, v2| f(key, v1).cmp(&f(key, v2)))
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and find the maximum and minimum of
+    /// each group.
+    ///
+    /// If several elements are equally maximum, the last element is picked.
+    /// If several elements are equally minimum, the first element is picked.
+    ///
+    /// See [.minmax()](../trait.Itertools.html#method.minmax) for the non-grouping version.
+    ///
+    /// Differences from the non grouping version:
+    /// - It never produces a `MinMaxResult::NoElements`
+    /// - It doesn't have any speedup
+    ///
+    /// Returns a `HashMap` associating the key of each group with the minimum and maximum of that group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use itertools::MinMaxResult::{OneElement, MinMax};
+    ///
+    /// let lookup = vec![1, 3, 4, 5, 7, 9, 12].into_iter()
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .minmax();
+    ///
+    /// assert_eq!(lookup[&0], MinMax(3, 12));
+    /// assert_eq!(lookup[&1], MinMax(1, 7));
+    /// assert_eq!(lookup[&2], OneElement(5));
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn minmax(self) -> HashMap<K, MinMaxResult<V>, S>
+        where V: Ord,
+    {
+        self.minmax_by(|_, v1, v2| V::cmp(v1, v2))
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and find the maximum and minimum of
+    /// each group with respect to the specified comparison function.
+    ///
+    /// If several elements are equally maximum, the last element is picked.
+    /// If several elements are equally minimum, the first element is picked.
+    ///
+    /// It has the same differences from the non-grouping version as `minmax`.
+    ///
+    /// Returns a `HashMap` associating the key of each group with the minimum and maximum of that group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use itertools::MinMaxResult::{OneElement, MinMax};
+    ///
+    /// let lookup = vec![1, 3, 4, 5, 7, 9, 12].into_iter()
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .minmax_by(|_key, x, y| y.cmp(x));
+    ///
+    /// assert_eq!(lookup[&0], MinMax(12, 3));
+    /// assert_eq!(lookup[&1], MinMax(7, 1));
+    /// assert_eq!(lookup[&2], OneElement(5));
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn minmax_by<F>(self, mut compare: F) -> HashMap<K, MinMaxResult<V>, S>
+        where F: FnMut(&K, &V, &V) -> Ordering,
+    {
+        self.aggregate(|acc, key, val| {
+            Some(match acc {
+                Some(MinMaxResult::OneElement(e)) => {
+                    if compare(key, &val, &e) == Ordering::Less {
+                        MinMaxResult::MinMax(val, e)
+                    } else {
+                        MinMaxResult::MinMax(e, val)
+                    }
+                }
+                Some(MinMaxResult::MinMax(min, max)) => {
+                    if compare(key, &val, &min) == Ordering::Less {
+                        MinMaxResult::MinMax(val, max)
+                    } else if compare(key, &val, &max) != Ordering::Less {
+                        MinMaxResult::MinMax(min, val)
+                    } else {
+                        MinMaxResult::MinMax(min, max)
+                    }
+                }
+                None => MinMaxResult::OneElement(val),
+                Some(MinMaxResult::NoElements) => unreachable!(),
+            })
+        })
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and find the elements of each group
+    /// that gives the minimum and maximum from the specified function.
+    ///
+    /// If several elements are equally maximum, the last element is picked.
+    /// If several elements are equally minimum, the first element is picked.
+    ///
+    /// It has the same differences from the non-grouping version as `minmax`.
+    ///
+    /// Returns a `HashMap` associating the key of each group with the minimum and maximum of that group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use itertools::MinMaxResult::{OneElement, MinMax};
+    ///
+    /// let lookup = vec![1, 3, 4, 5, 7, 9, 12].into_iter()
+    ///     .map(|n| (n % 3, n))
+    ///     .into_grouping_map()
+    ///     .minmax_by_key(|_key, &val| val % 4);
+    ///
+    /// assert_eq!(lookup[&0], MinMax(12, 3));
+    /// assert_eq!(lookup[&1], MinMax(4, 7));
+    /// assert_eq!(lookup[&2], OneElement(5));
+    /// assert_eq!(lookup.len(), 3);
+    /// ```
+    pub fn minmax_by_key<F, CK>(self, mut f: F) -> HashMap<K, MinMaxResult<V>, S>
+        where F: FnMut(&K, &V) -> CK,
+              CK: Ord,
+    {
+        self.minmax_by(|key, v1, v2| f(key, v1).cmp(&f(key, v2)))
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and sums them,
+    /// checking for overflow.
+    ///
+    /// Return a `HashMap` associating the key of each group with the sum
+    /// of that group's elements, or `None` if computing it overflowed.
+    /// Once a group overflows, its remaining elements are ignored.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![(1, 100u8), (2, 100), (1, 100), (2, 100), (2, 100)].into_iter()
+    ///     .into_grouping_map()
+    ///     .checked_sum();
+    ///
+    /// assert_eq!(lookup[&1], Some(200));
+    /// assert_eq!(lookup[&2], None);
+    /// assert_eq!(lookup.len(), 2);
+    /// ```
+    pub fn checked_sum(self) -> HashMap<K, Option<V>, S>
+        where V: CheckedArith,
+    {
+        self.checked_fold_first(V::checked_add)
+    }
+
+    /// Groups elements from the `GroupingMap` source by key and multiplies
+    /// them, checking for overflow.
+    ///
+    /// Return a `HashMap` associating the key of each group with the product
+    /// of that group's elements, or `None` if computing it overflowed.
+    /// Once a group overflows, its remaining elements are ignored.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![(0, 10u8), (1, 20), (0, 12), (1, 20)].into_iter()
+    ///     .into_grouping_map()
+    ///     .checked_product();
+    ///
+    /// assert_eq!(lookup[&0], Some(120));
+    /// assert_eq!(lookup[&1], None); // 400 overflows u8
+    /// ```
+    pub fn checked_product(self) -> HashMap<K, Option<V>, S>
+        where V: CheckedArith,
+    {
+        self.checked_fold_first(V::checked_mul)
+    }
+
+    fn checked_fold_first<FO>(self, mut operation: FO) -> HashMap<K, Option<V>, S>
+        where FO: FnMut(V, V) -> Option<V>,
+    {
+        self.aggregate(|acc, _key, val| {
+            Some(match acc {
+                None => Some(val),
+                Some(Some(acc)) => operation(acc, val),
+                Some(None) => None,
+            })
+        })
+    }
+
+    /// Groups the elements of each group again, by the key that
+    /// `key_mapper` returns for them, for a two-level group-and-fold
+    /// operation.
+    ///
+    /// See [`NestedGroupingMap`](struct.NestedGroupingMap.html) for the
+    /// operations available, which return a `HashMap` of `HashMap`s without
+    /// collecting the elements of a group first.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let sales = vec![("north", 2019, 10), ("south", 2019, 5), ("north", 2020, 7),
+    ///                  ("north", 2019, 1), ("south", 2020, 3)];
+    /// let lookup = sales.into_iter()
+    ///     .into_grouping_map_by(|&(region, _, _)| region)
+    ///     .nested_by(|&(_, year, _)| year)
+    ///     .fold(0, |acc, _region, _year, (_, _, amount)| acc + amount);
+    ///
+    /// assert_eq!(lookup["north"][&2019], 11);
+    /// assert_eq!(lookup["north"][&2020], 7);
+    /// assert_eq!(lookup["south"][&2019], 5);
+    /// assert_eq!(lookup["south"][&2020], 3);
+    /// ```
+    pub fn nested_by<K2, F>(self, key_mapper: F) -> NestedGroupingMap<I, F, S>
+        where K2: Hash + Eq,
+              F: FnMut(&V) -> K2,
+    {
+        NestedGroupingMap { grouping_map: self, key_mapper }
+    }
+}
+
+/// `NestedGroupingMap` is an intermediate struct for efficient two-level
+/// group-and-fold operations.
+///
+/// It groups elements by the key of the [`GroupingMap`](struct.GroupingMap.html)
+/// it was made from, then the elements of each group by a second key, and
+/// folds the inner groups at the same time. The inner maps use the default
+/// hasher.
+///
+/// See [`GroupingMap::nested_by`](struct.GroupingMap.html#method.nested_by) for more information.
+#[derive(Clone)]
+#[must_use = "NestedGroupingMap is lazy and do nothing unless consumed"]
+pub struct NestedGroupingMap<I, F, S = RandomState> {
+    grouping_map: GroupingMap<I, S>,
+    key_mapper: F,
+}
+
+impl<I, F, S> fmt::Debug for NestedGroupingMap<I, F, S>
+    where I: fmt::Debug,
+          S: fmt::Debug,
+{
+    debug_fmt_fields!(NestedGroupingMap, grouping_map);
+}
+
+impl<I, K, V, K2, F, S> NestedGroupingMap<I, F, S>
+    where I: Iterator<Item = (K, V)>,
+          K: Hash + Eq,
+          K2: Hash + Eq,
+          F: FnMut(&V) -> K2,
+          S: BuildHasher,
+{
+    /// Like [`GroupingMap::aggregate`](struct.GroupingMap.html#method.aggregate),
+    /// with two levels of groups: `operation` is passed references to both
+    /// keys of the element.
+    ///
+    /// If the aggregation of the last element of an inner group discards the
+    /// accumulator, there won't be an entry for its key in the inner map,
+    /// nor in the outer map if the inner map is left empty.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = (0..20)
+    ///     .into_grouping_map_by(|n| n % 2)
+    ///     .nested_by(|n| n % 3)
+    ///     .aggregate(|acc, _, _, n| if n < 10 { Some(acc.unwrap_or(0) + n) } else { acc });
+    ///
+    /// assert_eq!(lookup[&0][&0], 0 + 6);
+    /// assert_eq!(lookup[&1][&2], 5);
+    /// assert_eq!(lookup[&1].len(), 3);
+    /// ```
+    pub fn aggregate<FO, R>(self, mut operation: FO) -> HashMap<K, HashMap<K2, R>, S>
+        where FO: FnMut(Option<R>, &K, &K2, V) -> Option<R>,
+    {
+        let NestedGroupingMap { grouping_map, mut key_mapper } = self;
+        grouping_map.aggregate(|inner, key, val| {
+            let mut inner = inner.unwrap_or_else(HashMap::new);
+            let key2 = key_mapper(&val);
+            let acc = inner.remove(&key2);
+            if let Some(op_res) = operation(acc, key, &key2, val) {
+                inner.insert(key2, op_res);
+            }
+            if inner.is_empty() {
+                None
+            } else {
+                Some(inner)
+            }
+        })
+    }
+
+    /// Like [`GroupingMap::fold`](struct.GroupingMap.html#method.fold), with
+    /// two levels of groups: `operation` is passed references to both keys
+    /// of the element.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = (0..20)
+    ///     .into_grouping_map_by(|n| n % 2)
+    ///     .nested_by(|n| n % 3)
+    ///     .fold(0, |acc, _, _, n| acc + n);
+    ///
+    /// assert_eq!(lookup[&0][&0], 0 + 6 + 12 + 18);
+    /// assert_eq!(lookup[&1][&1], 1 + 7 + 13 + 19);
+    /// assert_eq!(lookup[&1].len(), 3);
+    /// ```
+    pub fn fold<FO, R>(self, init: R, mut operation: FO) -> HashMap<K, HashMap<K2, R>, S>
+        where R: Clone,
+              FO: FnMut(R, &K, &K2, V) -> R,
+    {
+        self.aggregate(|acc, key, key2, val| {
+            let acc = acc.unwrap_or_else(|| init.clone());
+            Some(operation(acc, key, key2, val))
+        })
+    }
+
+    /// Like [`GroupingMap::fold_first`](struct.GroupingMap.html#method.fold_first),
+    /// with two levels of groups: `operation` is passed references to both
+    /// keys of the element.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = (1..20)
+    ///     .into_grouping_map_by(|n| n % 2)
+    ///     .nested_by(|n| n % 3)
+    ///     .fold_first(|acc, _, _, n| acc * 100 + n);
+    ///
+    /// assert_eq!(lookup[&0][&0], 61218);
+    /// assert_eq!(lookup[&1][&1], 1071319);
+    /// ```
+    pub fn fold_first<FO>(self, mut operation: FO) -> HashMap<K, HashMap<K2, V>, S>
+        where FO: FnMut(V, &K, &K2, V) -> V,
+    {
+        self.aggregate(|acc, key, key2, val| {
+            Some(match acc {
+                Some(acc) => operation(acc, key, key2, val),
+                None => val,
+            })
+        })
+    }
+
+    /// Like [`GroupingMap::collect`](struct.GroupingMap.html#method.collect),
+    /// with two levels of groups.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = (0..10)
+    ///     .into_grouping_map_by(|n| n % 2)
+    ///     .nested_by(|n| n % 3)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(lookup[&0][&0], vec![0, 6]);
+    /// assert_eq!(lookup[&1][&2], vec![5]);
+    /// ```
+    pub fn collect<C>(self) -> HashMap<K, HashMap<K2, C>, S>
+        where C: Default + Extend<V>,
+    {
+        let NestedGroupingMap { grouping_map, mut key_mapper } = self;
+        grouping_map.aggregate(|inner, _key, val| {
+            let mut inner: HashMap<K2, C> = inner.unwrap_or_else(HashMap::new);
+            inner.entry_or_default(key_mapper(&val)).extend(Some(val));
+            Some(inner)
+        })
+    }
+}
@@ -0,0 +1,158 @@
+use std::fmt;
+use std::iter::Fuse;
+
+use size_hint;
+
+/// `GroupingRuns` is an intermediate struct for group-and-fold operations
+/// on a source sorted, or at least clustered, by key.
+///
+/// Unlike [`GroupingMap`](struct.GroupingMap.html), which needs to store
+/// every group until the source is exhausted, its operations are lazy
+/// iterators that yield the result of a run of equal keys as soon as the
+/// run ends. If the same key comes back after another key, it starts a new
+/// run.
+///
+/// See [`.into_grouping_runs()`](../trait.Itertools.html#method.into_grouping_runs)
+/// for more information.
+#[must_use = "GroupingRuns is lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct GroupingRuns<I> {
+    iter: I,
+}
+
+/// Creates a new `GroupingRuns` from `iter`
+pub fn new<I, K, V>(iter: I) -> GroupingRuns<I>
+    where I: Iterator<Item = (K, V)>,
+          K: PartialEq,
+{
+    GroupingRuns { iter }
+}
+
+impl<I, K, V> GroupingRuns<I>
+    where I: Iterator<Item = (K, V)>,
+          K: PartialEq,
+{
+    /// Return an iterator adaptor that folds the elements of each run of
+    /// equal keys, and yields the key of the run with the result.
+    ///
+    /// `init` is called to obtain the initial value of the accumulator of
+    /// each run, with a reference to the key of the run and to its first
+    /// element. `operation` is then called on each element of the run with
+    /// the current accumulator, a reference to the key and the element.
+    ///
+    /// Iterator element type is `(K, R)`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let log = vec![("mon", 3), ("mon", 5), ("tue", 1), ("wed", 2), ("wed", 7)];
+    /// let totals = log.into_iter()
+    ///     .into_grouping_runs()
+    ///     .fold_with(|_day, _hours| 0, |acc, _day, hours| acc + hours);
+    ///
+    /// itertools::assert_equal(totals, vec![("mon", 8), ("tue", 1), ("wed", 9)]);
+    /// ```
+    pub fn fold_with<FI, FO, R>(self, init: FI, operation: FO) -> FoldRuns<I, FI, FO>
+        where FI: FnMut(&K, &V) -> R,
+              FO: FnMut(R, &K, V) -> R,
+    {
+        FoldRuns {
+            iter: self.iter.fuse(),
+            init,
+            operation,
+            pending: None,
+        }
+    }
+
+    /// Return an iterator adaptor that collects the elements of each run of
+    /// equal keys in an instance of `C`, and yields the key of the run with
+    /// the collection.
+    ///
+    /// Iterator element type is `(K, C)`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![(1, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+    /// let runs = data.into_iter()
+    ///     .into_grouping_runs()
+    ///     .collect::<Vec<_>>();
+    ///
+    /// itertools::assert_equal(runs, vec![(1, vec!['a', 'b']), (2, vec!['c']), (1, vec!['d'])]);
+    /// ```
+    pub fn collect<C>(self) -> CollectRuns<I, K, V, C>
+        where C: Default + Extend<V>,
+    {
+        fn init<K, V, C: Default>(_: &K, _: &V) -> C {
+            C::default()
+        }
+        fn extend<K, V, C: Extend<V>>(mut acc: C, _: &K, val: V) -> C {
+            acc.extend(Some(val));
+            acc
+        }
+        self.fold_with(init, extend)
+    }
+}
+
+/// An iterator adaptor that collects the elements of each run of equal keys.
+///
+/// See [`GroupingRuns::collect`](struct.GroupingRuns.html#method.collect) for more information.
+pub type CollectRuns<I, K, V, C> = FoldRuns<I, fn(&K, &V) -> C, fn(C, &K, V) -> C>;
+
+/// An iterator adaptor that folds the elements of each run of equal keys.
+///
+/// Iterator element type is `(K, R)`.
+///
+/// This iterator is *fused*.
+///
+/// See [`GroupingRuns`](struct.GroupingRuns.html) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct FoldRuns<I, FI, FO>
+    where I: Iterator
+{
+    iter: Fuse<I>,
+    init: FI,
+    operation: FO,
+    /// The first element of the next run, if already taken
+    pending: Option<I::Item>,
+}
+
+impl<I, FI, FO> fmt::Debug for FoldRuns<I, FI, FO>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(FoldRuns, iter, pending);
+}
+
+impl<I, K, V, FI, FO, R> Iterator for FoldRuns<I, FI, FO>
+    where I: Iterator<Item = (K, V)>,
+          K: PartialEq,
+          FI: FnMut(&K, &V) -> R,
+          FO: FnMut(R, &K, V) -> R,
+{
+    type Item = (K, R);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, val) = match self.pending.take() {
+            Some(elt) => elt,
+            None => self.iter.next()?,
+        };
+        let acc = (self.init)(&key, &val);
+        let mut acc = (self.operation)(acc, &key, val);
+        for (next_key, val) in &mut self.iter {
+            if next_key != key {
+                self.pending = Some((next_key, val));
+                break;
+            }
+            acc = (self.operation)(acc, &key, val);
+        }
+        Some((key, acc))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = size_hint::add_scalar(self.iter.size_hint(),
+                                                   self.pending.is_some() as usize);
+        ((lower > 0) as usize, upper)
+    }
+}
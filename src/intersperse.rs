@@ -1,4 +1,5 @@
 use std::iter::Fuse;
+use std::usize;
 use super::size_hint;
 
 #[derive(Clone)]
@@ -77,3 +78,79 @@ impl<I> Iterator for Intersperse<I>
         })
     }
 }
+
+/// An iterator adaptor to insert a particular value after every `n`
+/// elements of the adapted iterator, as long as more elements follow.
+///
+/// Iterator element type is `I::Item`
+///
+/// This iterator is *fused*.
+///
+/// See [`.intersperse_every()`](../trait.Itertools.html#method.intersperse_every) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct IntersperseEvery<I>
+    where I: Iterator
+{
+    element: I::Item,
+    iter: Fuse<I>,
+    peek: Option<I::Item>,
+    n: usize,
+    count: usize,
+}
+
+/// Create a new IntersperseEvery iterator
+pub fn intersperse_every<I>(iter: I, n: usize, elt: I::Item) -> IntersperseEvery<I>
+    where I: Iterator
+{
+    assert!(n != 0);
+    let mut iter = iter.fuse();
+    IntersperseEvery {
+        peek: iter.next(),
+        iter,
+        element: elt,
+        n,
+        count: 0,
+    }
+}
+
+impl<I> IntersperseEvery<I>
+    where I: Iterator
+{
+    /// Total number of elements yielded for `rest` remaining source elements.
+    fn with_separators(&self, rest: usize) -> Option<usize> {
+        if rest == 0 {
+            return Some(0);
+        }
+        // A separator follows each complete group that is not the last one
+        self.count.checked_add(rest - 1)
+            .and_then(|x| (x / self.n).checked_add(rest))
+    }
+}
+
+impl<I> Iterator for IntersperseEvery<I>
+    where I: Iterator,
+          I::Item: Clone
+{
+    type Item = I::Item;
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        let elt = self.peek.take()?;
+        if self.count == self.n {
+            self.count = 0;
+            self.peek = Some(elt);
+            Some(self.element.clone())
+        } else {
+            self.count += 1;
+            self.peek = self.iter.next();
+            Some(elt)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let has_peek = self.peek.is_some() as usize;
+        let (lo, hi) = size_hint::add_scalar(self.iter.size_hint(), has_peek);
+        (self.with_separators(lo).unwrap_or(usize::MAX),
+         hi.and_then(|hi| self.with_separators(hi)))
+    }
+}
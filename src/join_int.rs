@@ -0,0 +1,97 @@
+use std::str;
+
+const DIGIT_PAIRS: &[u8; 200] = b"\
+    0001020304050607080910111213141516171819\
+    2021222324252627282930313233343536373839\
+    4041424344454647484950515253545556575859\
+    6061626364656667686970717273747576777879\
+    8081828384858687888990919293949596979899";
+
+/// An integer type that [`.join_int()`](trait.Itertools.html#method.join_int)
+/// can format without going through `std::fmt`.
+///
+/// Implemented for the primitive integer types and references to them.
+pub trait JoinInt {
+    #[doc(hidden)]
+    fn push_to(&self, out: &mut String);
+}
+
+/// Append the decimal digits of `n` to `out`, two at a time, using a
+/// stack buffer.
+fn push_u64(mut n: u64, out: &mut String) {
+    let mut buf = [0u8; 20];
+    let mut pos = buf.len();
+    while n >= 100 {
+        let d = (n % 100) as usize * 2;
+        n /= 100;
+        pos -= 2;
+        buf[pos..pos + 2].copy_from_slice(&DIGIT_PAIRS[d..d + 2]);
+    }
+    if n >= 10 {
+        let d = n as usize * 2;
+        pos -= 2;
+        buf[pos..pos + 2].copy_from_slice(&DIGIT_PAIRS[d..d + 2]);
+    } else {
+        pos -= 1;
+        buf[pos] = b'0' + n as u8;
+    }
+    out.push_str(str::from_utf8(&buf[pos..]).unwrap());
+}
+
+fn push_i64(n: i64, out: &mut String) {
+    if n < 0 {
+        out.push('-');
+        // wrapping_neg keeps i64::MIN, which is then read back correctly
+        push_u64(n.wrapping_neg() as u64, out);
+    } else {
+        push_u64(n as u64, out);
+    }
+}
+
+macro_rules! impl_join_int {
+    ($push:ident, $wide:ty; $($t:ty)*) => {
+        $(
+            impl JoinInt for $t {
+                #[inline]
+                fn push_to(&self, out: &mut String) {
+                    $push(*self as $wide, out)
+                }
+            }
+        )*
+    }
+}
+
+impl_join_int!(push_u64, u64; u8 u16 u32 u64 usize);
+impl_join_int!(push_i64, i64; i8 i16 i32 i64 isize);
+
+impl<'a, T> JoinInt for &'a T
+    where T: JoinInt + ?Sized
+{
+    #[inline]
+    fn push_to(&self, out: &mut String) {
+        (**self).push_to(out)
+    }
+}
+
+/// See [`.join_int()`](../trait.Itertools.html#method.join_int) for more information.
+pub fn join_int<I>(iter: &mut I, sep: &str) -> String
+    where I: Iterator + ?Sized,
+          I::Item: JoinInt,
+{
+    match iter.next() {
+        None => String::new(),
+        Some(first_elt) => {
+            let mut result = String::new();
+            first_elt.push_to(&mut result);
+            // estimate the capacity needed from the width of the first element
+            let (lower, _) = iter.size_hint();
+            let elt_len = result.len();
+            result.reserve(lower.saturating_mul(sep.len() + elt_len));
+            for elt in iter {
+                result.push_str(sep);
+                elt.push_to(&mut result);
+            }
+            result
+        }
+    }
+}
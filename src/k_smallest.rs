@@ -0,0 +1,27 @@
+use std::cmp::Ordering;
+
+use kmerge_impl::{heapify, sift_down};
+
+/// Return the `k` smallest elements of `iter` with respect to `compare`,
+/// sorted, keeping at most `k` elements in memory.
+pub fn k_smallest_by<I, F>(mut iter: I, k: usize, mut compare: F) -> Vec<I::Item>
+    where I: Iterator,
+          F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    if k == 0 {
+        return Vec::new();
+    }
+    let mut heap = iter.by_ref().take(k).collect::<Vec<_>>();
+    if heap.len() == k {
+        // A max-heap, so that its root is the element to replace by a smaller one.
+        heapify(&mut heap, |a, b| compare(a, b) == Ordering::Greater);
+        for elt in iter {
+            if compare(&elt, &heap[0]) == Ordering::Less {
+                heap[0] = elt;
+                sift_down(&mut heap, 0, |a, b| compare(a, b) == Ordering::Greater);
+            }
+        }
+    }
+    heap.sort_by(compare);
+    heap
+}
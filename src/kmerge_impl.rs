@@ -71,7 +71,7 @@ impl<I> Clone for HeadTail<I>
 }
 
 /// Make `data` a heap (min-heap w.r.t the sorting).
-fn heapify<T, S>(data: &mut [T], mut less_than: S)
+pub(crate) fn heapify<T, S>(data: &mut [T], mut less_than: S)
     where S: FnMut(&T, &T) -> bool
 {
     for i in (0..data.len() / 2).rev() {
@@ -80,7 +80,7 @@ fn heapify<T, S>(data: &mut [T], mut less_than: S)
 }
 
 /// Sift down element at `index` (`heap` is a min-heap wrt the ordering)
-fn sift_down<T, S>(heap: &mut [T], index: usize, mut less_than: S)
+pub(crate) fn sift_down<T, S>(heap: &mut [T], index: usize, mut less_than: S)
     where S: FnMut(&T, &T) -> bool
 {
     debug_assert!(index <= heap.len());
@@ -229,3 +229,196 @@ impl<I, F> Iterator for KMergeBy<I, F>
                  .unwrap_or((0, Some(0)))
     }
 }
+
+/// An iterator adaptor that merges an arbitrary number of base iterators in
+/// ascending order, skipping elements equal to the one before them.
+/// If all base iterators are sorted (ascending), the result is their sorted
+/// set union.
+///
+/// Iterator element type is `I::Item`.
+///
+/// See [`.kmerge_dedup()`](../trait.Itertools.html#method.kmerge_dedup) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct KMergeDedup<I>
+    where I: Iterator,
+{
+    merge: KMerge<I>,
+    pending: Option<I::Item>,
+}
+
+impl<I> fmt::Debug for KMergeDedup<I>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(KMergeDedup, merge, pending);
+}
+
+impl<I> Clone for KMergeDedup<I>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+{
+    fn clone(&self) -> KMergeDedup<I> {
+        clone_fields!(KMergeDedup, self, merge, pending)
+    }
+}
+
+/// Create an iterator that merges elements of the contained iterators,
+/// skipping duplicates.
+///
+/// Equivalent to `iterable.into_iter().kmerge_dedup()`.
+pub fn kmerge_dedup<I>(iterable: I) -> KMergeDedup<<I::Item as IntoIterator>::IntoIter>
+    where I: IntoIterator,
+          I::Item: IntoIterator,
+          <<I as IntoIterator>::Item as IntoIterator>::Item: PartialOrd
+{
+    KMergeDedup { merge: kmerge(iterable), pending: None }
+}
+
+impl<I> Iterator for KMergeDedup<I>
+    where I: Iterator,
+          I::Item: PartialOrd,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = match self.pending.take() {
+            Some(elt) => elt,
+            None => self.merge.next()?,
+        };
+        // skip the duplicates of result, keeping the next distinct element
+        for elt in &mut self.merge {
+            if elt != result {
+                self.pending = Some(elt);
+                break;
+            }
+        }
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = size_hint::add_scalar(self.merge.size_hint(),
+                                                   self.pending.is_some() as usize);
+        ((lower > 0) as usize, upper)
+    }
+}
+
+/// An iterator adaptor that merges an arbitrary number of base iterators
+/// according to a fallible ordering function.
+///
+/// Iterator element type is `Result<I::Item, E>`.
+///
+/// See [`.try_kmerge_by()`](../trait.Itertools.html#method.try_kmerge_by) for more
+/// information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct TryKMergeBy<I, F, E>
+    where I: Iterator,
+{
+    heap: Vec<HeadTail<I>>,
+    less_than: F,
+    /// The first error of the comparator, not yet yielded
+    error: Option<E>,
+    done: bool,
+}
+
+impl<I, F, E> fmt::Debug for TryKMergeBy<I, F, E>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+          E: fmt::Debug,
+{
+    debug_fmt_fields!(TryKMergeBy, heap, error, done);
+}
+
+impl<I, F, E> Clone for TryKMergeBy<I, F, E>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+          F: Clone,
+          E: Clone,
+{
+    fn clone(&self) -> TryKMergeBy<I, F, E> {
+        clone_fields!(TryKMergeBy, self, heap, less_than, error, done)
+    }
+}
+
+/// Call the fallible `less_than` for the heap functions, keeping the first
+/// error in `error` (and leaving the heap alone after it).
+fn try_less_than<T, F, E>(less_than: &mut F, error: &mut Option<E>, a: &T, b: &T) -> bool
+    where F: FnMut(&T, &T) -> Result<bool, E>,
+{
+    if error.is_some() {
+        return false;
+    }
+    match less_than(a, b) {
+        Ok(less) => less,
+        Err(e) => {
+            *error = Some(e);
+            false
+        }
+    }
+}
+
+/// Create an iterator that merges elements of the contained iterators
+/// using a fallible ordering function.
+///
+/// Equivalent to `iterable.into_iter().try_kmerge_by(less_than)`.
+pub fn try_kmerge_by<I, F, E>(iterable: I, mut less_than: F)
+    -> TryKMergeBy<<I::Item as IntoIterator>::IntoIter, F, E>
+    where I: IntoIterator,
+          I::Item: IntoIterator,
+          F: FnMut(&<<I as IntoIterator>::Item as IntoIterator>::Item,
+                   &<<I as IntoIterator>::Item as IntoIterator>::Item) -> Result<bool, E>,
+{
+    let iter = iterable.into_iter();
+    let (lower, _) = iter.size_hint();
+    let mut heap: Vec<_> = Vec::with_capacity(lower);
+    heap.extend(iter.filter_map(|it| HeadTail::new(it.into_iter())));
+    let mut error = None;
+    heapify(&mut heap, |a, b| try_less_than(&mut less_than, &mut error, &a.head, &b.head));
+    TryKMergeBy { heap, less_than, error, done: false }
+}
+
+impl<I, F, E> Iterator for TryKMergeBy<I, F, E>
+    where I: Iterator,
+          F: FnMut(&I::Item, &I::Item) -> Result<bool, E>,
+{
+    type Item = Result<I::Item, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if let Some(e) = self.error.take() {
+            self.done = true;
+            return Some(Err(e));
+        }
+        if self.heap.is_empty() {
+            self.done = true;
+            return None;
+        }
+        let result = if let Some(next) = self.heap[0].next() {
+            next
+        } else {
+            self.heap.swap_remove(0).head
+        };
+        // The heap was in order before, so an error in this sift is
+        // returned by the next call only.
+        let less_than = &mut self.less_than;
+        let error = &mut self.error;
+        sift_down(&mut self.heap, 0, |a, b| try_less_than(less_than, error, &a.head, &b.head));
+        Some(Ok(result))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.error.is_some() && !self.done {
+            return (1, Some(1));
+        }
+        if self.done || self.heap.is_empty() {
+            return (0, Some(0));
+        }
+        let (_, upper) = self.heap.iter()
+                                  .map(|i| i.size_hint())
+                                  .fold1(size_hint::add)
+                                  .unwrap_or((0, Some(0)));
+        // an error can end the merge after any element
+        (1, upper.and_then(|x| x.checked_add(1)))
+    }
+}
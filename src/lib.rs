@@ -1,6 +1,7 @@
 #![warn(missing_docs)]
 #![crate_name="itertools"]
 #![cfg_attr(not(feature = "use_std"), no_std)]
+#![cfg_attr(feature = "nightly", feature(trusted_len))]
 
 //! Extra iterator adaptors, functions and macros.
 //!
@@ -40,6 +41,41 @@
 //!     any items that depend on collections (like `group_by`, `unique`,
 //!     `kmerge`, `join` and many more).
 //!
+//! - `approx_unique`
+//!   - Optional, requires `use_std`.
+//!   - Enables [`.approx_unique_count()`](./trait.Itertools.html#method.approx_unique_count),
+//!     a constant memory estimate of the number of distinct elements.
+//!
+//! - `int_join`
+//!   - Optional, requires `use_std`.
+//!   - Enables [`.join_int()`](./trait.Itertools.html#method.join_int),
+//!     a `join` for integers that formats them without `std::fmt`.
+//!
+//! - `rand`
+//!   - Optional, only has an effect with `use_std`, which is enabled by
+//!     default: on its own, it enables nothing.
+//!   - Enables [`GroupingMap::sample`](./structs/struct.GroupingMap.html#method.sample),
+//!     a reservoir sampling of each group, using the `rand` crate.
+//!
+//! - `use_rand`
+//!   - Optional, enables both `rand` and `use_std`.
+//!
+//! - `rayon`
+//!   - Optional, only has an effect with `use_std`, which is enabled by
+//!     default: on its own, it enables nothing.
+//!   - Enables [`.par_fold_chunks()`](./trait.Itertools.html#method.par_fold_chunks),
+//!     a parallel fold of the chunks of an iterator, using the `rayon` crate.
+//!
+//! - `use_rayon`
+//!   - Optional, enables both `rayon` and `use_std`.
+//!
+//! - `nightly`
+//!   - Optional, requires a nightly compiler.
+//!   - Implements the unstable `TrustedLen` trait for the adaptors that
+//!     preserve the exact length of their inputs (`multizip`, `.zip_eq()`,
+//!     `.interleave()`, `.map_into()`, `repeat_n`, ...), which lets
+//!     `.collect::<Vec<_>>()` and `Vec::extend` skip capacity checks.
+//!
 //! ## Rust Version
 //!
 //! This version of itertools requires Rust 1.24 or later.
@@ -48,6 +84,10 @@
 #![doc(html_root_url="https://docs.rs/itertools/0.8/")]
 
 extern crate either;
+#[cfg(all(feature = "rand", feature = "use_std"))]
+extern crate rand;
+#[cfg(all(feature = "use_std", feature = "rayon"))]
+extern crate rayon;
 
 #[cfg(not(feature = "use_std"))]
 extern crate core as std;
@@ -56,17 +96,18 @@ pub use either::Either;
 
 #[cfg(feature = "use_std")]
 use std::collections::HashMap;
-use std::iter::{IntoIterator};
+use std::iter::{IntoIterator, Sum};
 use std::cmp::Ordering;
 use std::fmt;
 #[cfg(feature = "use_std")]
-use std::hash::Hash;
+use std::hash::{BuildHasher, Hash};
 #[cfg(feature = "use_std")]
 use std::fmt::Write;
 #[cfg(feature = "use_std")]
 type VecIntoIter<T> = ::std::vec::IntoIter<T>;
 #[cfg(feature = "use_std")]
 use std::iter::FromIterator;
+use std::ops::{Add, Sub};
 
 #[macro_use]
 mod impl_macros;
@@ -82,120 +123,240 @@ pub mod structs {
         DedupBy,
         Interleave,
         InterleaveShortest,
+        InterleaveWeighted,
         Product,
+        ProductFlat,
         PutBack,
         Batching,
         MapInto,
         MapResults,
+        FlatMapOk,
         Merge,
         MergeBy,
         TakeWhileRef,
+        TakeWhileInclusive,
         WhileSome,
         Coalesce,
         TupleCombinations,
         Positions,
+        Stride,
         Update,
     };
     #[allow(deprecated)]
     pub use adaptors::Step;
     #[cfg(feature = "use_std")]
+    pub use adaptors::{MultiInterleave, MultiInterleaveShortest, MultiInterleaveWeighted};
+    #[cfg(feature = "use_std")]
     pub use adaptors::MultiProduct;
     #[cfg(feature = "use_std")]
+    pub use array_chunks::ArrayChunks;
+    pub use array_windows::ArrayWindows;
+    #[cfg(feature = "use_std")]
+    pub use cached_product::CachedProduct;
+    pub use cartesian_square::CartesianSquare;
+    #[cfg(feature = "use_std")]
     pub use combinations::Combinations;
     #[cfg(feature = "use_std")]
     pub use combinations_with_replacement::CombinationsWithReplacement;
     pub use cons_tuples_impl::ConsTuples;
+    pub use cumulative_fold::{CumSum, CumulativeFold};
+    pub use diffs_impl::{Diffs, DiffsBy};
+    #[cfg(feature = "use_std")]
+    pub use drop_last_impl::DropLast;
+    #[cfg(feature = "use_std")]
+    pub use duplicates_impl::{Duplicates, DuplicatesBy};
+    #[cfg(feature = "use_std")]
+    pub use duplicate_positions::DuplicatePositions;
     pub use exactly_one_err::ExactlyOneError;
-    pub use format::{Format, FormatWith};
+    pub use format::{Format, FormatDebug, FormatTruncated, FormatWith};
     #[cfg(feature = "use_std")]
     pub use groupbylazy::{IntoChunks, Chunk, Chunks, GroupBy, Group, Groups};
-    pub use intersperse::Intersperse;
     #[cfg(feature = "use_std")]
-    pub use kmerge_impl::{KMerge, KMergeBy};
+    pub use grouping_map::{GroupingMap, GroupingMapBy, NestedGroupingMap};
+    pub use grouping_runs::{CollectRuns, FoldRuns, GroupingRuns};
+    pub use intersperse::{Intersperse, IntersperseEvery};
+    #[cfg(feature = "use_std")]
+    pub use kmerge_impl::{KMerge, KMergeBy, KMergeDedup, TryKMergeBy};
+    #[cfg(feature = "use_std")]
+    pub use map_parallel::MapParallel;
     pub use merge_join::MergeJoinBy;
     #[cfg(feature = "use_std")]
     pub use multipeek_impl::MultiPeek;
+    pub use non_empty::NonEmpty;
+    #[cfg(feature = "use_std")]
+    pub use pad_front::{PadBothUsing, PadFrontUsing};
     pub use pad_tail::PadUsing;
+    #[cfg(feature = "use_std")]
+    pub use partition_lazy::PartitionLazy;
+    #[cfg(feature = "use_std")]
+    pub use peek_nth::PeekNth;
+    pub use peek_window::PeekWindow;
     pub use peeking_take_while::PeekingTakeWhile;
-    pub use process_results_impl::ProcessResults;
+    #[cfg(feature = "use_std")]
+    pub use permutation_indices::PermutationIndices;
+    #[cfg(feature = "use_std")]
+    pub use permutations::Permutations;
+    #[cfg(feature = "use_std")]
+    pub use powerset::Powerset;
+    pub use process_results_impl::{CaptureErr, ProcessResults};
     #[cfg(feature = "use_std")]
     pub use put_back_n_impl::PutBackN;
     #[cfg(feature = "use_std")]
     pub use rciter_impl::RcIter;
+    #[cfg(feature = "use_std")]
+    pub use readahead::ReadAhead;
     pub use repeatn::RepeatN;
+    #[cfg(feature = "use_std")]
+    pub use rolling::{RollingMean, RollingSum};
+    pub use run_lengths::{RunLengthDecode, RunLengthEncode, RunLengthEncodeBy, RunLengthEncodeByKey,
+                          RunLengthsBy};
     #[allow(deprecated)]
     pub use sources::{RepeatCall, Unfold, Iterate};
     #[cfg(feature = "use_std")]
-    pub use tee::Tee;
-    pub use tuple_impl::{TupleBuffer, TupleWindows, Tuples};
+    pub use split_between::SplitBetween;
+    #[cfg(feature = "use_std")]
+    pub use split_on::SplitOn;
+    #[cfg(feature = "use_std")]
+    pub use tee::{Tee, TeeBounded, TeeN, TryTeeBounded};
+    pub use tuple_impl::{CircularTupleWindows, TupleBuffer, TupleWindows, Tuples};
     #[cfg(feature = "use_std")]
     pub use unique_impl::{Unique, UniqueBy};
+    #[cfg(feature = "use_std")]
+    pub use window_extrema::{WindowMax, WindowMaxBy, WindowMin, WindowMinBy};
     pub use with_position::WithPosition;
-    pub use zip_eq_impl::ZipEq;
+    pub use zip_cycling::ZipCycling;
+    pub use zip_eq_impl::{TryZipEq, ZipEq};
     pub use zip_longest::ZipLongest;
-    pub use ziptuple::Zip;
+    pub use ziptuple::{Zip, ZipEqTuple, ZipLongestTuple};
 }
 #[allow(deprecated)]
 pub use structs::*;
+pub use cartesian_square::SquarePairs;
 pub use concat_impl::concat;
 pub use cons_tuples_impl::cons_tuples;
 pub use diff::diff_with;
 pub use diff::Diff;
 #[cfg(feature = "use_std")]
+pub use group_map::{DuplicateKeyError, KeepFirst, KeepLast, LookupPolicy};
+#[cfg(feature = "use_std")]
+pub use grouping_map::{CheckedArith, DestinationMap, ToF64};
+#[cfg(feature = "use_std")]
 pub use kmerge_impl::{kmerge_by};
+#[cfg(feature = "int_join")]
+pub use join_int::JoinInt;
 pub use minmax::MinMaxResult;
 pub use peeking_take_while::PeekingNext;
-pub use process_results_impl::process_results;
+#[cfg(feature = "use_std")]
+pub use permutation_indices::permutation_indices;
+pub use process_results_impl::{process_results, process_results_partial, ProcessResultsError};
 pub use repeatn::repeat_n;
 #[allow(deprecated)]
 pub use sources::{repeat_call, unfold, iterate};
+#[cfg(feature = "use_std")]
+pub use tee::{TeeOverflow, TeeOverflowError};
+pub use unziptuple::{multiunzip, MultiUnzip};
 pub use with_position::Position;
-pub use ziptuple::multizip;
+pub use zip_eq_impl::ZipEqError;
+pub use ziptuple::{multizip, multizip_eq, multizip_longest};
 mod adaptors;
+#[cfg(feature = "use_std")]
+mod array_chunks;
+mod array_windows;
+#[cfg(feature = "use_std")]
+mod cached_product;
+#[cfg(feature = "approx_unique")]
+mod approx_unique;
 mod either_or_both;
 pub use either_or_both::EitherOrBoth;
 #[doc(hidden)]
 pub mod free;
 #[doc(inline)]
 pub use free::*;
+mod cartesian_square;
 mod concat_impl;
 mod cons_tuples_impl;
 #[cfg(feature = "use_std")]
 mod combinations;
 #[cfg(feature = "use_std")]
 mod combinations_with_replacement;
+mod cumulative_fold;
+#[cfg(feature = "use_std")]
+mod drop_last_impl;
+#[cfg(feature = "use_std")]
+mod duplicate_positions;
+#[cfg(feature = "use_std")]
+mod duplicates_impl;
 mod exactly_one_err;
 mod diff;
+mod diffs_impl;
 mod format;
 #[cfg(feature = "use_std")]
 mod group_map;
 #[cfg(feature = "use_std")]
 mod groupbylazy;
+#[cfg(feature = "use_std")]
+mod grouping_map;
+mod grouping_runs;
 mod intersperse;
+#[cfg(feature = "int_join")]
+mod join_int;
+#[cfg(feature = "use_std")]
+mod k_smallest;
 #[cfg(feature = "use_std")]
 mod kmerge_impl;
 #[cfg(feature = "use_std")]
 mod lazy_buffer;
+#[cfg(feature = "use_std")]
+mod map_parallel;
 mod merge_join;
 mod minmax;
 #[cfg(feature = "use_std")]
 mod multipeek_impl;
+mod non_empty;
+#[cfg(feature = "use_std")]
+mod pad_front;
 mod pad_tail;
+#[cfg(feature = "use_std")]
+mod partition_lazy;
+#[cfg(feature = "use_std")]
+mod peek_nth;
+mod peek_window;
 mod peeking_take_while;
+#[cfg(feature = "use_std")]
+mod permutation_indices;
+#[cfg(feature = "use_std")]
+mod permutations;
+#[cfg(feature = "use_std")]
+mod powerset;
 mod process_results_impl;
 #[cfg(feature = "use_std")]
 mod put_back_n_impl;
 #[cfg(feature = "use_std")]
 mod rciter_impl;
+#[cfg(feature = "use_std")]
+mod readahead;
 mod repeatn;
+#[cfg(feature = "use_std")]
+mod rolling;
+mod run_lengths;
 mod size_hint;
 mod sources;
 #[cfg(feature = "use_std")]
+mod split_between;
+#[cfg(feature = "use_std")]
+mod split_on;
+#[cfg(feature = "use_std")]
+mod subsequence_impl;
+#[cfg(feature = "use_std")]
 mod tee;
 mod tuple_impl;
 #[cfg(feature = "use_std")]
 mod unique_impl;
+mod unziptuple;
+#[cfg(feature = "use_std")]
+mod window_extrema;
 mod with_position;
+mod zip_cycling;
 mod zip_eq_impl;
 mod zip_longest;
 mod ziptuple;
@@ -322,6 +483,112 @@ macro_rules! izip {
     };
 }
 
+#[macro_export]
+/// Create an iterator running multiple iterators of the same length in
+/// lockstep.
+///
+/// This is a version of [`.zip_eq()`] that's supporting more than two
+/// iterators, just like [`izip!()`] is for the standard `.zip()`. The
+/// iterator element type is a tuple with one element from each of the input
+/// iterators.
+///
+/// **Panics** if the iterators are not all of the same length.
+///
+/// **Note:** The result of this macro is in the general case an iterator
+/// composed of repeated `zip_eq()` and a `.map()`; it has an anonymous type.
+/// Use [`multizip_eq`] if a nameable type is needed.
+///
+/// [`.zip_eq()`]: trait.Itertools.html#method.zip_eq
+/// [`izip!()`]: macro.izip.html
+/// [`multizip_eq`]: fn.multizip_eq.html
+///
+/// ```
+/// #[macro_use] extern crate itertools;
+/// # fn main() {
+///
+/// let names = ["a", "b", "c"];
+/// let xs = [1, 2, 3];
+/// let ys = [4, 5, 6];
+///
+/// for (name, x, y) in izip_eq!(&names, &xs, &ys) {
+///     println!("{}: {}", name, x * y);
+/// }
+/// # }
+/// ```
+macro_rules! izip_eq {
+    // @closure creates a tuple-flattening closure for .map() call, see izip!
+    ( @closure $p:pat => $tup:expr ) => {
+        |$p| $tup
+    };
+
+    ( @closure $p:pat => ( $($tup:tt)* ) , $_iter:expr $( , $tail:expr )* ) => {
+        izip_eq!(@closure ($p, b) => ( $($tup)*, b ) $( , $tail )*)
+    };
+
+    // @zip nests the zip_eq calls from the left
+    ( @zip $acc:expr ) => {
+        $acc
+    };
+
+    ( @zip $acc:expr , $next:expr $( , $tail:expr )* ) => {
+        izip_eq!(@zip $crate::zip_eq($acc, $next) $( , $tail )*)
+    };
+
+    // unary
+    ($first:expr $(,)*) => {
+        $crate::__std_iter::IntoIterator::into_iter($first)
+    };
+
+    // binary
+    ($first:expr, $second:expr $(,)*) => {
+        $crate::zip_eq($first, $second)
+    };
+
+    // n-ary where n > 2
+    ( $first:expr $( , $rest:expr )* $(,)* ) => {
+        $crate::__std_iter::Iterator::map(
+            izip_eq!(@zip $first $( , $rest )*),
+            izip_eq!(@closure a => (a) $( , $rest )*)
+        )
+    };
+}
+
+#[macro_export]
+/// Create an iterator running multiple iterators in lockstep, until all of
+/// them run out.
+///
+/// This is a version of [`.zip_longest()`] that's supporting more than two
+/// iterators, and up to eight. The iterator element type is a tuple with an
+/// `Option` of an element from each of the input iterators, `None` for the
+/// iterators that have run out.
+///
+/// **Note:** The result of this macro is a value of the named type
+/// [`ZipLongestTuple`], see [`multizip_longest`].
+///
+/// [`.zip_longest()`]: trait.Itertools.html#method.zip_longest
+/// [`ZipLongestTuple`]: structs/struct.ZipLongestTuple.html
+/// [`multizip_longest`]: fn.multizip_longest.html
+///
+/// ```
+/// #[macro_use] extern crate itertools;
+/// # fn main() {
+///
+/// let names = ["x", "y", "z"];
+/// let xs = [1.5, 2.5];
+/// let ys = [4];
+///
+/// let rows: Vec<_> = izip_longest!(&names, &xs, &ys).collect();
+/// assert_eq!(rows, vec![(Some(&"x"), Some(&1.5), Some(&4)),
+///                       (Some(&"y"), Some(&2.5), None),
+///                       (Some(&"z"), None, None)]);
+/// # }
+/// ```
+macro_rules! izip_longest {
+    ( $( $iter:expr ),+ $(,)* ) => {
+        $crate::multizip_longest(( $( $iter, )+ ))
+    };
+}
+
 /// An [`Iterator`] blanket implementation that provides extra adaptors and
 /// methods.
 ///
@@ -377,6 +644,103 @@ pub trait Itertools : Iterator {
         adaptors::interleave_shortest(self, other.into_iter())
     }
 
+    /// Take elements from two iterators in a repeating pattern: `a_weight`
+    /// elements from `self`, then `b_weight` elements from `other`, until
+    /// both have run out.
+    ///
+    /// When one of them runs out, the rest of the other one is yielded.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// **Panics** if a weight is zero.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (1..7).interleave_weighted(vec![-1, -2, -3], 2, 1);
+    /// itertools::assert_equal(it, vec![1, 2, -1, 3, 4, -2, 5, 6, -3]);
+    /// ```
+    fn interleave_weighted<J>(self, other: J, a_weight: usize, b_weight: usize)
+        -> InterleaveWeighted<Self, J::IntoIter>
+        where J: IntoIterator<Item = Self::Item>,
+              Self: Sized
+    {
+        adaptors::interleave_weighted(self, other.into_iter(), a_weight, b_weight)
+    }
+
+    /// Take one element from each of the iterators returned by meta-iterator
+    /// `self` in turn, round-robin, until all of them have run out.
+    ///
+    /// An iterator that has run out is skipped, so each element of each
+    /// iterator is yielded exactly once. This generalizes
+    /// [`.interleave()`](#method.interleave) to any number of iterators.
+    ///
+    /// Iterator element type is the element type of the subiterators.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let queues = vec![vec!["a1", "a2", "a3"], vec![], vec!["c1"], vec!["d1", "d2"]];
+    /// let it = queues.into_iter().multi_interleave();
+    /// itertools::assert_equal(it, vec!["a1", "c1", "d1", "a2", "d2", "a3"]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn multi_interleave(self) -> MultiInterleave<<Self::Item as IntoIterator>::IntoIter>
+        where Self: Sized,
+              Self::Item: IntoIterator,
+    {
+        adaptors::multi_interleave(self)
+    }
+
+    /// Take one element from each of the iterators returned by meta-iterator
+    /// `self` in turn, until one of them has run out.
+    ///
+    /// The output stops as soon as an iterator has no element for its
+    /// turn: the elements left in the other iterators are not yielded.
+    ///
+    /// Iterator element type is the element type of the subiterators.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let shards = vec![vec![1, 4, 7, 10], vec![2, 5, 8], vec![3, 6, 9, 12]];
+    /// let it = shards.into_iter().multi_interleave_shortest();
+    /// itertools::assert_equal(it, vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn multi_interleave_shortest(self)
+        -> MultiInterleaveShortest<<Self::Item as IntoIterator>::IntoIter>
+        where Self: Sized,
+              Self::Item: IntoIterator,
+    {
+        adaptors::multi_interleave_shortest(self)
+    }
+
+    /// Take elements from each of the iterators returned by meta-iterator
+    /// `self` in turn, as many as its weight, until all of them have run out.
+    ///
+    /// The meta-iterator yields `(iterable, weight)` pairs. An iterator that
+    /// has run out is skipped.
+    ///
+    /// Iterator element type is the element type of the subiterators.
+    ///
+    /// **Panics** if a weight is zero.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let feeds = vec![(vec!["a1", "a2", "a3", "a4"], 2), (vec!["b1"], 1), (vec!["c1", "c2"], 1)];
+    /// let it = feeds.into_iter().multi_interleave_weighted();
+    /// itertools::assert_equal(it, vec!["a1", "a2", "b1", "c1", "a3", "a4", "c2"]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn multi_interleave_weighted<J>(self) -> MultiInterleaveWeighted<J::IntoIter>
+        where Self: Iterator<Item = (J, usize)> + Sized,
+              J: IntoIterator,
+    {
+        adaptors::multi_interleave_weighted(self)
+    }
+
     /// An iterator adaptor to insert a particular value
     /// between each element of the adapted iterator.
     ///
@@ -396,6 +760,38 @@ pub trait Itertools : Iterator {
         intersperse::intersperse(self, element)
     }
 
+    /// An iterator adaptor to insert a particular value after every `n`
+    /// elements of the adapted iterator.
+    ///
+    /// The separator is only inserted between elements: there is none at
+    /// the end, even when the number of elements is a multiple of `n`.
+    /// `.intersperse_every(1, element)` is the same as `.intersperse(element)`.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// **Panics** if `n` is zero.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// itertools::assert_equal((0..7).intersperse_every(3, -1),
+    ///                         vec![0, 1, 2, -1, 3, 4, 5, -1, 6]);
+    /// itertools::assert_equal((0..6).intersperse_every(3, -1),
+    ///                         vec![0, 1, 2, -1, 3, 4, 5]);
+    ///
+    /// // thousands separators
+    /// let digits: String = "1234567".chars().rev().intersperse_every(3, ',').collect();
+    /// assert_eq!(digits.chars().rev().collect::<String>(), "1,234,567");
+    /// ```
+    fn intersperse_every(self, n: usize, element: Self::Item) -> IntersperseEvery<Self>
+        where Self: Sized,
+              Self::Item: Clone
+    {
+        intersperse::intersperse_every(self, n, element)
+    }
+
     /// Create an iterator which iterates over both this and the specified
     /// iterator simultaneously, yielding pairs of two optional elements.
     ///
@@ -430,6 +826,33 @@ pub trait Itertools : Iterator {
         zip_longest::zip_longest(self, other.into_iter())
     }
 
+    /// Create an iterator which iterates over both this and the specified
+    /// iterator simultaneously, restarting the shorter one as long as the
+    /// longer one has elements left.
+    ///
+    /// The output is as long as the longest of the two iterators, or empty
+    /// if either of them is empty. Since it is not known in advance which one
+    /// is shorter, both must be `Clone`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// Iterator element type is `(Self::Item, J::Item)`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let rows = (0..5).zip_cycling(vec!["odd", "even"].into_iter().rev());
+    /// itertools::assert_equal(rows, vec![(0, "even"), (1, "odd"), (2, "even"),
+    ///                                    (3, "odd"), (4, "even")]);
+    /// ```
+    fn zip_cycling<J>(self, other: J) -> ZipCycling<Self, J::IntoIter>
+        where J: IntoIterator,
+              J::IntoIter: Clone,
+              Self: Sized + Clone
+    {
+        zip_cycling::zip_cycling(self, other.into_iter())
+    }
+
     /// Create an iterator which iterates over both this and the specified
     /// iterator simultaneously, yielding pairs of elements.
     ///
@@ -443,6 +866,38 @@ pub trait Itertools : Iterator {
         zip_eq(self, other)
     }
 
+    /// Create an iterator which iterates over both this and the specified
+    /// iterator simultaneously, yielding pairs of elements in `Ok`, and
+    /// an `Err` if they are not of equal lengths.
+    ///
+    /// This is the non-panicking version of [`.zip_eq()`](#method.zip_eq):
+    /// when one iterator reaches its end before the other, the adaptor
+    /// yields a [`ZipEqError`](struct.ZipEqError.html) holding the number
+    /// of pairs yielded and the first element of the longer iterator, and
+    /// then ends.
+    ///
+    /// Iterator element type is
+    /// `Result<(Self::Item, J::Item), ZipEqError<Self::Item, J::Item>>`.
+    ///
+    /// ```
+    /// use itertools::{Either, Itertools};
+    ///
+    /// let pairs: Result<Vec<_>, _> = (0..3).try_zip_eq("abc".chars()).collect();
+    /// assert_eq!(pairs.unwrap(), vec![(0, 'a'), (1, 'b'), (2, 'c')]);
+    ///
+    /// let err = (0..3).try_zip_eq("ab".chars()).find(|r| r.is_err()).unwrap().unwrap_err();
+    /// assert_eq!(err.len, 2);
+    /// assert_eq!(err.extra, Either::Left(2));
+    /// assert_eq!(err.to_string(),
+    ///            "the first iterator is longer: the second one ended after 2 elements");
+    /// ```
+    fn try_zip_eq<J>(self, other: J) -> TryZipEq<Self, J::IntoIter>
+        where J: IntoIterator,
+              Self: Sized
+    {
+        try_zip_eq(self, other)
+    }
+
     /// A “meta iterator adaptor”. Its closure receives a reference to the
     /// iterator and may pick off as many elements as it likes, to produce the
     /// next iterator element.
@@ -550,6 +1005,68 @@ pub trait Itertools : Iterator {
         groupbylazy::new_chunks(self, size)
     }
 
+    /// Return an iterator adaptor that collects the elements in chunks,
+    /// starting a new chunk between each two adjacent elements for which
+    /// `pred` returns `true`.
+    ///
+    /// `pred` is called with references to the previous and the next
+    /// element, which makes it possible to split where the sequence has a
+    /// gap, or where it stops increasing, which `.group_by()` and its key
+    /// function can't express. Unlike `.group_by()`, the chunks are
+    /// collected in `Vec`s, so the adaptor is an ordinary iterator.
+    ///
+    /// Iterator element type is `Vec<Self::Item>`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // split the timestamps where there is a gap of more than 10
+    /// let timestamps = vec![1, 3, 8, 30, 31, 50, 52, 55];
+    /// let sessions = timestamps.into_iter().split_between(|&prev, &next| next - prev > 10);
+    /// itertools::assert_equal(sessions, vec![vec![1, 3, 8], vec![30, 31], vec![50, 52, 55]]);
+    ///
+    /// // split the increasing runs
+    /// let runs = vec![1, 2, 4, 3, 5, 2].into_iter().split_between(|prev, next| next < prev);
+    /// itertools::assert_equal(runs, vec![vec![1, 2, 4], vec![3, 5], vec![2]]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn split_between<F>(self, pred: F) -> SplitBetween<Self, F>
+        where Self: Sized,
+              F: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        split_between::split_between(self, pred)
+    }
+
+    /// Return an iterator adaptor that collects the elements in chunks
+    /// separated by the elements for which `pred` returns `true`, like
+    /// `str::split`.
+    ///
+    /// The separators are dropped. Two adjacent separators, or a separator
+    /// at either end, delimit an empty chunk, and an empty iterator yields
+    /// a single empty chunk, so there is always one more chunk than there
+    /// are separators.
+    ///
+    /// Iterator element type is `Vec<Self::Item>`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let bytes = b"GET /\nHost: x\n\nbody";
+    /// let lines = bytes.iter().cloned().split_on(|&b| b == b'\n');
+    /// itertools::assert_equal(lines, vec![b"GET /".to_vec(), b"Host: x".to_vec(),
+    ///                                     vec![], b"body".to_vec()]);
+    ///
+    /// let fields = vec![1, 0, 0, 2, 3, 0].into_iter().split_on(|&x| x == 0);
+    /// itertools::assert_equal(fields, vec![vec![1], vec![], vec![2, 3], vec![]]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn split_on<F>(self, pred: F) -> SplitOn<Self, F>
+        where Self: Sized,
+              F: FnMut(&Self::Item) -> bool,
+    {
+        split_on::split_on(self, pred)
+    }
+
     /// Return an iterator over all contiguous windows producing tuples of
     /// a specific size (up to 4).
     ///
@@ -589,35 +1106,113 @@ pub trait Itertools : Iterator {
         tuple_impl::tuple_windows(self)
     }
 
-    /// Return an iterator that groups the items in tuples of a specific size
-    /// (up to 4).
+    /// Return an iterator over all windows, wrapping back to the first
+    /// elements when the window would otherwise exceed the length of the
+    /// iterator, producing tuples of a specific size (up to 4).
     ///
-    /// See also the method [`.next_tuple()`](#method.next_tuple).
+    /// `circular_tuple_windows` clones the iterator elements so that they can be
+    /// part of successive windows, this makes it most suited for iterators
+    /// of references and other values that are cheap to copy.
+    ///
+    /// The iterator produces exactly one window per element of the source,
+    /// starting at that element: the edges of a polygon, for instance.
     ///
     /// ```
     /// use itertools::Itertools;
     /// let mut v = Vec::new();
-    /// for (a, b) in (1..5).tuples() {
+    /// for (a, b) in (1..5).circular_tuple_windows() {
     ///     v.push((a, b));
     /// }
-    /// assert_eq!(v, vec![(1, 2), (3, 4)]);
+    /// assert_eq!(v, vec![(1, 2), (2, 3), (3, 4), (4, 1)]);
     ///
-    /// let mut it = (1..7).tuples();
+    /// let mut it = (1..5).circular_tuple_windows();
     /// assert_eq!(Some((1, 2, 3)), it.next());
-    /// assert_eq!(Some((4, 5, 6)), it.next());
+    /// assert_eq!(Some((2, 3, 4)), it.next());
+    /// assert_eq!(Some((3, 4, 1)), it.next());
+    /// assert_eq!(Some((4, 1, 2)), it.next());
     /// assert_eq!(None, it.next());
     ///
     /// // this requires a type hint
-    /// let it = (1..7).tuples::<(_, _, _)>();
-    /// itertools::assert_equal(it, vec![(1, 2, 3), (4, 5, 6)]);
+    /// let it = (1..5).circular_tuple_windows::<(_, _, _)>();
+    /// itertools::assert_equal(it, vec![(1, 2, 3), (2, 3, 4), (3, 4, 1), (4, 1, 2)]);
+    /// ```
+    fn circular_tuple_windows<T>(self) -> CircularTupleWindows<Self, T>
+        where Self: Sized + Clone + Iterator<Item = T::Item> + ExactSizeIterator,
+              T: tuple_impl::TupleCollect + Clone,
+              T::Item: Clone
+    {
+        tuple_impl::circular_tuple_windows(self)
+    }
+
+    /// Return an iterator over all contiguous windows producing arrays of a
+    /// specific size, where `A` is an array type `[_; N]`.
     ///
-    /// // you can also specify the complete type
-    /// use itertools::Tuples;
-    /// use std::ops::Range;
+    /// Unlike [`.tuple_windows()`](#method.tuple_windows), the windows can
+    /// be indexed and iterated over, and arrays of up to 32 elements can be
+    /// used.
     ///
-    /// let it: Tuples<Range<u32>, (u32, u32, u32)> = (1..7).tuples();
-    /// itertools::assert_equal(it, vec![(1, 2, 3), (4, 5, 6)]);
-    /// ```
+    /// `array_windows` clones the windows so that the elements can be part
+    /// of successive windows, this makes it most suited for iterators of
+    /// references and other values that are cheap to copy.
+    ///
+    /// Iterator element type is `A`.
+    ///
+    /// **Panics** if the array is empty.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut it = (1..5).array_windows::<[_; 3]>();
+    /// assert_eq!(it.next(), Some([1, 2, 3]));
+    /// assert_eq!(it.next(), Some([2, 3, 4]));
+    /// assert_eq!(it.next(), None);
+    ///
+    /// let moving_max = [3, 1, 4, 1, 5, 9, 2, 6].iter().cloned()
+    ///     .array_windows::<[u32; 4]>()
+    ///     .map(|window| *window.iter().max().unwrap());
+    /// itertools::assert_equal(moving_max, vec![4, 5, 9, 9, 9]);
+    ///
+    /// let words = vec![String::from("a"), String::from("b"), String::from("c")];
+    /// let pairs = words.iter().array_windows::<[&String; 2]>()
+    ///     .map(|window| format!("{}{}", window[0], window[1]));
+    /// itertools::assert_equal(pairs, vec!["ab", "bc"]);
+    /// ```
+    fn array_windows<A>(self) -> ArrayWindows<Self, A>
+        where Self: Sized,
+              A: array_windows::ArrayCollect<Item = Self::Item> + Clone,
+    {
+        array_windows::array_windows(self)
+    }
+
+    /// Return an iterator that groups the items in tuples of a specific size
+    /// (up to 4).
+    ///
+    /// See also the method [`.next_tuple()`](#method.next_tuple).
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// let mut v = Vec::new();
+    /// for (a, b) in (1..5).tuples() {
+    ///     v.push((a, b));
+    /// }
+    /// assert_eq!(v, vec![(1, 2), (3, 4)]);
+    ///
+    /// let mut it = (1..7).tuples();
+    /// assert_eq!(Some((1, 2, 3)), it.next());
+    /// assert_eq!(Some((4, 5, 6)), it.next());
+    /// assert_eq!(None, it.next());
+    ///
+    /// // this requires a type hint
+    /// let it = (1..7).tuples::<(_, _, _)>();
+    /// itertools::assert_equal(it, vec![(1, 2, 3), (4, 5, 6)]);
+    ///
+    /// // you can also specify the complete type
+    /// use itertools::Tuples;
+    /// use std::ops::Range;
+    ///
+    /// let it: Tuples<Range<u32>, (u32, u32, u32)> = (1..7).tuples();
+    /// itertools::assert_equal(it, vec![(1, 2, 3), (4, 5, 6)]);
+    /// ```
     ///
     /// See also [`Tuples::into_buffer`](structs/struct.Tuples.html#method.into_buffer).
     fn tuples<T>(self) -> Tuples<Self, T>
@@ -627,6 +1222,50 @@ pub trait Itertools : Iterator {
         tuple_impl::tuples(self)
     }
 
+    /// Return an iterator that groups the items in arrays of a specific size,
+    /// where `A` is an array type `[_; N]`.
+    ///
+    /// Unlike [`.tuples()`](#method.tuples), the chunks can be indexed and
+    /// iterated over, and arrays of up to 32 elements can be used.
+    ///
+    /// This limit comes from the minimum Rust version supported by this
+    /// crate, 1.24, which has no const generics: the chunk size is given by
+    /// the array type `A` rather than by a constant `N`, and only the arrays
+    /// of up to 32 elements can be collected from an iterator. The elements
+    /// of each chunk are gathered in a buffer first, which also holds the
+    /// incomplete last chunk.
+    ///
+    /// If the number of elements is not a multiple of the chunk size, the
+    /// elements of the incomplete last chunk are available with
+    /// [`ArrayChunks::remainder`](structs/struct.ArrayChunks.html#method.remainder)
+    /// once the iterator is exhausted.
+    ///
+    /// Iterator element type is `A`.
+    ///
+    /// **Panics** if the array is empty.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut chunks = (1..8).array_chunks::<[_; 3]>();
+    /// assert_eq!(chunks.next(), Some([1, 2, 3]));
+    /// assert_eq!(chunks.next(), Some([4, 5, 6]));
+    /// assert_eq!(chunks.next(), None);
+    /// assert_eq!(chunks.remainder(), &[7]);
+    ///
+    /// let sums = (0..20).array_chunks::<[u32; 5]>()
+    ///     .map(|chunk| chunk.iter().sum::<u32>())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(sums, vec![10, 35, 60, 85]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn array_chunks<A>(self) -> ArrayChunks<Self, A>
+        where Self: Sized,
+              A: array_windows::ArrayCollect<Item = Self::Item>,
+    {
+        array_chunks::array_chunks(self)
+    }
+
     /// Split into an iterator pair that both yield all elements from
     /// the original iterator.
     ///
@@ -652,6 +1291,130 @@ pub trait Itertools : Iterator {
         tee::new(self)
     }
 
+    /// Split into an iterator pair that both yield all elements from
+    /// the original iterator, buffering at most `capacity` elements.
+    ///
+    /// Unlike `.tee()`, the buffer can't grow without bound when one half is
+    /// read much faster than the other. When a half would get more than
+    /// `capacity` elements ahead of the other one, the `overflow` policy
+    /// applies: see [`TeeOverflow`](enum.TeeOverflow.html).
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// **Panics** if `capacity` is zero.
+    ///
+    /// ```
+    /// use itertools::{Itertools, TeeOverflow};
+    ///
+    /// let (mut t1, mut t2) = (0..6).tee_bounded(2, TeeOverflow::Pause);
+    /// itertools::assert_equal(t1.by_ref(), vec![0, 1]);
+    /// assert_eq!(t2.next(), Some(0));
+    /// assert_eq!(t1.next(), Some(2));
+    ///
+    /// let (t1, t2) = (0..6).tee_bounded(2, TeeOverflow::DropOldest);
+    /// itertools::assert_equal(t1, 0..6);
+    /// itertools::assert_equal(t2, vec![4, 5]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn tee_bounded(self, capacity: usize, overflow: TeeOverflow)
+        -> (TeeBounded<Self>, TeeBounded<Self>)
+        where Self: Sized,
+              Self::Item: Clone
+    {
+        tee::new_bounded(self, capacity, overflow)
+    }
+
+    /// Split into an iterator pair that both yield all elements from
+    /// the original iterator, buffering at most `capacity` elements, and
+    /// yielding an error when a half would get further ahead.
+    ///
+    /// Like `.tee_bounded()` with `TeeOverflow::Pause`, but the half that is
+    /// `capacity` elements ahead of the other one yields
+    /// `Err(TeeOverflowError)` instead of `None`, until the other half
+    /// catches up: the elements are wrapped in `Ok`. Once the other half is
+    /// dropped, there is no error anymore.
+    ///
+    /// Iterator element type is `Result<Self::Item, TeeOverflowError>`.
+    ///
+    /// **Panics** if `capacity` is zero.
+    ///
+    /// ```
+    /// use itertools::{Itertools, TeeOverflowError};
+    ///
+    /// let (mut t1, mut t2) = (0..6).try_tee_bounded(2);
+    /// assert_eq!(t1.next(), Some(Ok(0)));
+    /// assert_eq!(t1.next(), Some(Ok(1)));
+    /// assert_eq!(t1.next(), Some(Err(TeeOverflowError { capacity: 2 })));
+    /// assert_eq!(t2.next(), Some(Ok(0)));
+    /// assert_eq!(t1.next(), Some(Ok(2)));
+    ///
+    /// drop(t2);
+    /// itertools::assert_equal(t1, vec![Ok(3), Ok(4), Ok(5)]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn try_tee_bounded(self, capacity: usize) -> (TryTeeBounded<Self>, TryTeeBounded<Self>)
+        where Self: Sized,
+              Self::Item: Clone
+    {
+        tee::new_try_bounded(self, capacity)
+    }
+
+    /// Split into `n` iterators that all yield all elements from the
+    /// original iterator.
+    ///
+    /// Like for `.tee()`, the handles share a single buffer of the elements
+    /// some handles have read and others have not yet, so that the same
+    /// stream can feed several consumers without nesting `.tee()` calls.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut tees = (0..4).tee_n(3);
+    /// assert_eq!(tees.len(), 3);
+    /// assert_eq!(tees[0].next(), Some(0));
+    /// itertools::assert_equal(tees.pop().unwrap(), 0..4);
+    /// itertools::assert_equal(tees.pop().unwrap(), 0..4);
+    /// itertools::assert_equal(tees.pop().unwrap(), 1..4);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn tee_n(self, n: usize) -> Vec<TeeN<Self>>
+        where Self: Sized,
+              Self::Item: Clone
+    {
+        tee::new_n(self, n)
+    }
+
+    /// Split into an iterator pair that yield the elements for which
+    /// `pred` returns `true` and `false` respectively, without collecting
+    /// them.
+    ///
+    /// Both iterators read from the original iterator as needed: the
+    /// elements for the other side are kept in a buffer until it reads them.
+    /// The predicate is called once per element.
+    ///
+    /// **Note:** If only one side is read, the buffer of the other side
+    /// grows with the elements skipped.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let (mut evens, odds) = (0..).partition_lazy(|x| x % 2 == 0);
+    /// assert_eq!(evens.next(), Some(0));
+    /// assert_eq!(evens.next(), Some(2));
+    /// itertools::assert_equal(odds.take(3), vec![1, 3, 5]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn partition_lazy<F>(self, pred: F) -> (PartitionLazy<Self, F>, PartitionLazy<Self, F>)
+        where Self: Sized,
+              F: FnMut(&Self::Item) -> bool
+    {
+        partition_lazy::new(self, pred)
+    }
+
     /// Return an iterator adaptor that steps `n` elements in the base iterator
     /// for each iteration.
     ///
@@ -676,6 +1439,65 @@ pub trait Itertools : Iterator {
         adaptors::step(self, n)
     }
 
+    /// Return an iterator adaptor that skips `offset` elements of the base
+    /// iterator, then yields every `step`-th element: the elements at
+    /// positions `offset`, `offset + step`, `offset + 2 * step`, ...
+    ///
+    /// Unlike `.skip(offset).step_by(step)`, its `size_hint` is exact if the
+    /// base iterator's is, and `.nth()` skips the elements of all the strides
+    /// in one call to the base iterator's `.nth()`.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// **Panics** if the step is 0.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (0..10).stride(1, 3);
+    /// assert_eq!(it.len(), 3);
+    /// itertools::assert_equal(it, vec![1, 4, 7]);
+    ///
+    /// assert_eq!((0..100).stride(5, 10).nth(2), Some(25));
+    /// ```
+    fn stride(self, offset: usize, step: usize) -> Stride<Self>
+        where Self: Sized
+    {
+        adaptors::stride(self, offset, step)
+    }
+
+    /// Return an iterator adaptor that allows peeking at up to `N` elements
+    /// ahead, where `A` is an array type `[_; N]`.
+    ///
+    /// The peeked elements are buffered in the array, without allocating,
+    /// which suits parsers with a small, known lookahead. Arrays of up to 32
+    /// elements can be used.
+    ///
+    /// See [`PeekWindow::peek`](structs/struct.PeekWindow.html#method.peek).
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// **Panics** if the array is empty.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut tokens = "a=>b".chars().peek_window::<[_; 2]>();
+    /// assert_eq!(tokens.peek(1), Some(&'='));
+    /// assert_eq!(tokens.next(), Some('a'));
+    /// if tokens.peek(0) == Some(&'=') && tokens.peek(1) == Some(&'>') {
+    ///     tokens.next();
+    ///     tokens.next();
+    /// }
+    /// itertools::assert_equal(tokens, "b".chars());
+    /// ```
+    fn peek_window<A>(self) -> PeekWindow<Self, A>
+        where Self: Sized,
+              A: Default + AsRef<[Option<Self::Item>]> + AsMut<[Option<Self::Item>]>,
+    {
+        peek_window::peek_window(self)
+    }
+
     /// Convert each item of the iterator using the `Into` trait.
     ///
     /// ```rust
@@ -708,6 +1530,60 @@ pub trait Itertools : Iterator {
         adaptors::map_results(self, f)
     }
 
+    /// Return an iterator adaptor that applies the provided closure
+    /// to every `Result::Ok` value and flattens the iterables it returns,
+    /// yielding each of their elements wrapped in `Ok`. `Result::Err`
+    /// values are passed through unchanged.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let input = vec![Ok(2), Err("bad"), Ok(0), Ok(3)];
+    /// let it = input.into_iter().flat_map_ok(|n| 0..n);
+    /// itertools::assert_equal(it, vec![Ok(0), Ok(1), Err("bad"), Ok(0), Ok(1), Ok(2)]);
+    /// ```
+    fn flat_map_ok<F, T, J, E>(self, f: F) -> FlatMapOk<Self, F, J>
+        where Self: Iterator<Item = Result<T, E>> + Sized,
+              F: FnMut(T) -> J,
+              J: IntoIterator,
+    {
+        adaptors::flat_map_ok(self, f)
+    }
+
+    /// Return an iterator adaptor that yields the values inside the `Ok`s of
+    /// an iterator of `Result`s, and stops at the first `Err`, storing it in
+    /// the `error` slot.
+    ///
+    /// This lets a plain `for` loop run over a fallible source, like the
+    /// lines of a `BufRead` or the entries of a directory, and check for an
+    /// error after it. If the slot already holds an error, no element is
+    /// read.
+    ///
+    /// Iterator element type is `T`.
+    ///
+    /// ```
+    /// use std::io::{self, BufRead};
+    /// use itertools::Itertools;
+    ///
+    /// fn count_words<R: BufRead>(input: R) -> io::Result<usize> {
+    ///     let mut error = Ok(());
+    ///     let mut count = 0;
+    ///     for line in input.lines().capture_err(&mut error) {
+    ///         count += line.split_whitespace().count();
+    ///     }
+    ///     error?;
+    ///     Ok(count)
+    /// }
+    ///
+    /// assert_eq!(count_words("a b\nc".as_bytes()).unwrap(), 3);
+    /// assert!(count_words(&[0x61, 0xff][..]).is_err());
+    /// ```
+    fn capture_err<'a, T, E>(self, error: &'a mut Result<(), E>) -> CaptureErr<'a, Self, E>
+        where Self: Iterator<Item = Result<T, E>> + Sized,
+    {
+        process_results_impl::capture_err(self, error)
+    }
+
     /// Return an iterator adaptor that merges the two base iterators in
     /// ascending order.  If both base iterators are sorted (ascending), the
     /// result is sorted.
@@ -820,6 +1696,34 @@ pub trait Itertools : Iterator {
         kmerge(self)
     }
 
+    /// Return an iterator adaptor that flattens an iterator of iterators by
+    /// merging them in ascending order, and skips each element that is
+    /// equal to the previous one.
+    ///
+    /// If all base iterators are sorted (ascending), the result is their
+    /// sorted set union: of each run of equal elements, only the first to
+    /// come out of the merge is kept.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = vec![1, 3, 5, 7];
+    /// let b = vec![3, 4, 5];
+    /// let c = vec![1, 1, 8];
+    /// let it = vec![a, b, c].into_iter().kmerge_dedup();
+    /// itertools::assert_equal(it, vec![1, 3, 4, 5, 7, 8]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn kmerge_dedup(self) -> KMergeDedup<<Self::Item as IntoIterator>::IntoIter>
+        where Self: Sized,
+              Self::Item: IntoIterator,
+              <Self::Item as IntoIterator>::Item: PartialOrd,
+    {
+        kmerge_impl::kmerge_dedup(self)
+    }
+
     /// Return an iterator adaptor that flattens an iterator of iterators by
     /// merging them according to the given closure.
     ///
@@ -851,6 +1755,48 @@ pub trait Itertools : Iterator {
         kmerge_by(self, first)
     }
 
+    /// Return an iterator adaptor that flattens an iterator of iterators by
+    /// merging them according to the given fallible closure.
+    ///
+    /// The closure `first` is called with two elements *a*, *b* and should
+    /// return `Ok(true)` if *a* is ordered before *b*, or an error if they
+    /// can't be compared, like floats when one is NaN.
+    ///
+    /// The elements are yielded in `Ok` until the closure returns an error:
+    /// the error is then yielded, and the iterator ends.
+    ///
+    /// Iterator element type is `Result<Self::Item, E>`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// fn less_than(a: &f64, b: &f64) -> Result<bool, String> {
+    ///     a.partial_cmp(b)
+    ///      .map(|ord| ord == std::cmp::Ordering::Less)
+    ///      .ok_or_else(|| format!("can't compare {} and {}", a, b))
+    /// }
+    ///
+    /// let merged: Result<Vec<_>, _> = vec![vec![1., 3.], vec![2., 4.]]
+    ///     .into_iter().try_kmerge_by(less_than).collect();
+    /// assert_eq!(merged, Ok(vec![1., 2., 3., 4.]));
+    ///
+    /// let mut merged = vec![vec![1., std::f64::NAN], vec![2., 4.]]
+    ///     .into_iter().try_kmerge_by(less_than);
+    /// assert_eq!(merged.next(), Some(Ok(1.)));
+    /// assert!(merged.next().unwrap().is_err());
+    /// assert_eq!(merged.next(), None);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn try_kmerge_by<F, E>(self, first: F)
+        -> TryKMergeBy<<Self::Item as IntoIterator>::IntoIter, F, E>
+        where Self: Sized,
+              Self::Item: IntoIterator,
+              F: FnMut(&<Self::Item as IntoIterator>::Item,
+                       &<Self::Item as IntoIterator>::Item) -> Result<bool, E>
+    {
+        kmerge_impl::try_kmerge_by(self, first)
+    }
+
     /// Return an iterator adaptor that iterates over the cartesian product of
     /// the element sets of two iterators `self` and `J`.
     ///
@@ -862,6 +1808,9 @@ pub trait Itertools : Iterator {
     /// let it = (0..2).cartesian_product("αβ".chars());
     /// itertools::assert_equal(it, vec![(0, 'α'), (0, 'β'), (1, 'α'), (1, 'β')]);
     /// ```
+    ///
+    /// The rest of a row can be skipped with
+    /// [`Product::skip_row`](structs/struct.Product.html#method.skip_row).
     fn cartesian_product<J>(self, other: J) -> Product<Self, J::IntoIter>
         where Self: Sized,
               Self::Item: Clone,
@@ -872,61 +1821,212 @@ pub trait Itertools : Iterator {
     }
 
     /// Return an iterator adaptor that iterates over the cartesian product of
-    /// all subiterators returned by meta-iterator `self`.
-    ///
-    /// All provided iterators must yield the same `Item` type. To generate
-    /// the product of iterators yielding multiple types, use the
-    /// [`iproduct`](macro.iproduct.html) macro instead.
+    /// an iterator of tuples `self` and the elements of `J`, extending each
+    /// tuple with an element of `J`.
     ///
+    /// This is `.cartesian_product(other).cons_tuples()`: starting from a
+    /// `.cartesian_product()`, each call adds one more iterator to the
+    /// product, and yields flat tuples like [`iproduct!`](macro.iproduct.html).
+    /// The tuples can have up to 12 elements.
     ///
-    /// The iterator element type is `Vec<T>`, where `T` is the iterator element
-    /// of the subiterators.
+    /// Iterator element type is `(A, B, ..., J::Item)`.
     ///
     /// ```
     /// use itertools::Itertools;
-    /// let mut multi_prod = (0..3).map(|i| (i * 2)..(i * 2 + 2))
-    ///     .multi_cartesian_product();
-    /// assert_eq!(multi_prod.next(), Some(vec![0, 2, 4]));
-    /// assert_eq!(multi_prod.next(), Some(vec![0, 2, 5]));
-    /// assert_eq!(multi_prod.next(), Some(vec![0, 3, 4]));
-    /// assert_eq!(multi_prod.next(), Some(vec![0, 3, 5]));
-    /// assert_eq!(multi_prod.next(), Some(vec![1, 2, 4]));
-    /// assert_eq!(multi_prod.next(), Some(vec![1, 2, 5]));
-    /// assert_eq!(multi_prod.next(), Some(vec![1, 3, 4]));
-    /// assert_eq!(multi_prod.next(), Some(vec![1, 3, 5]));
-    /// assert_eq!(multi_prod.next(), None);
+    ///
+    /// let it = (0..2).cartesian_product("ab".chars())
+    ///                .cartesian_product_flat(vec![true])
+    ///                .cartesian_product_flat(5..6);
+    /// itertools::assert_equal(it, vec![(0, 'a', true, 5), (0, 'b', true, 5),
+    ///                                  (1, 'a', true, 5), (1, 'b', true, 5)]);
     /// ```
-    #[cfg(feature = "use_std")]
-    fn multi_cartesian_product(self) -> MultiProduct<<Self::Item as IntoIterator>::IntoIter>
-        where Self: Iterator + Sized,
-              Self::Item: IntoIterator,
-              <Self::Item as IntoIterator>::IntoIter: Clone,
-              <Self::Item as IntoIterator>::Item: Clone
+    fn cartesian_product_flat<J>(self, other: J) -> ProductFlat<Self, J::IntoIter>
+        where Self: Sized,
+              Self::Item: Clone,
+              J: IntoIterator,
+              J::IntoIter: Clone,
+              ProductFlat<Self, J::IntoIter>: Iterator,
     {
-        adaptors::multi_cartesian_product(self)
+        cons_tuples(self.cartesian_product(other))
     }
 
-    /// Return an iterator adaptor that uses the passed-in closure to
-    /// optionally merge together consecutive elements.
-    ///
-    /// The closure `f` is passed two elements, `previous` and `current` and may
-    /// return either (1) `Ok(combined)` to merge the two values or
-    /// (2) `Err((previous', current'))` to indicate they can't be merged.
-    /// In (2), the value `previous'` is emitted by the iterator.
-    /// Either (1) `combined` or (2) `current'` becomes the previous value
-    /// when coalesce continues with the next pair of elements to merge. The
-    /// value that remains at the end is also emitted by the iterator.
+    /// Return an iterator adaptor that iterates over the cartesian product of
+    /// the element sets of two iterators `self` and `J`, without requiring
+    /// `J` to be `Clone`.
     ///
-    /// Iterator element type is `Self::Item`.
+    /// The elements of `J` are read once, during the first row, and cloned
+    /// into an internal `Vec` that the following rows iterate over. This
+    /// makes products with iterators that can't be restarted possible, like
+    /// the lines of a reader.
     ///
-    /// This iterator is *fused*.
+    /// Iterator element type is `(Self::Item, J::Item)`.
     ///
     /// ```
+    /// use std::io::BufRead;
     /// use itertools::Itertools;
     ///
-    /// // sum same-sign runs together
-    /// let data = vec![-1., -2., -3., 3., 1., 0., -1.];
-    /// itertools::assert_equal(data.into_iter().coalesce(|x, y|
+    /// let lines = "a\nb".as_bytes().lines().map(Result::unwrap);
+    /// let it = (0..2).cartesian_product_cached(lines);
+    /// itertools::assert_equal(it, vec![(0, "a".to_string()), (0, "b".to_string()),
+    ///                                  (1, "a".to_string()), (1, "b".to_string())]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn cartesian_product_cached<J>(self, other: J) -> CachedProduct<Self, J::IntoIter>
+        where Self: Sized,
+              Self::Item: Clone,
+              J: IntoIterator,
+              J::Item: Clone,
+    {
+        cached_product::cartesian_product_cached(self, other.into_iter())
+    }
+
+    /// Return an iterator adaptor that iterates over the cartesian product of
+    /// the iterator with itself.
+    ///
+    /// This is the same as `.cartesian_product(self.clone())`; see
+    /// [`.cartesian_square_with()`](#method.cartesian_square_with) to skip
+    /// the pairs of an element with itself, or to have each unordered
+    /// pair once.
+    ///
+    /// Iterator element type is `(Self::Item, Self::Item)`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (0..2).cartesian_square();
+    /// itertools::assert_equal(it, vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+    /// ```
+    fn cartesian_square(self) -> CartesianSquare<Self>
+        where Self: Sized + Clone,
+              Self::Item: Clone,
+    {
+        cartesian_square::cartesian_square(self, SquarePairs::Ordered)
+    }
+
+    /// Return an iterator adaptor that iterates over the pairs of elements
+    /// `(x[i], x[j])` of the iterator that `pairs` selects: all of them,
+    /// those with `i != j`, `i <= j` or `i < j`.
+    ///
+    /// The pairs are generated in order, without filtering out the others:
+    /// `SquarePairs::UnorderedDistinct` does half the work of the full square.
+    ///
+    /// Iterator element type is `(Self::Item, Self::Item)`.
+    ///
+    /// ```
+    /// use itertools::{Itertools, SquarePairs};
+    ///
+    /// let it = (0..3).cartesian_square_with(SquarePairs::OrderedDistinct);
+    /// itertools::assert_equal(it, vec![(0, 1), (0, 2), (1, 0), (1, 2), (2, 0), (2, 1)]);
+    ///
+    /// let it = (0..3).cartesian_square_with(SquarePairs::Unordered);
+    /// itertools::assert_equal(it, vec![(0, 0), (0, 1), (0, 2), (1, 1), (1, 2), (2, 2)]);
+    ///
+    /// let it = (0..3).cartesian_square_with(SquarePairs::UnorderedDistinct);
+    /// itertools::assert_equal(it, vec![(0, 1), (0, 2), (1, 2)]);
+    /// ```
+    fn cartesian_square_with(self, pairs: SquarePairs) -> CartesianSquare<Self>
+        where Self: Sized + Clone,
+              Self::Item: Clone,
+    {
+        cartesian_square::cartesian_square(self, pairs)
+    }
+
+    /// Return an iterator adaptor that iterates over the cartesian product of
+    /// all subiterators returned by meta-iterator `self`.
+    ///
+    /// All provided iterators must yield the same `Item` type. To generate
+    /// the product of iterators yielding multiple types, use the
+    /// [`iproduct`](macro.iproduct.html) macro instead.
+    ///
+    ///
+    /// The iterator element type is `Vec<T>`, where `T` is the iterator element
+    /// of the subiterators.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// let mut multi_prod = (0..3).map(|i| (i * 2)..(i * 2 + 2))
+    ///     .multi_cartesian_product();
+    /// assert_eq!(multi_prod.next(), Some(vec![0, 2, 4]));
+    /// assert_eq!(multi_prod.next(), Some(vec![0, 2, 5]));
+    /// assert_eq!(multi_prod.next(), Some(vec![0, 3, 4]));
+    /// assert_eq!(multi_prod.next(), Some(vec![0, 3, 5]));
+    /// assert_eq!(multi_prod.next(), Some(vec![1, 2, 4]));
+    /// assert_eq!(multi_prod.next(), Some(vec![1, 2, 5]));
+    /// assert_eq!(multi_prod.next(), Some(vec![1, 3, 4]));
+    /// assert_eq!(multi_prod.next(), Some(vec![1, 3, 5]));
+    /// assert_eq!(multi_prod.next(), None);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn multi_cartesian_product(self) -> MultiProduct<<Self::Item as IntoIterator>::IntoIter>
+        where Self: Iterator + Sized,
+              Self::Item: IntoIterator,
+              <Self::Item as IntoIterator>::IntoIter: Clone,
+              <Self::Item as IntoIterator>::Item: Clone
+    {
+        adaptors::multi_cartesian_product(self)
+    }
+
+    /// Return an iterator adaptor that flattens tuples of the form
+    /// `((A, B, ...), X, Y, ...)` into `(A, B, ..., X, Y, ...)`.
+    ///
+    /// The inner tuple is the first element and has at least 2 elements,
+    /// and flat tuples of up to 12 elements can be produced.
+    ///
+    /// Each application removes the nesting of the first element, so the
+    /// tuples nested several times, like `(((A, B), C), D)` from chaining
+    /// `.zip()` or `.cartesian_product()`, are flattened by applying
+    /// `.cons_tuples()` once per level, or after each step, which is how the
+    /// [`iproduct!`](macro.iproduct.html) macro works. Since the elements
+    /// may be tuples themselves, the nesting depth can't be found from the
+    /// types alone.
+    ///
+    /// Iterator element type is `(A, B, ..., X, Y, ...)`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (0..2).cartesian_product("ab".chars())
+    ///                .cartesian_product(vec![true]).cons_tuples();
+    /// itertools::assert_equal(it, vec![(0, 'a', true), (0, 'b', true),
+    ///                                  (1, 'a', true), (1, 'b', true)]);
+    ///
+    /// let it = (0..2).zip(10..12).zip(20..22).cons_tuples()
+    ///                .zip(30..32).cons_tuples();
+    /// itertools::assert_equal(it, vec![(0, 10, 20, 30), (1, 11, 21, 31)]);
+    ///
+    /// // `(((i32, i32), i32), i32)` to `((i32, i32), i32, i32)`, then flat
+    /// let it = (0..2).zip(10..12).zip(20..22).zip(30..32)
+    ///                .cons_tuples().cons_tuples();
+    /// itertools::assert_equal(it, vec![(0, 10, 20, 30), (1, 11, 21, 31)]);
+    /// ```
+    fn cons_tuples<J>(self) -> ConsTuples<Self, J>
+        where Self: Sized + Iterator<Item = J>,
+              ConsTuples<Self, J>: Iterator,
+    {
+        cons_tuples(self)
+    }
+
+    /// Return an iterator adaptor that uses the passed-in closure to
+    /// optionally merge together consecutive elements.
+    ///
+    /// The closure `f` is passed two elements, `previous` and `current` and may
+    /// return either (1) `Ok(combined)` to merge the two values or
+    /// (2) `Err((previous', current'))` to indicate they can't be merged.
+    /// In (2), the value `previous'` is emitted by the iterator.
+    /// Either (1) `combined` or (2) `current'` becomes the previous value
+    /// when coalesce continues with the next pair of elements to merge. The
+    /// value that remains at the end is also emitted by the iterator.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // sum same-sign runs together
+    /// let data = vec![-1., -2., -3., 3., 1., 0., -1.];
+    /// itertools::assert_equal(data.into_iter().coalesce(|x, y|
     ///         if (x >= 0.) == (y >= 0.) {
     ///             Ok(x + y)
     ///         } else {
@@ -1030,6 +2130,206 @@ pub trait Itertools : Iterator {
         unique_impl::unique_by(self, f)
     }
 
+    /// Return an iterator over the elements of this iterator without
+    /// duplicates, keeping the **last** occurrence of each element instead
+    /// of the first. Duplicates are detected using hash and equality.
+    ///
+    /// The elements are yielded in the order of their last occurrence.
+    ///
+    /// **Note:** This consumes the entire iterator (like `.sorted()`) since
+    /// the last occurrence of an element is only known at the end, and
+    /// returns the result as a new iterator that owns its elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![10, 20, 30, 20, 40, 10, 50];
+    /// itertools::assert_equal(data.into_iter().unique_last(),
+    ///                         vec![30, 20, 40, 10, 50]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn unique_last(self) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              Self::Item: Eq + Hash
+    {
+        unique_impl::unique_last(self).into_iter()
+    }
+
+    /// Return an iterator over the elements of this iterator without
+    /// duplicates, keeping the **last** element of each key.
+    ///
+    /// Duplicates are detected by comparing the key they map to
+    /// with the keying function `f` by hash and equality.
+    /// The elements are yielded in the order of their last occurrence.
+    ///
+    /// **Note:** This consumes the entire iterator (like `.sorted()`) since
+    /// the last occurrence of a key is only known at the end, and
+    /// returns the result as a new iterator that owns its elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // later settings override earlier ones
+    /// let settings = vec![("color", "red"), ("size", "big"), ("color", "blue")];
+    /// itertools::assert_equal(settings.into_iter().unique_by_last(|s| s.0),
+    ///                         vec![("size", "big"), ("color", "blue")]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn unique_by_last<V, F>(self, f: F) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              V: Eq + Hash,
+              F: FnMut(&Self::Item) -> V
+    {
+        unique_impl::unique_by_last(self, f).into_iter()
+    }
+
+    /// Split the elements of this iterator into the first occurrence of each
+    /// element, and the later occurrences: the duplicates.
+    ///
+    /// Duplicates are detected using hash and equality, in a single pass.
+    /// Both vectors keep the order of the elements in the iterator.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![10, 20, 30, 20, 40, 10, 20];
+    /// let (unique, duplicates) = data.into_iter().unique_with_rest();
+    /// assert_eq!(unique, vec![10, 20, 30, 40]);
+    /// assert_eq!(duplicates, vec![20, 10, 20]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn unique_with_rest(self) -> (Vec<Self::Item>, Vec<Self::Item>)
+        where Self: Sized,
+              Self::Item: Clone + Eq + Hash
+    {
+        unique_impl::unique_with_rest(self)
+    }
+
+    /// Split the elements of this iterator into the first element of each
+    /// key, and the later elements with an already seen key.
+    ///
+    /// Duplicates are detected by comparing the key they map to
+    /// with the keying function `f` by hash and equality, in a single pass.
+    /// Both vectors keep the order of the elements in the iterator.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let records = vec![(1, "ann"), (2, "bob"), (1, "abe")];
+    /// let (kept, rejected) = records.into_iter().unique_by_with_rest(|r| r.0);
+    /// assert_eq!(kept, vec![(1, "ann"), (2, "bob")]);
+    /// assert_eq!(rejected, vec![(1, "abe")]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn unique_by_with_rest<V, F>(self, f: F) -> (Vec<Self::Item>, Vec<Self::Item>)
+        where Self: Sized,
+              V: Eq + Hash,
+              F: FnMut(&Self::Item) -> V
+    {
+        unique_impl::unique_by_with_rest(self, f)
+    }
+
+    /// Return an iterator adaptor that yields the elements that are equal to
+    /// an earlier element, once per distinct value: the second occurrence of
+    /// each value is yielded, and the following ones are skipped.
+    ///
+    /// This is the opposite view of [`.unique()`](#method.unique).
+    /// Duplicates are detected using hash and equality; the first occurrence
+    /// of each value is stored in a hash map in the iterator.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![10, 20, 30, 20, 40, 10, 50, 20];
+    /// itertools::assert_equal(data.into_iter().duplicates(),
+    ///                         vec![20, 10]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn duplicates(self) -> Duplicates<Self>
+        where Self: Sized,
+              Self::Item: Eq + Hash
+    {
+        duplicates_impl::duplicates(self)
+    }
+
+    /// Return an iterator adaptor that yields the elements whose key is
+    /// equal to the key of an earlier element, once per distinct key: the
+    /// second element with each key is yielded, and the following ones are
+    /// skipped.
+    ///
+    /// Duplicates are detected by comparing the key they map to
+    /// with the keying function `f` by hash and equality.
+    /// The keys are stored in a hash map in the iterator.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec!["a", "bb", "aa", "c", "ccc"];
+    /// itertools::assert_equal(data.into_iter().duplicates_by(|s| s.len()),
+    ///                         vec!["aa", "c"]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn duplicates_by<K, F>(self, f: F) -> DuplicatesBy<Self, K, F>
+        where Self: Sized,
+              K: Eq + Hash,
+              F: FnMut(&Self::Item) -> K
+    {
+        duplicates_impl::duplicates_by(self, f)
+    }
+
+    /// Return an iterator adaptor that yields each element that is equal to
+    /// an earlier element, with its index and the index of the first
+    /// element it is equal to: `(first_index, index, element)`.
+    ///
+    /// Duplicates are detected by hashing; each distinct element is cloned
+    /// once and kept in a hash map with the index where it first occurred.
+    ///
+    /// Iterator element type is `(usize, usize, Self::Item)`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let keys = vec!["host", "port", "user", "port", "host", "port"];
+    /// itertools::assert_equal(keys.into_iter().duplicate_positions(),
+    ///                         vec![(1, 3, "port"), (0, 4, "host"), (1, 5, "port")]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn duplicate_positions(self) -> DuplicatePositions<Self>
+        where Self: Sized,
+              Self::Item: Clone + Eq + Hash,
+    {
+        duplicate_positions::duplicate_positions(self)
+    }
+
+    /// Estimate the number of distinct elements in the iterator, using the
+    /// HyperLogLog algorithm.
+    ///
+    /// Unlike `.unique().count()`, the memory used is constant: `2^precision`
+    /// bytes, where `precision` must be in `4..=16`. The typical relative
+    /// error of the estimate is `1.04 / sqrt(2^precision)`, i.e. about 1.6%
+    /// for a precision of 12 (using 4 KiB).
+    ///
+    /// Elements are hashed with the standard library's default hasher using
+    /// fixed keys, so the estimate is deterministic.
+    ///
+    /// **Panics** if `precision` is not in `4..=16`.
+    ///
+    /// Requires the crate feature `approx_unique`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let estimate = (0..10_000).map(|i| i % 2500).approx_unique_count(12);
+    /// assert!(2400 < estimate && estimate < 2600);
+    /// ```
+    #[cfg(feature = "approx_unique")]
+    fn approx_unique_count(self, precision: u8) -> usize
+        where Self: Sized,
+              Self::Item: Hash
+    {
+        approx_unique::approx_unique_count(self, precision)
+    }
+
     /// Return an iterator adaptor that borrows from this iterator and
     /// takes items while the closure `accept` returns `true`.
     ///
@@ -1074,6 +2374,34 @@ pub trait Itertools : Iterator {
         adaptors::take_while_ref(self, accept)
     }
 
+    /// Return an iterator adaptor that takes items while the predicate
+    /// `accept` returns `true`, and then the first item for which it returns
+    /// `false`, if any.
+    ///
+    /// Unlike `.take_while()`, the rejected element is yielded instead of
+    /// being lost: it suits reading up to a terminator, included. The
+    /// adaptor is fused, the iterator isn't advanced once it is done.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let items = vec![1, 2, 3, 4, 5];
+    /// let filtered: Vec<_> = items.into_iter().take_while_inclusive(|&n| n % 3 != 0).collect();
+    /// assert_eq!(filtered, vec![1, 2, 3]);
+    ///
+    /// // read a record up to and including its terminator
+    /// let mut bytes = b"abc;de;".iter().cloned();
+    /// let record: Vec<u8> = bytes.by_ref().take_while_inclusive(|&b| b != b';').collect();
+    /// assert_eq!(record, b"abc;");
+    /// assert_eq!(bytes.next(), Some(b'd'));
+    /// ```
+    fn take_while_inclusive<F>(self, accept: F) -> TakeWhileInclusive<Self, F>
+        where Self: Sized,
+              F: FnMut(&Self::Item) -> bool
+    {
+        adaptors::take_while_inclusive(self, accept)
+    }
+
     /// Return an iterator adaptor that filters `Option<A>` iterator elements
     /// and produces `A`. Stops on the first `None` encountered.
     ///
@@ -1201,593 +2529,1393 @@ pub trait Itertools : Iterator {
         combinations_with_replacement::combinations_with_replacement(self, n)
     }
 
-    /// Return an iterator adaptor that pads the sequence to a minimum length of
-    /// `min` by filling missing elements using a closure `f`.
+    /// Return an iterator that iterates through the powerset of the elements from an
+    /// iterator.
     ///
-    /// Iterator element type is `Self::Item`.
+    /// Iterator element type is `Vec<Self::Item>`. The iterator produces a new `Vec`
+    /// per iteration, and clones the iterator elements.
     ///
-    /// ```
-    /// use itertools::Itertools;
+    /// The powerset of a set contains all subsets including the empty set and the full
+    /// input set. A powerset has length _2^n_ where _n_ is the length of the input
+    /// set. The subsets are produced by increasing length, each length in the
+    /// order of [`.combinations()`](#method.combinations).
     ///
-    /// let it = (0..5).pad_using(10, |i| 2*i);
-    /// itertools::assert_equal(it, vec![0, 1, 2, 3, 4, 10, 12, 14, 16, 18]);
+    /// Each `Vec` produced by this iterator represents a subset of the elements
+    /// produced by the source iterator.
     ///
-    /// let it = (0..10).pad_using(5, |i| 2*i);
-    /// itertools::assert_equal(it, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    /// use itertools::Itertools;
     ///
-    /// let it = (0..5).pad_using(10, |i| 2*i).rev();
-    /// itertools::assert_equal(it, vec![18, 16, 14, 12, 10, 4, 3, 2, 1, 0]);
+    /// let sets = (1..4).powerset().collect::<Vec<_>>();
+    /// itertools::assert_equal(sets, vec![
+    ///     vec![],
+    ///     vec![1],
+    ///     vec![2],
+    ///     vec![3],
+    ///     vec![1, 2],
+    ///     vec![1, 3],
+    ///     vec![2, 3],
+    ///     vec![1, 2, 3],
+    /// ]);
     /// ```
-    fn pad_using<F>(self, min: usize, f: F) -> PadUsing<Self, F>
+    #[cfg(feature = "use_std")]
+    fn powerset(self) -> Powerset<Self>
         where Self: Sized,
-              F: FnMut(usize) -> Self::Item
+              Self::Item: Clone,
     {
-        pad_tail::pad_using(self, min, f)
+        powerset::powerset(self)
     }
 
-    /// Return an iterator adaptor that wraps each element in a `Position` to
-    /// ease special-case handling of the first or last elements.
+    /// Return an iterator adaptor that iterates over all `k`-permutations of the
+    /// elements from an iterator.
     ///
-    /// Iterator element type is
-    /// [`Position<Self::Item>`](enum.Position.html)
+    /// Iterator element type is `Vec<Self::Item>` with length `k`. The iterator
+    /// produces a new Vec per iteration, and clones the iterator elements.
+    ///
+    /// The permutations are produced lazily, in the lexicographic order of the
+    /// positions of their elements in the source, like the ones of
+    /// [`permutation_indices()`](fn.permutation_indices.html). The elements
+    /// are buffered as they are needed, so the source can be infinite.
+    ///
+    /// If `k` is greater than the length of the source, there are no
+    /// permutations. If `k` is zero, there is a single, empty one.
     ///
     /// ```
-    /// use itertools::{Itertools, Position};
+    /// use itertools::Itertools;
     ///
-    /// let it = (0..4).with_position();
-    /// itertools::assert_equal(it,
-    ///                         vec![Position::First(0),
-    ///                              Position::Middle(1),
-    ///                              Position::Middle(2),
-    ///                              Position::Last(3)]);
+    /// let perms = (5..8).permutations(2);
+    /// itertools::assert_equal(perms, vec![
+    ///     vec![5, 6],
+    ///     vec![5, 7],
+    ///     vec![6, 5],
+    ///     vec![6, 7],
+    ///     vec![7, 5],
+    ///     vec![7, 6],
+    /// ]);
+    /// ```
+    ///
+    /// Note: Permutations does not take into account the equality of the iterated values.
     ///
-    /// let it = (0..1).with_position();
-    /// itertools::assert_equal(it, vec![Position::Only(0)]);
     /// ```
-    fn with_position(self) -> WithPosition<Self>
+    /// use itertools::Itertools;
+    ///
+    /// let it = vec![2, 2].into_iter().permutations(2);
+    /// itertools::assert_equal(it, vec![
+    ///     vec![2, 2], // Note: these are the same
+    ///     vec![2, 2], // Note: these are the same
+    /// ]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn permutations(self, k: usize) -> Permutations<Self>
         where Self: Sized,
+              Self::Item: Clone,
     {
-        with_position::with_position(self)
+        permutations::permutations(self, k)
     }
 
-    /// Return an iterator adaptor that yields the indices of all elements
-    /// satisfying a predicate, counted from the start of the iterator.
+    /// Return an iterator adaptor that pads the sequence to a minimum length of
+    /// `min` by filling missing elements using a closure `f`.
     ///
-    /// Equivalent to `iter.enumerate().filter(|(_, v)| predicate(v)).map(|(i, _)| i)`.
+    /// Iterator element type is `Self::Item`.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let data = vec![1, 2, 3, 3, 4, 6, 7, 9];
-    /// itertools::assert_equal(data.iter().positions(|v| v % 2 == 0), vec![1, 4, 5]);
+    /// let it = (0..5).pad_using(10, |i| 2*i);
+    /// itertools::assert_equal(it, vec![0, 1, 2, 3, 4, 10, 12, 14, 16, 18]);
     ///
-    /// itertools::assert_equal(data.iter().positions(|v| v % 2 == 1).rev(), vec![7, 6, 3, 2, 0]);
+    /// let it = (0..10).pad_using(5, |i| 2*i);
+    /// itertools::assert_equal(it, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    ///
+    /// let it = (0..5).pad_using(10, |i| 2*i).rev();
+    /// itertools::assert_equal(it, vec![18, 16, 14, 12, 10, 4, 3, 2, 1, 0]);
     /// ```
-    fn positions<P>(self, predicate: P) -> Positions<Self, P>
+    fn pad_using<F>(self, min: usize, f: F) -> PadUsing<Self, F>
         where Self: Sized,
-              P: FnMut(Self::Item) -> bool,
+              F: FnMut(usize) -> Self::Item
     {
-        adaptors::positions(self, predicate)
+        pad_tail::pad_using(self, min, f)
     }
 
-    /// Return an iterator adaptor that applies a mutating function
-    /// to each element before yielding it.
+    /// Return an iterator adaptor that pads the sequence to a minimum length of
+    /// `min` by filling missing elements at the front using a closure `f`.
+    ///
+    /// The closure is called with the position of each missing element, like
+    /// for [`.pad_using()`](#method.pad_using). To know how many elements are
+    /// missing, the first `min` elements are buffered before the first one is
+    /// yielded.
+    ///
+    /// Iterator element type is `Self::Item`.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let input = vec![vec![1], vec![3, 2, 1]];
-    /// let it = input.into_iter().update(|mut v| v.push(0));
-    /// itertools::assert_equal(it, vec![vec![1, 0], vec![3, 2, 1, 0]]);
+    /// let it = (1..4).pad_front_using(6, |_| 0);
+    /// itertools::assert_equal(it, vec![0, 0, 0, 1, 2, 3]);
+    ///
+    /// let it = (1..4).pad_front_using(2, |_| 0);
+    /// itertools::assert_equal(it, vec![1, 2, 3]);
+    ///
+    /// // right-align a number
+    /// let digits: String = "42".chars().pad_front_using(5, |_| ' ').collect();
+    /// assert_eq!(digits, "   42");
     /// ```
-    fn update<F>(self, updater: F) -> Update<Self, F>
+    #[cfg(feature = "use_std")]
+    fn pad_front_using<F>(self, min: usize, f: F) -> PadFrontUsing<Self, F>
         where Self: Sized,
-              F: FnMut(&mut Self::Item),
+              F: FnMut(usize) -> Self::Item
     {
-        adaptors::update(self, updater)
+        pad_front::pad_front_using(self, min, f)
     }
 
-    // non-adaptor methods
-    /// Advances the iterator and returns the next items grouped in a tuple of
-    /// a specific size (up to 4).
+    /// Return an iterator adaptor that pads the sequence to a minimum length of
+    /// `min` by filling missing elements at both ends using a closure `f`.
     ///
-    /// If there are enough elements to be grouped in a tuple, then the tuple is
-    /// returned inside `Some`, otherwise `None` is returned.
+    /// The missing elements are split evenly between the front and the back,
+    /// with one more at the back if their number is odd: the sequence is
+    /// centered. Like for [`.pad_front_using()`](#method.pad_front_using),
+    /// the closure is called with the position of each missing element, and
+    /// the first `min` elements are buffered.
+    ///
+    /// Iterator element type is `Self::Item`.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let mut iter = 1..5;
+    /// let it = (1..4).pad_both_using(8, |i| i * 10);
+    /// itertools::assert_equal(it, vec![0, 10, 1, 2, 3, 50, 60, 70]);
     ///
-    /// assert_eq!(Some((1, 2)), iter.next_tuple());
+    /// let title: String = "menu".chars().pad_both_using(10, |_| '-').collect();
+    /// assert_eq!(title, "---menu---");
     /// ```
-    fn next_tuple<T>(&mut self) -> Option<T>
-        where Self: Sized + Iterator<Item = T::Item>,
-              T: tuple_impl::TupleCollect
+    #[cfg(feature = "use_std")]
+    fn pad_both_using<F>(self, min: usize, f: F) -> PadBothUsing<Self, F>
+        where Self: Sized,
+              F: FnMut(usize) -> Self::Item
     {
-        T::collect_from_iter_no_buf(self)
+        pad_front::pad_both_using(self, min, f)
     }
 
-    /// Collects all items from the iterator into a tuple of a specific size
-    /// (up to 4).
+    /// Return an iterator adaptor that yields all but the last `n` elements
+    /// of the iterator.
     ///
-    /// If the number of elements inside the iterator is **exactly** equal to
-    /// the tuple size, then the tuple is returned inside `Some`, otherwise
-    /// `None` is returned.
+    /// The adaptor keeps the `n` most recent elements in a buffer, so the
+    /// length of the iterator does not need to be known in advance, and
+    /// it is not collected. If the iterator has `n` elements or fewer,
+    /// nothing is yielded.
+    ///
+    /// Iterator element type is `Self::Item`.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let iter = 1..3;
+    /// let it = (0..6).drop_last(2);
+    /// itertools::assert_equal(it, vec![0, 1, 2, 3]);
     ///
-    /// if let Some((x, y)) = iter.collect_tuple() {
-    ///     assert_eq!((x, y), (1, 2))
-    /// } else {
-    ///     panic!("Expected two elements")
-    /// }
+    /// // strip a trailing footer line
+    /// let text = "name,age\nalice,30\nbob,25\n-- 2 records";
+    /// itertools::assert_equal(text.lines().skip(1).drop_last(1),
+    ///                         vec!["alice,30", "bob,25"]);
+    ///
+    /// let it = (0..2).drop_last(3);
+    /// itertools::assert_equal(it, Vec::<i32>::new());
     /// ```
-    fn collect_tuple<T>(mut self) -> Option<T>
-        where Self: Sized + Iterator<Item = T::Item>,
-              T: tuple_impl::TupleCollect
+    #[cfg(feature = "use_std")]
+    fn drop_last(self, n: usize) -> DropLast<Self>
+        where Self: Sized
     {
-        match self.next_tuple() {
-            elt @ Some(_) => match self.next() {
-                Some(_) => None,
-                None => elt,
-            },
-            _ => None
-        }
+        drop_last_impl::drop_last(self, n)
     }
 
-
-    /// Find the position and value of the first element satisfying a predicate.
+    /// Return an iterator adaptor that wraps each element in a `Position` to
+    /// ease special-case handling of the first or last elements.
     ///
-    /// The iterator is not advanced past the first element found.
+    /// Iterator element type is
+    /// [`Position<Self::Item>`](enum.Position.html)
     ///
     /// ```
-    /// use itertools::Itertools;
+    /// use itertools::{Itertools, Position};
     ///
-    /// let text = "Hα";
-    /// assert_eq!(text.chars().find_position(|ch| ch.is_lowercase()), Some((1, 'α')));
+    /// let it = (0..4).with_position();
+    /// itertools::assert_equal(it,
+    ///                         vec![Position::First(0),
+    ///                              Position::Middle(1),
+    ///                              Position::Middle(2),
+    ///                              Position::Last(3)]);
+    ///
+    /// let it = (0..1).with_position();
+    /// itertools::assert_equal(it, vec![Position::Only(0)]);
+    ///
+    /// // iterating from the back keeps the positions
+    /// let it = (0..3).with_position().rev();
+    /// itertools::assert_equal(it,
+    ///                         vec![Position::Last(2),
+    ///                              Position::Middle(1),
+    ///                              Position::First(0)]);
     /// ```
-    fn find_position<P>(&mut self, mut pred: P) -> Option<(usize, Self::Item)>
-        where P: FnMut(&Self::Item) -> bool
+    fn with_position(self) -> WithPosition<Self>
+        where Self: Sized,
     {
-        let mut index = 0usize;
-        for elt in self {
-            if pred(&elt) {
-                return Some((index, elt));
-            }
-            index += 1;
-        }
-        None
+        with_position::with_position(self)
     }
 
-    /// Check whether all elements compare equal.
+    /// Return an iterator adaptor that yields the minimum of each window of
+    /// `n` consecutive elements, like `windows(n)` does for slices.
     ///
-    /// Empty iterators are considered to have equal elements:
+    /// Nothing is yielded if the iterator has fewer than `n` elements.
+    /// Each element is cloned when it is the minimum of a window. If several
+    /// elements are equally minimum, the first one is yielded, like
+    /// `Iterator::min`.
+    ///
+    /// This uses a monotonic queue: it takes amortized constant time per
+    /// element and buffers at most `n` elements.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// **Panics** if `n` is zero.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let data = vec![1, 1, 1, 2, 2, 3, 3, 3, 4, 5, 5];
-    /// assert!(!data.iter().all_equal());
-    /// assert!(data[0..3].iter().all_equal());
-    /// assert!(data[3..5].iter().all_equal());
-    /// assert!(data[5..8].iter().all_equal());
-    ///
-    /// let data : Option<usize> = None;
-    /// assert!(data.into_iter().all_equal());
+    /// let it = vec![4, 2, 12, 3, 8, 7, 1].into_iter().window_min(3);
+    /// itertools::assert_equal(it, vec![2, 2, 3, 3, 1]);
     /// ```
-    fn all_equal(&mut self) -> bool
+    #[cfg(feature = "use_std")]
+    fn window_min(self, n: usize) -> WindowMin<Self>
         where Self: Sized,
-              Self::Item: PartialEq,
+              Self::Item: Clone + Ord,
     {
-        match self.next() {
-            None => true,
-            Some(a) => self.all(|x| a == x),
-        }
+        window_extrema::window_min(self, n)
     }
 
-    /// Consume the first `n` elements from the iterator eagerly,
-    /// and return the same iterator again.
+    /// Return an iterator adaptor that yields the maximum of each window of
+    /// `n` consecutive elements, like `windows(n)` does for slices.
     ///
-    /// It works similarly to *.skip(* `n` *)* except it is eager and
-    /// preserves the iterator type.
+    /// If several elements are equally maximum, the last one is yielded, like
+    /// `Iterator::max`. See [`.window_min()`](#method.window_min) for more
+    /// information.
+    ///
+    /// **Panics** if `n` is zero.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let mut iter = "αβγ".chars().dropping(2);
-    /// itertools::assert_equal(iter, "γ".chars());
+    /// let it = vec![4, 2, 12, 3, 8, 7, 1].into_iter().window_max(3);
+    /// itertools::assert_equal(it, vec![12, 12, 12, 8, 8]);
     /// ```
-    ///
-    /// *Fusing notes: if the iterator is exhausted by dropping,
-    /// the result of calling `.next()` again depends on the iterator implementation.*
-    fn dropping(mut self, n: usize) -> Self
-        where Self: Sized
+    #[cfg(feature = "use_std")]
+    fn window_max(self, n: usize) -> WindowMax<Self>
+        where Self: Sized,
+              Self::Item: Clone + Ord,
     {
-        if n > 0 {
-            self.nth(n - 1);
-        }
-        self
+        window_extrema::window_max(self, n)
     }
 
-    /// Consume the last `n` elements from the iterator eagerly,
-    /// and return the same iterator again.
+    /// Return an iterator adaptor that yields the minimum of each window of
+    /// `n` consecutive elements, as determined by the comparison function
+    /// `compare`.
     ///
-    /// This is only possible on double ended iterators. `n` may be
-    /// larger than the number of elements.
+    /// Like [`.window_min()`](#method.window_min), it uses a monotonic
+    /// queue, which makes the elements that are only `PartialOrd`, like
+    /// floats, or compared by a key, usable too. If several elements are
+    /// equally minimum, the first one is yielded, like `Iterator::min_by`.
     ///
-    /// Note: This method is eager, dropping the back elements immediately and
-    /// preserves the iterator type.
+    /// **Panics** if `n` is zero.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let init = vec![0, 3, 6, 9].into_iter().dropping_back(1);
-    /// itertools::assert_equal(init, vec![0, 3, 6]);
+    /// let prices = vec![4.5, 2.0, 12.25, 3.0, 8.5];
+    /// let lows = prices.into_iter().window_min_by(2, |a, b| a.partial_cmp(b).unwrap());
+    /// itertools::assert_equal(lows, vec![2.0, 2.0, 3.0, 3.0]);
     /// ```
-    fn dropping_back(mut self, n: usize) -> Self
+    #[cfg(feature = "use_std")]
+    fn window_min_by<F>(self, n: usize, compare: F) -> WindowMinBy<Self, F>
         where Self: Sized,
-              Self: DoubleEndedIterator
+              Self::Item: Clone,
+              F: FnMut(&Self::Item, &Self::Item) -> Ordering,
     {
-        if n > 0 {
-            (&mut self).rev().nth(n - 1);
-        }
-        self
+        window_extrema::window_min_by(self, n, compare)
     }
 
-    /// Run the closure `f` eagerly on each element of the iterator.
+    /// Return an iterator adaptor that yields the maximum of each window of
+    /// `n` consecutive elements, as determined by the comparison function
+    /// `compare`.
     ///
-    /// Consumes the iterator until its end.
+    /// If several elements are equally maximum, the last one is yielded, like
+    /// `Iterator::max_by`. See [`.window_min_by()`](#method.window_min_by)
+    /// for more information.
+    ///
+    /// **Panics** if `n` is zero.
     ///
     /// ```
-    /// use std::sync::mpsc::channel;
     /// use itertools::Itertools;
     ///
-    /// let (tx, rx) = channel();
+    /// let words = vec!["a", "ccc", "bb", "d", "e"];
+    /// let longest = words.into_iter().window_max_by(2, |a, b| a.len().cmp(&b.len()));
+    /// itertools::assert_equal(longest, vec!["ccc", "ccc", "bb", "e"]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn window_max_by<F>(self, n: usize, compare: F) -> WindowMaxBy<Self, F>
+        where Self: Sized,
+              Self::Item: Clone,
+              F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        window_extrema::window_max_by(self, n, compare)
+    }
+
+    /// Return an iterator adaptor that folds the elements like `.fold()`,
+    /// and yields the value of the accumulator after each element.
     ///
-    /// // use .foreach() to apply a function to each value -- sending it
-    /// (0..5).map(|x| x * 2 + 1).foreach(|x| { tx.send(x).unwrap(); } );
+    /// The initial value `init` itself isn't yielded: there is one value
+    /// per element. Unlike with `.scan()`, the accumulator is passed by value
+    /// to `f`, and cloned to be yielded.
     ///
-    /// drop(tx);
+    /// Iterator element type is `B`.
     ///
-    /// itertools::assert_equal(rx.iter(), vec![1, 3, 5, 7, 9]);
     /// ```
-    #[deprecated(note="Use .for_each() instead", since="0.8")]
-    fn foreach<F>(self, f: F)
-        where F: FnMut(Self::Item),
-              Self: Sized,
+    /// use itertools::Itertools;
+    ///
+    /// let running_max = vec![3, 1, 4, 1, 5, 9, 2].into_iter()
+    ///     .cumulative_fold(0, |max, x| if x > max { x } else { max });
+    /// itertools::assert_equal(running_max, vec![3, 3, 4, 4, 5, 9, 9]);
+    ///
+    /// let words = vec!["a", "b", "c"].into_iter()
+    ///     .cumulative_fold(String::new(), |acc, s| acc + s);
+    /// itertools::assert_equal(words, vec!["a", "ab", "abc"]);
+    /// ```
+    fn cumulative_fold<B, F>(self, init: B, f: F) -> CumulativeFold<Self, B, F>
+        where Self: Sized,
+              B: Clone,
+              F: FnMut(B, Self::Item) -> B,
     {
-        self.for_each(f)
+        cumulative_fold::cumulative_fold(self, init, f)
     }
 
-    /// Combine all an iterator's elements into one element by using `Extend`.
+    /// Return an iterator adaptor that yields the running sums of the
+    /// elements: the prefix sums, the last one being the sum of all the
+    /// elements.
     ///
-    /// This combinator will extend the first item with each of the rest of the
-    /// items of the iterator. If the iterator is empty, the default value of
-    /// `I::Item` is returned.
+    /// Iterator element type is `Self::Item`.
     ///
-    /// ```rust
+    /// ```
     /// use itertools::Itertools;
     ///
-    /// let input = vec![vec![1], vec![2, 3], vec![4, 5, 6]];
-    /// assert_eq!(input.into_iter().concat(),
-    ///            vec![1, 2, 3, 4, 5, 6]);
+    /// itertools::assert_equal((1..6).cumsum(), vec![1, 3, 6, 10, 15]);
+    /// itertools::assert_equal(vec![0.5, 0.25].into_iter().cumsum(), vec![0.5, 0.75]);
     /// ```
-    fn concat(self) -> Self::Item
+    fn cumsum(self) -> CumSum<Self>
         where Self: Sized,
-              Self::Item: Extend<<<Self as Iterator>::Item as IntoIterator>::Item> + IntoIterator + Default
+              Self::Item: Clone + Add<Output = Self::Item> + Sum,
     {
-        concat(self)
+        cumulative_fold::cumsum(self)
     }
 
-    /// `.collect_vec()` is simply a type specialization of `.collect()`,
-    /// for convenience.
-    #[cfg(feature = "use_std")]
-    fn collect_vec(self) -> Vec<Self::Item>
-        where Self: Sized
+    /// Return an iterator adaptor that yields the difference between each
+    /// element and the one before it: `b - a` for each pair of consecutive
+    /// elements `a`, `b`.
+    ///
+    /// The elements are subtracted by reference, so they are not cloned.
+    /// An iterator of fewer than two elements yields nothing.
+    ///
+    /// Iterator element type is `D`, the output of the subtraction.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// itertools::assert_equal(vec![1, 4, 9, 16].into_iter().diffs(), vec![3, 5, 7]);
+    /// itertools::assert_equal(vec![1.5, 1.0].into_iter().diffs(), vec![-0.5]);
+    /// itertools::assert_equal(Some(1).into_iter().diffs(), Vec::<i32>::new());
+    /// ```
+    fn diffs<D>(self) -> Diffs<Self, D>
+        where Self: Sized,
+              for<'a> &'a Self::Item: Sub<Output = D>,
     {
-        self.collect()
+        diffs_impl::diffs(self)
     }
 
-    /// Assign to each reference in `self` from the `from` iterator,
-    /// stopping at the shortest of the two iterators.
+    /// Return an iterator adaptor that yields the result of the closure
+    /// `f(a, b)` for each pair of consecutive elements `a`, `b`.
     ///
-    /// The `from` iterator is queried for its next element before the `self`
-    /// iterator, and if either is exhausted the method is done.
+    /// This is the general form of [`.diffs()`](#method.diffs): the closure
+    /// receives references to both elements, which are not cloned.
+    /// An iterator of fewer than two elements yields nothing.
     ///
-    /// Return the number of elements written.
+    /// Iterator element type is `R`.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let mut xs = [0; 4];
-    /// xs.iter_mut().set_from(1..);
-    /// assert_eq!(xs, [1, 2, 3, 4]);
+    /// let words = vec!["apple", "apricot", "banana"];
+    /// let same_initial = words.into_iter()
+    ///     .diffs_by(|a, b| a.chars().next() == b.chars().next());
+    /// itertools::assert_equal(same_initial, vec![true, false]);
+    ///
+    /// let ratios = vec![1u32, 2, 8].into_iter().diffs_by(|a, b| b / a);
+    /// itertools::assert_equal(ratios, vec![2, 4]);
     /// ```
-    #[inline]
-    fn set_from<'a, A: 'a, J>(&mut self, from: J) -> usize
-        where Self: Iterator<Item = &'a mut A>,
-              J: IntoIterator<Item = A>
+    fn diffs_by<F, R>(self, f: F) -> DiffsBy<Self, F>
+        where Self: Sized,
+              F: FnMut(&Self::Item, &Self::Item) -> R,
     {
-        let mut count = 0;
-        for elt in from {
-            match self.next() {
-                None => break,
-                Some(ptr) => *ptr = elt,
-            }
-            count += 1;
-        }
-        count
+        diffs_impl::diffs_by(self, f)
     }
 
-    /// Combine all iterator elements into one String, separated by `sep`.
+    /// Return an iterator adaptor that yields the sum of each window of
+    /// `n` consecutive elements.
     ///
-    /// Use the `Display` implementation of each element.
+    /// Nothing is yielded if the iterator has fewer than `n` elements.
+    /// The adaptor buffers the last `n` elements and keeps a running total,
+    /// adding the element entering the window and subtracting the one
+    /// leaving it, so each sum takes constant time.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// **Panics** if `n` is zero.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// assert_eq!(["a", "b", "c"].iter().join(", "), "a, b, c");
-    /// assert_eq!([1, 2, 3].iter().join(", "), "1, 2, 3");
+    /// let it = (1..7).rolling_sum(3);
+    /// itertools::assert_equal(it, vec![6, 9, 12, 15]);
     /// ```
     #[cfg(feature = "use_std")]
-    fn join(&mut self, sep: &str) -> String
-        where Self::Item: std::fmt::Display
+    fn rolling_sum(self, n: usize) -> RollingSum<Self>
+        where Self: Sized,
+              Self::Item: Clone + Add<Output = Self::Item> + Sub<Output = Self::Item>,
     {
-        match self.next() {
-            None => String::new(),
-            Some(first_elt) => {
-                // estimate lower bound of capacity needed
-                let (lower, _) = self.size_hint();
-                let mut result = String::with_capacity(sep.len() * lower);
-                write!(&mut result, "{}", first_elt).unwrap();
-                for elt in self {
-                    result.push_str(sep);
-                    write!(&mut result, "{}", elt).unwrap();
-                }
-                result
-            }
-        }
+        rolling::rolling_sum(self, n)
     }
 
-    /// Format all iterator elements, separated by `sep`.
+    /// Return an iterator adaptor that yields the mean of each window of
+    /// `n` consecutive elements, as an `f64`.
     ///
-    /// All elements are formatted (any formatting trait)
-    /// with `sep` inserted between each element.
+    /// This is [`.rolling_sum(n)`](#method.rolling_sum) divided by `n`,
+    /// except that the running total is kept as an `f64`, so that it cannot
+    /// overflow the element type. It accumulates rounding errors over very
+    /// long iterators.
     ///
-    /// **Panics** if the formatter helper is formatted more than once.
+    /// Iterator element type is `f64`.
+    ///
+    /// **Panics** if `n` is zero.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let data = [1.1, 2.71828, -3.];
-    /// assert_eq!(
-    ///     format!("{:.2}", data.iter().format(", ")),
-    ///            "1.10, 2.72, -3.00");
+    /// let prices = vec![10, 11, 15, 10];
+    /// itertools::assert_equal(prices.into_iter().rolling_mean(2), vec![10.5, 13., 12.5]);
     /// ```
-    fn format(self, sep: &str) -> Format<Self>
+    #[cfg(feature = "use_std")]
+    fn rolling_mean(self, n: usize) -> RollingMean<Self>
         where Self: Sized,
+              Self::Item: ToF64,
     {
-        format::new_format_default(self, sep)
+        rolling::rolling_mean(self, n)
     }
 
-    /// Format all iterator elements, separated by `sep`.
-    ///
-    /// This is a customizable version of `.format()`.
+    /// Return an iterator adaptor that runs the iterator on a background
+    /// thread, which produces up to `capacity` elements ahead of the consumer.
     ///
-    /// The supplied closure `format` is called once per iterator element,
-    /// with two arguments: the element and a callback that takes a
-    /// `&Display` value, i.e. any reference to type that implements `Display`.
+    /// This lets a slow producer (reading or decoding records, say) work at
+    /// the same time as a slow consumer. The elements are passed through a
+    /// bounded channel; with a `capacity` of zero, the producer waits for
+    /// each element to be received before computing the next one.
     ///
-    /// Using `&format_args!(...)` is the most versatile way to apply custom
-    /// element formatting. The callback can be called multiple times if needed.
+    /// The thread starts as soon as the adaptor is created. If the adaptor
+    /// is dropped, the thread stops after the element it is producing.
+    /// If the iterator panics, the panic is resumed in the consumer
+    /// once the elements produced before it are received.
     ///
-    /// **Panics** if the formatter helper is formatted more than once.
+    /// Iterator element type is `Self::Item`.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let data = [1.1, 2.71828, -3.];
-    /// let data_formatter = data.iter().format_with(", ", |elt, f| f(&format_args!("{:.2}", elt)));
-    /// assert_eq!(format!("{}", data_formatter),
-    ///            "1.10, 2.72, -3.00");
+    /// let lines = (0..100).map(|i| format!("line {}", i)).readahead(16);
+    /// assert_eq!(lines.map(|line| line.len()).sum::<usize>(), 10 * 6 + 90 * 7);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn readahead(self, capacity: usize) -> ReadAhead<Self::Item>
+        where Self: Sized + Send + 'static,
+              Self::Item: Send + 'static,
+    {
+        readahead::readahead(self, capacity)
+    }
+
+    /// Return an iterator adaptor that applies `f` to each element on a pool
+    /// of `n_threads` worker threads, and yields the results in the order
+    /// of the elements.
     ///
-    /// // .format_with() is recursively composable
-    /// let matrix = [[1., 2., 3.],
-    ///               [4., 5., 6.]];
-    /// let matrix_formatter = matrix.iter().format_with("\n", |row, f| {
-    ///                                 f(&row.iter().format_with(", ", |elt, g| g(&elt)))
-    ///                              });
-    /// assert_eq!(format!("{}", matrix_formatter),
-    ///            "1, 2, 3\n4, 5, 6");
+    /// The iterator itself stays on the calling thread, and runs at most
+    /// `2 * n_threads` elements ahead of the results yielded so far; results
+    /// that are ready early wait in a reordering buffer. The workers start
+    /// as soon as the adaptor is created and stop when it is dropped or
+    /// exhausted. If `f` panics, the panic is resumed in the consumer.
+    ///
+    /// Iterator element type is `U`.
     ///
+    /// **Panics** if `n_threads` is zero.
     ///
     /// ```
-    fn format_with<F>(self, sep: &str, format: F) -> FormatWith<Self, F>
+    /// use itertools::Itertools;
+    ///
+    /// let it = (0..20u64).map_parallel(4, |n| (0..n).sum::<u64>());
+    /// itertools::assert_equal(it, (0..20u64).map(|n| n * n.saturating_sub(1) / 2));
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn map_parallel<F, U>(self, n_threads: usize, f: F) -> MapParallel<Self, U>
         where Self: Sized,
-              F: FnMut(Self::Item, &mut FnMut(&fmt::Display) -> fmt::Result) -> fmt::Result,
+              Self::Item: Send + 'static,
+              F: Fn(Self::Item) -> U + Send + Sync + 'static,
+              U: Send + 'static,
     {
-        format::new_format(self, sep, format)
+        map_parallel::map_parallel(self, n_threads, f)
     }
 
-    /// Fold `Result` values from an iterator.
+    /// Return an iterator adaptor that yields the key and the length of each
+    /// run of consecutive elements for which `key` returns equal values.
     ///
-    /// Only `Ok` values are folded. If no error is encountered, the folded
-    /// value is returned inside `Ok`. Otherwise, the operation terminates
-    /// and returns the first `Err` value it encounters. No iterator elements are
-    /// consumed after the first error.
+    /// Like `.group_by(key).into_iter().map(|(k, g)| (k, g.count()))`, but
+    /// the adaptor is an ordinary iterator that stores nothing but the
+    /// key of the next run.
     ///
-    /// The first accumulator value is the `start` parameter.
-    /// Each iteration passes the accumulator value and the next value inside `Ok`
-    /// to the fold function `f` and its return value becomes the new accumulator value.
+    /// Iterator element type is `(K, usize)`.
     ///
-    /// For example the sequence *Ok(1), Ok(2), Ok(3)* will result in a
-    /// computation like this:
+    /// ```
+    /// use itertools::Itertools;
     ///
-    /// ```ignore
-    /// let mut accum = start;
-    /// accum = f(accum, 1);
-    /// accum = f(accum, 2);
-    /// accum = f(accum, 3);
+    /// let data = vec![1, 3, -2, -2, 1, 0, 1, 2];
+    /// let runs = data.into_iter().run_lengths_by(|&x| x >= 0);
+    /// itertools::assert_equal(runs, vec![(true, 2), (false, 2), (true, 4)]);
     /// ```
+    fn run_lengths_by<F, K>(self, key: F) -> RunLengthsBy<Self, F, K>
+        where Self: Sized,
+              F: FnMut(&Self::Item) -> K,
+              K: PartialEq,
+    {
+        run_lengths::run_lengths_by(self, key)
+    }
+
+    /// Return an iterator adaptor that run-length encodes the iterator: it
+    /// yields the length of each run of consecutive equal elements, with the
+    /// first element of the run.
     ///
-    /// With a `start` value of 0 and an addition as folding function,
-    /// this effectively results in *((0 + 1) + 2) + 3*
+    /// The other elements of each run are dropped; see
+    /// [`.run_length_decode()`](#method.run_length_decode) for the inverse.
+    ///
+    /// Iterator element type is `(usize, Self::Item)`.
     ///
     /// ```
-    /// use std::ops::Add;
     /// use itertools::Itertools;
     ///
-    /// let values = [1, 2, -2, -1, 2, 1];
-    /// assert_eq!(
-    ///     values.iter()
-    ///           .map(Ok::<_, ()>)
-    ///           .fold_results(0, Add::add),
-    ///     Ok(3)
-    /// );
-    /// assert!(
-    ///     values.iter()
-    ///           .map(|&x| if x >= 0 { Ok(x) } else { Err("Negative number") })
-    ///           .fold_results(0, Add::add)
-    ///           .is_err()
-    /// );
+    /// let runs = "aaabccdd".chars().run_length_encode();
+    /// itertools::assert_equal(runs, vec![(3, 'a'), (1, 'b'), (2, 'c'), (2, 'd')]);
     /// ```
-    fn fold_results<A, E, B, F>(&mut self, mut start: B, mut f: F) -> Result<B, E>
-        where Self: Iterator<Item = Result<A, E>>,
-              F: FnMut(B, A) -> B
+    fn run_length_encode(self) -> RunLengthEncode<Self>
+        where Self: Sized,
+              Self::Item: PartialEq,
     {
-        for elt in self {
-            match elt {
-                Ok(v) => start = f(start, v),
-                Err(u) => return Err(u),
-            }
-        }
-        Ok(start)
+        run_lengths::run_length_encode(self)
     }
 
-    /// Fold `Option` values from an iterator.
-    ///
-    /// Only `Some` values are folded. If no `None` is encountered, the folded
-    /// value is returned inside `Some`. Otherwise, the operation terminates
-    /// and returns `None`. No iterator elements are consumed after the `None`.
+    /// Return an iterator adaptor that run-length encodes the iterator,
+    /// using `same_run(first, elt)` to tell whether `elt` belongs to the
+    /// run started by `first`.
     ///
-    /// This is the `Option` equivalent to `fold_results`.
+    /// Iterator element type is `(usize, Self::Item)`.
     ///
     /// ```
-    /// use std::ops::Add;
     /// use itertools::Itertools;
     ///
-    /// let mut values = vec![Some(1), Some(2), Some(-2)].into_iter();
-    /// assert_eq!(values.fold_options(5, Add::add), Some(5 + 1 + 2 - 2));
-    ///
-    /// let mut more_values = vec![Some(2), None, Some(0)].into_iter();
-    /// assert!(more_values.fold_options(0, Add::add).is_none());
-    /// assert_eq!(more_values.next().unwrap(), Some(0));
+    /// let data = vec![1.0, 1.05, 0.98, 2.0, 2.1];
+    /// let runs = data.into_iter().run_length_encode_by(|a: &f64, b: &f64| (a - b).abs() < 0.5);
+    /// itertools::assert_equal(runs, vec![(3, 1.0), (2, 2.0)]);
     /// ```
-    fn fold_options<A, B, F>(&mut self, mut start: B, mut f: F) -> Option<B>
-        where Self: Iterator<Item = Option<A>>,
-              F: FnMut(B, A) -> B
+    fn run_length_encode_by<F>(self, same_run: F) -> RunLengthEncodeBy<Self, F>
+        where Self: Sized,
+              F: FnMut(&Self::Item, &Self::Item) -> bool,
     {
-        for elt in self {
-            match elt {
-                Some(v) => start = f(start, v),
-                None => return None,
-            }
-        }
-        Some(start)
+        run_lengths::run_length_encode_by(self, same_run)
     }
 
-    /// Accumulator of the elements in the iterator.
+    /// Return an iterator adaptor that run-length encodes the iterator,
+    /// grouping consecutive elements for which `key` returns equal values.
     ///
-    /// Like `.fold()`, without a base case. If the iterator is
-    /// empty, return `None`. With just one element, return it.
-    /// Otherwise elements are accumulated in sequence using the closure `f`.
+    /// Iterator element type is `(usize, Self::Item)`.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// assert_eq!((0..10).fold1(|x, y| x + y).unwrap_or(0), 45);
-    /// assert_eq!((0..0).fold1(|x, y| x * y), None);
+    /// let words = vec!["ant", "ape", "bee", "cat", "cow"];
+    /// let runs = words.into_iter().run_length_encode_by_key(|w| w.chars().next());
+    /// itertools::assert_equal(runs, vec![(2, "ant"), (1, "bee"), (2, "cat")]);
     /// ```
-    fn fold1<F>(mut self, f: F) -> Option<Self::Item>
-        where F: FnMut(Self::Item, Self::Item) -> Self::Item,
-              Self: Sized,
+    fn run_length_encode_by_key<F, K>(self, key: F) -> RunLengthEncodeByKey<Self, F, K>
+        where Self: Sized,
+              F: FnMut(&Self::Item) -> K,
+              K: PartialEq,
     {
-        self.next().map(move |x| self.fold(x, f))
+        run_lengths::run_length_encode_by_key(self, key)
     }
 
-    /// Accumulate the elements in the iterator in a tree-like manner.
+    /// Return an iterator adaptor that run-length decodes the iterator: it
+    /// expands each pair `(n, elt)` to `n` copies of `elt`, lazily.
     ///
-    /// You can think of it as, while there's more than one item, repeatedly
-    /// combining adjacent items.  It does so in bottom-up-merge-sort order,
-    /// however, so that it needs only logarithmic stack space.
+    /// Each element is cloned `n - 1` times, the last copy being the
+    /// element itself. Pairs with a count of zero yield nothing.
     ///
-    /// This produces a call tree like the following (where the calls under
-    /// an item are done after reading that item):
+    /// Iterator element type is `T`.
     ///
-    /// ```text
-    /// 1 2 3 4 5 6 7
-    /// │ │ │ │ │ │ │
-    /// └─f └─f └─f │
-    ///   │   │   │ │
-    ///   └───f   └─f
-    ///       │     │
-    ///       └─────f
     /// ```
+    /// use itertools::Itertools;
     ///
-    /// Which, for non-associative functions, will typically produce a different
-    /// result than the linear call tree used by `fold1`:
+    /// let runs = vec![(3, 'a'), (0, 'z'), (1, 'b'), (2, 'c')];
+    /// let text: String = runs.into_iter().run_length_decode().collect();
+    /// assert_eq!(text, "aaabcc");
     ///
-    /// ```text
-    /// 1 2 3 4 5 6 7
-    /// │ │ │ │ │ │ │
-    /// └─f─f─f─f─f─f
+    /// // round trip
+    /// let data = vec![1, 1, 2, 3, 3, 3];
+    /// let decoded = data.iter().run_length_encode().run_length_decode();
+    /// itertools::assert_equal(decoded, &data);
     /// ```
+    fn run_length_decode<T>(self) -> RunLengthDecode<Self, T>
+        where Self: Sized + Iterator<Item = (usize, T)>,
+              T: Clone,
+    {
+        run_lengths::run_length_decode(self)
+    }
+
+    /// Return an iterator adaptor that yields the indices of all elements
+    /// satisfying a predicate, counted from the start of the iterator.
     ///
-    /// If `f` is associative, prefer the normal `fold1` instead.
+    /// Equivalent to `iter.enumerate().filter(|(_, v)| predicate(v)).map(|(i, _)| i)`.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// // The same tree as above
-    /// let num_strings = (1..8).map(|x| x.to_string());
-    /// assert_eq!(num_strings.tree_fold1(|x, y| format!("f({}, {})", x, y)),
-    ///     Some(String::from("f(f(f(1, 2), f(3, 4)), f(f(5, 6), 7))")));
-    ///
-    /// // Like fold1, an empty iterator produces None
-    /// assert_eq!((0..0).tree_fold1(|x, y| x * y), None);
+    /// let data = vec![1, 2, 3, 3, 4, 6, 7, 9];
+    /// itertools::assert_equal(data.iter().positions(|v| v % 2 == 0), vec![1, 4, 5]);
     ///
-    /// // tree_fold1 matches fold1 for associative operations...
-    /// assert_eq!((0..10).tree_fold1(|x, y| x + y),
-    ///     (0..10).fold1(|x, y| x + y));
-    /// // ...but not for non-associative ones
-    /// assert_ne!((0..10).tree_fold1(|x, y| x - y),
-    ///     (0..10).fold1(|x, y| x - y));
+    /// itertools::assert_equal(data.iter().positions(|v| v % 2 == 1).rev(), vec![7, 6, 3, 2, 0]);
     /// ```
-    fn tree_fold1<F>(mut self, mut f: F) -> Option<Self::Item>
-        where F: FnMut(Self::Item, Self::Item) -> Self::Item,
-              Self: Sized,
+    fn positions<P>(self, predicate: P) -> Positions<Self, P>
+        where Self: Sized,
+              P: FnMut(Self::Item) -> bool,
     {
-        type State<T> = Result<T, Option<T>>;
+        adaptors::positions(self, predicate)
+    }
 
-        fn inner0<T, II, FF>(it: &mut II, f: &mut FF) -> State<T>
-            where
-                II: Iterator<Item = T>,
-                FF: FnMut(T, T) -> T
-        {
-            // This function could be replaced with `it.next().ok_or(None)`,
-            // but half the useful tree_fold1 work is combining adjacent items,
-            // so put that in a form that LLVM is more likely to optimize well.
+    /// Return an iterator adaptor that applies a mutating function
+    /// to each element before yielding it.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let input = vec![vec![1], vec![3, 2, 1]];
+    /// let it = input.into_iter().update(|mut v| v.push(0));
+    /// itertools::assert_equal(it, vec![vec![1, 0], vec![3, 2, 1, 0]]);
+    /// ```
+    fn update<F>(self, updater: F) -> Update<Self, F>
+        where Self: Sized,
+              F: FnMut(&mut Self::Item),
+    {
+        adaptors::update(self, updater)
+    }
 
-            let a =
-                if let Some(v) = it.next() { v }
-                else { return Err(None) };
-            let b =
-                if let Some(v) = it.next() { v }
-                else { return Err(Some(a)) };
+    // non-adaptor methods
+    /// Advances the iterator and returns the next items grouped in a tuple of
+    /// a specific size (up to 4).
+    ///
+    /// If there are enough elements to be grouped in a tuple, then the tuple is
+    /// returned inside `Some`, otherwise `None` is returned.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut iter = 1..5;
+    ///
+    /// assert_eq!(Some((1, 2)), iter.next_tuple());
+    /// ```
+    fn next_tuple<T>(&mut self) -> Option<T>
+        where Self: Sized + Iterator<Item = T::Item>,
+              T: tuple_impl::TupleCollect
+    {
+        T::collect_from_iter_no_buf(self)
+    }
+
+    /// Collects all items from the iterator into a tuple of a specific size
+    /// (up to 4).
+    ///
+    /// If the number of elements inside the iterator is **exactly** equal to
+    /// the tuple size, then the tuple is returned inside `Some`, otherwise
+    /// `None` is returned.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let iter = 1..3;
+    ///
+    /// if let Some((x, y)) = iter.collect_tuple() {
+    ///     assert_eq!((x, y), (1, 2))
+    /// } else {
+    ///     panic!("Expected two elements")
+    /// }
+    /// ```
+    fn collect_tuple<T>(mut self) -> Option<T>
+        where Self: Sized + Iterator<Item = T::Item>,
+              T: tuple_impl::TupleCollect
+    {
+        match self.next_tuple() {
+            elt @ Some(_) => match self.next() {
+                Some(_) => None,
+                None => elt,
+            },
+            _ => None
+        }
+    }
+
+
+    /// Find the position and value of the first element satisfying a predicate.
+    ///
+    /// The iterator is not advanced past the first element found.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let text = "Hα";
+    /// assert_eq!(text.chars().find_position(|ch| ch.is_lowercase()), Some((1, 'α')));
+    /// ```
+    fn find_position<P>(&mut self, mut pred: P) -> Option<(usize, Self::Item)>
+        where P: FnMut(&Self::Item) -> bool
+    {
+        let mut index = 0usize;
+        for elt in self {
+            if pred(&elt) {
+                return Some((index, elt));
+            }
+            index += 1;
+        }
+        None
+    }
+
+    /// Find the position where `pattern` first occurs as a contiguous
+    /// subsequence of the iterator.
+    ///
+    /// Return the index of the first element of the match, or `None` if
+    /// the pattern does not occur. An empty pattern matches at `0`.
+    ///
+    /// The pattern is collected, but the iterator is searched in a single
+    /// pass without buffering any of its elements. The iterator is not
+    /// advanced past the end of the first match.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut bytes = b"GET /a HTTP/1.1\r\n\r\nbody".iter();
+    /// assert_eq!(bytes.find_subsequence(b"\r\n\r\n"), Some(15));
+    /// assert_eq!(bytes.as_slice(), b"body");
+    ///
+    /// assert_eq!((0..10).find_subsequence(vec![3, 5]), None);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn find_subsequence<J>(&mut self, pattern: J) -> Option<usize>
+        where J: IntoIterator,
+              Self::Item: PartialEq<J::Item>,
+              J::Item: PartialEq,
+    {
+        subsequence_impl::find_subsequence(self, pattern)
+    }
+
+    /// Check whether `pattern` occurs as a contiguous subsequence of the
+    /// iterator.
+    ///
+    /// See [`.find_subsequence()`](#method.find_subsequence) for more information.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let tokens = "let x = 1 ; let y = x ;".split(' ');
+    /// assert!(tokens.clone().contains_subsequence(vec!["y", "=", "x"]));
+    /// assert!(!tokens.clone().contains_subsequence(vec!["x", "=", "y"]));
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn contains_subsequence<J>(&mut self, pattern: J) -> bool
+        where J: IntoIterator,
+              Self::Item: PartialEq<J::Item>,
+              J::Item: PartialEq,
+    {
+        self.find_subsequence(pattern).is_some()
+    }
+
+    /// Check whether all elements compare equal.
+    ///
+    /// Empty iterators are considered to have equal elements:
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![1, 1, 1, 2, 2, 3, 3, 3, 4, 5, 5];
+    /// assert!(!data.iter().all_equal());
+    /// assert!(data[0..3].iter().all_equal());
+    /// assert!(data[3..5].iter().all_equal());
+    /// assert!(data[5..8].iter().all_equal());
+    ///
+    /// let data : Option<usize> = None;
+    /// assert!(data.into_iter().all_equal());
+    /// ```
+    fn all_equal(&mut self) -> bool
+        where Self: Sized,
+              Self::Item: PartialEq,
+    {
+        match self.next() {
+            None => true,
+            Some(a) => self.all(|x| a == x),
+        }
+    }
+
+    /// Consume the first `n` elements from the iterator eagerly,
+    /// and return the same iterator again.
+    ///
+    /// It works similarly to *.skip(* `n` *)* except it is eager and
+    /// preserves the iterator type.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut iter = "αβγ".chars().dropping(2);
+    /// itertools::assert_equal(iter, "γ".chars());
+    /// ```
+    ///
+    /// *Fusing notes: if the iterator is exhausted by dropping,
+    /// the result of calling `.next()` again depends on the iterator implementation.*
+    fn dropping(mut self, n: usize) -> Self
+        where Self: Sized
+    {
+        if n > 0 {
+            self.nth(n - 1);
+        }
+        self
+    }
+
+    /// Consume the last `n` elements from the iterator eagerly,
+    /// and return the same iterator again.
+    ///
+    /// This is only possible on double ended iterators. `n` may be
+    /// larger than the number of elements.
+    ///
+    /// Note: This method is eager, dropping the back elements immediately and
+    /// preserves the iterator type.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let init = vec![0, 3, 6, 9].into_iter().dropping_back(1);
+    /// itertools::assert_equal(init, vec![0, 3, 6]);
+    /// ```
+    fn dropping_back(mut self, n: usize) -> Self
+        where Self: Sized,
+              Self: DoubleEndedIterator
+    {
+        if n > 0 {
+            (&mut self).rev().nth(n - 1);
+        }
+        self
+    }
+
+    /// Run the closure `f` eagerly on each element of the iterator.
+    ///
+    /// Consumes the iterator until its end.
+    ///
+    /// ```
+    /// use std::sync::mpsc::channel;
+    /// use itertools::Itertools;
+    ///
+    /// let (tx, rx) = channel();
+    ///
+    /// // use .foreach() to apply a function to each value -- sending it
+    /// (0..5).map(|x| x * 2 + 1).foreach(|x| { tx.send(x).unwrap(); } );
+    ///
+    /// drop(tx);
+    ///
+    /// itertools::assert_equal(rx.iter(), vec![1, 3, 5, 7, 9]);
+    /// ```
+    #[deprecated(note="Use .for_each() instead", since="0.8")]
+    fn foreach<F>(self, f: F)
+        where F: FnMut(Self::Item),
+              Self: Sized,
+    {
+        self.for_each(f)
+    }
+
+    /// Combine all an iterator's elements into one element by using `Extend`.
+    ///
+    /// This combinator will extend the first item with each of the rest of the
+    /// items of the iterator. If the iterator is empty, the default value of
+    /// `I::Item` is returned.
+    ///
+    /// ```rust
+    /// use itertools::Itertools;
+    ///
+    /// let input = vec![vec![1], vec![2, 3], vec![4, 5, 6]];
+    /// assert_eq!(input.into_iter().concat(),
+    ///            vec![1, 2, 3, 4, 5, 6]);
+    /// ```
+    fn concat(self) -> Self::Item
+        where Self: Sized,
+              Self::Item: Extend<<<Self as Iterator>::Item as IntoIterator>::Item> + IntoIterator + Default
+    {
+        concat(self)
+    }
+
+    /// `.collect_vec()` is simply a type specialization of `.collect()`,
+    /// for convenience.
+    #[cfg(feature = "use_std")]
+    fn collect_vec(self) -> Vec<Self::Item>
+        where Self: Sized
+    {
+        self.collect()
+    }
+
+    /// Assign to each reference in `self` from the `from` iterator,
+    /// stopping at the shortest of the two iterators.
+    ///
+    /// The `from` iterator is queried for its next element before the `self`
+    /// iterator, and if either is exhausted the method is done.
+    ///
+    /// Return the number of elements written.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut xs = [0; 4];
+    /// xs.iter_mut().set_from(1..);
+    /// assert_eq!(xs, [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    fn set_from<'a, A: 'a, J>(&mut self, from: J) -> usize
+        where Self: Iterator<Item = &'a mut A>,
+              J: IntoIterator<Item = A>
+    {
+        let mut count = 0;
+        for elt in from {
+            match self.next() {
+                None => break,
+                Some(ptr) => *ptr = elt,
+            }
+            count += 1;
+        }
+        count
+    }
+
+    /// Combine all iterator elements into one String, separated by `sep`.
+    ///
+    /// Use the `Display` implementation of each element.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!(["a", "b", "c"].iter().join(", "), "a, b, c");
+    /// assert_eq!([1, 2, 3].iter().join(", "), "1, 2, 3");
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn join(&mut self, sep: &str) -> String
+        where Self::Item: std::fmt::Display
+    {
+        match self.next() {
+            None => String::new(),
+            Some(first_elt) => {
+                // estimate lower bound of capacity needed
+                let (lower, _) = self.size_hint();
+                let mut result = String::with_capacity(sep.len() * lower);
+                write!(&mut result, "{}", first_elt).unwrap();
+                for elt in self {
+                    result.push_str(sep);
+                    write!(&mut result, "{}", elt).unwrap();
+                }
+                result
+            }
+        }
+    }
+
+    /// Combine all integer iterator elements into one String, separated by `sep`.
+    ///
+    /// This gives the same result as [`.join()`](#method.join), but writes
+    /// the digits with a stack buffer instead of going through `std::fmt`,
+    /// which makes joining long lists of numbers noticeably faster.
+    ///
+    /// Requires the crate feature `int_join`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!([1, 20, 300].iter().join_int(", "), "1, 20, 300");
+    /// assert_eq!((-2..2).join_int("|"), "-2|-1|0|1");
+    /// ```
+    #[cfg(feature = "int_join")]
+    fn join_int(&mut self, sep: &str) -> String
+        where Self::Item: JoinInt
+    {
+        join_int::join_int(self, sep)
+    }
+
+    /// Combine all iterator elements into one String, separated by `sep`.
+    ///
+    /// Use the `Debug` implementation of each element.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!([Some(1), None].iter().join_debug(", "), "Some(1), None");
+    /// assert_eq!(["a", "b"].iter().join_debug("-"), "\"a\"-\"b\"");
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn join_debug(&mut self, sep: &str) -> String
+        where Self::Item: std::fmt::Debug
+    {
+        match self.next() {
+            None => String::new(),
+            Some(first_elt) => {
+                // estimate lower bound of capacity needed
+                let (lower, _) = self.size_hint();
+                let mut result = String::with_capacity(sep.len() * lower);
+                write!(&mut result, "{:?}", first_elt).unwrap();
+                for elt in self {
+                    result.push_str(sep);
+                    write!(&mut result, "{:?}", elt).unwrap();
+                }
+                result
+            }
+        }
+    }
+
+    /// Format all iterator elements, separated by `sep`.
+    ///
+    /// All elements are formatted (any formatting trait)
+    /// with `sep` inserted between each element.
+    ///
+    /// Formatting flags (width, precision, alignment, fill, sign, ...) of the
+    /// outer format specifier are applied to **each element** separately; the
+    /// separator is written as is.
+    ///
+    /// **Panics** if the formatter helper is formatted more than once.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = [1.1, 2.71828, -3.];
+    /// assert_eq!(
+    ///     format!("{:.2}", data.iter().format(", ")),
+    ///            "1.10, 2.72, -3.00");
+    /// assert_eq!(
+    ///     format!("{:>6.1}", data.iter().format("|")),
+    ///            "   1.1|   2.7|  -3.0");
+    /// ```
+    fn format(self, sep: &str) -> Format<Self>
+        where Self: Sized,
+    {
+        format::new_format_default(self, sep)
+    }
+
+    /// Format all iterator elements using their `Debug` implementation,
+    /// separated by `sep`.
+    ///
+    /// The result implements `Display`, so that elements which only implement
+    /// `Debug` can be formatted with `{}` without allocating a `String` for
+    /// each of them. The flags of the outer format specifier are applied to
+    /// each element.
+    ///
+    /// **Panics** if the formatter helper is formatted more than once.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = [(1, 'a'), (2, 'b')];
+    /// assert_eq!(format!("[{}]", data.iter().format_debug(", ")),
+    ///            "[(1, 'a'), (2, 'b')]");
+    /// ```
+    fn format_debug(self, sep: &str) -> FormatDebug<Self>
+        where Self: Sized,
+    {
+        format::new_format_debug(self, sep)
+    }
+
+    /// Format at most `max_items` iterator elements, separated by `sep`.
+    ///
+    /// If the iterator has more elements than that, the output ends with an
+    /// ellipsis and the number of omitted elements. The remaining elements
+    /// are consumed (but not formatted) to count them.
+    ///
+    /// All elements are formatted (any formatting trait)
+    /// with `sep` inserted between each element.
+    ///
+    /// **Panics** if the formatter helper is formatted more than once.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!(format!("{}", (0..10).format_truncated(", ", 3)),
+    ///            "0, 1, 2, ... (7 more)");
+    /// assert_eq!(format!("{:?}", (0..3).format_truncated(", ", 3)),
+    ///            "0, 1, 2");
+    /// ```
+    fn format_truncated(self, sep: &str, max_items: usize) -> FormatTruncated<Self>
+        where Self: Sized,
+    {
+        format::new_format_truncated(self, sep, max_items)
+    }
+
+    /// Format all iterator elements, separated by `sep`.
+    ///
+    /// This is a customizable version of `.format()`.
+    ///
+    /// The supplied closure `format` is called once per iterator element,
+    /// with two arguments: the element and a callback that takes a
+    /// `&Display` value, i.e. any reference to type that implements `Display`.
+    ///
+    /// Using `&format_args!(...)` is the most versatile way to apply custom
+    /// element formatting. The callback can be called multiple times if needed.
+    ///
+    /// Like for `.format()`, the flags of the outer format specifier are passed
+    /// on to each value given to the callback. Note that a `format_args!(...)`
+    /// value carries its own format specifiers and ignores the outer flags.
+    ///
+    /// **Panics** if the formatter helper is formatted more than once.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = [1.1, 2.71828, -3.];
+    /// let data_formatter = data.iter().format_with(", ", |elt, f| f(&format_args!("{:.2}", elt)));
+    /// assert_eq!(format!("{}", data_formatter),
+    ///            "1.10, 2.72, -3.00");
+    ///
+    /// // .format_with() is recursively composable
+    /// let matrix = [[1., 2., 3.],
+    ///               [4., 5., 6.]];
+    /// let matrix_formatter = matrix.iter().format_with("\n", |row, f| {
+    ///                                 f(&row.iter().format_with(", ", |elt, g| g(&elt)))
+    ///                              });
+    /// assert_eq!(format!("{}", matrix_formatter),
+    ///            "1, 2, 3\n4, 5, 6");
+    ///
+    /// // the outer flags apply to each value passed to the callback
+    /// let padded = data.iter().format_with(" ", |elt, f| f(&elt));
+    /// assert_eq!(format!("{:*^7.1}", padded),
+    ///            "**1.1** **2.7** *-3.0**");
+    /// ```
+    fn format_with<F>(self, sep: &str, format: F) -> FormatWith<Self, F>
+        where Self: Sized,
+              F: FnMut(Self::Item, &mut FnMut(&fmt::Display) -> fmt::Result) -> fmt::Result,
+    {
+        format::new_format(self, sep, format)
+    }
+
+    /// Fold `Result` values from an iterator.
+    ///
+    /// Only `Ok` values are folded. If no error is encountered, the folded
+    /// value is returned inside `Ok`. Otherwise, the operation terminates
+    /// and returns the first `Err` value it encounters. No iterator elements are
+    /// consumed after the first error.
+    ///
+    /// The first accumulator value is the `start` parameter.
+    /// Each iteration passes the accumulator value and the next value inside `Ok`
+    /// to the fold function `f` and its return value becomes the new accumulator value.
+    ///
+    /// For example the sequence *Ok(1), Ok(2), Ok(3)* will result in a
+    /// computation like this:
+    ///
+    /// ```ignore
+    /// let mut accum = start;
+    /// accum = f(accum, 1);
+    /// accum = f(accum, 2);
+    /// accum = f(accum, 3);
+    /// ```
+    ///
+    /// With a `start` value of 0 and an addition as folding function,
+    /// this effectively results in *((0 + 1) + 2) + 3*
+    ///
+    /// ```
+    /// use std::ops::Add;
+    /// use itertools::Itertools;
+    ///
+    /// let values = [1, 2, -2, -1, 2, 1];
+    /// assert_eq!(
+    ///     values.iter()
+    ///           .map(Ok::<_, ()>)
+    ///           .fold_results(0, Add::add),
+    ///     Ok(3)
+    /// );
+    /// assert!(
+    ///     values.iter()
+    ///           .map(|&x| if x >= 0 { Ok(x) } else { Err("Negative number") })
+    ///           .fold_results(0, Add::add)
+    ///           .is_err()
+    /// );
+    /// ```
+    fn fold_results<A, E, B, F>(&mut self, mut start: B, mut f: F) -> Result<B, E>
+        where Self: Iterator<Item = Result<A, E>>,
+              F: FnMut(B, A) -> B
+    {
+        for elt in self {
+            match elt {
+                Ok(v) => start = f(start, v),
+                Err(u) => return Err(u),
+            }
+        }
+        Ok(start)
+    }
+
+    /// Fold `Option` values from an iterator.
+    ///
+    /// Only `Some` values are folded. If no `None` is encountered, the folded
+    /// value is returned inside `Some`. Otherwise, the operation terminates
+    /// and returns `None`. No iterator elements are consumed after the `None`.
+    ///
+    /// This is the `Option` equivalent to `fold_results`.
+    ///
+    /// ```
+    /// use std::ops::Add;
+    /// use itertools::Itertools;
+    ///
+    /// let mut values = vec![Some(1), Some(2), Some(-2)].into_iter();
+    /// assert_eq!(values.fold_options(5, Add::add), Some(5 + 1 + 2 - 2));
+    ///
+    /// let mut more_values = vec![Some(2), None, Some(0)].into_iter();
+    /// assert!(more_values.fold_options(0, Add::add).is_none());
+    /// assert_eq!(more_values.next().unwrap(), Some(0));
+    /// ```
+    fn fold_options<A, B, F>(&mut self, mut start: B, mut f: F) -> Option<B>
+        where Self: Iterator<Item = Option<A>>,
+              F: FnMut(B, A) -> B
+    {
+        for elt in self {
+            match elt {
+                Some(v) => start = f(start, v),
+                None => return None,
+            }
+        }
+        Some(start)
+    }
+
+    /// Accumulator of the elements in the iterator.
+    ///
+    /// Like `.fold()`, without a base case. If the iterator is
+    /// empty, return `None`. With just one element, return it.
+    /// Otherwise elements are accumulated in sequence using the closure `f`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!((0..10).fold1(|x, y| x + y).unwrap_or(0), 45);
+    /// assert_eq!((0..0).fold1(|x, y| x * y), None);
+    /// ```
+    fn fold1<F>(mut self, f: F) -> Option<Self::Item>
+        where F: FnMut(Self::Item, Self::Item) -> Self::Item,
+              Self: Sized,
+    {
+        self.next().map(move |x| self.fold(x, f))
+    }
+
+    /// Accumulator of the elements in the iterator, with a fallible closure.
+    ///
+    /// Like `.fold1()`, but `f` returns a `Result`: the first error stops the
+    /// accumulation and is returned. No iterator elements are consumed after
+    /// the error. If the iterator is empty, return `Ok(None)`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // merge touching intervals, failing on a gap
+    /// let merge = |a: (u32, u32), b: (u32, u32)| {
+    ///     if a.1 == b.0 { Ok((a.0, b.1)) } else { Err(a.1) }
+    /// };
+    /// assert_eq!(vec![(0, 2), (2, 5), (5, 6)].into_iter().try_fold1(merge), Ok(Some((0, 6))));
+    /// assert_eq!(vec![(0, 2), (3, 5)].into_iter().try_fold1(merge), Err(2));
+    /// assert_eq!(Vec::new().into_iter().try_fold1(merge), Ok(None));
+    /// ```
+    fn try_fold1<F, E>(&mut self, mut f: F) -> Result<Option<Self::Item>, E>
+        where F: FnMut(Self::Item, Self::Item) -> Result<Self::Item, E>,
+              Self: Sized,
+    {
+        let mut acc = match self.next() {
+            Some(x) => x,
+            None => return Ok(None),
+        };
+        for elt in self {
+            acc = f(acc, elt)?;
+        }
+        Ok(Some(acc))
+    }
+
+    /// Accumulate the elements in the iterator in a tree-like manner.
+    ///
+    /// You can think of it as, while there's more than one item, repeatedly
+    /// combining adjacent items.  It does so in bottom-up-merge-sort order,
+    /// however, so that it needs only logarithmic stack space.
+    ///
+    /// This produces a call tree like the following (where the calls under
+    /// an item are done after reading that item):
+    ///
+    /// ```text
+    /// 1 2 3 4 5 6 7
+    /// │ │ │ │ │ │ │
+    /// └─f └─f └─f │
+    ///   │   │   │ │
+    ///   └───f   └─f
+    ///       │     │
+    ///       └─────f
+    /// ```
+    ///
+    /// Which, for non-associative functions, will typically produce a different
+    /// result than the linear call tree used by `fold1`:
+    ///
+    /// ```text
+    /// 1 2 3 4 5 6 7
+    /// │ │ │ │ │ │ │
+    /// └─f─f─f─f─f─f
+    /// ```
+    ///
+    /// If `f` is associative, prefer the normal `fold1` instead.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // The same tree as above
+    /// let num_strings = (1..8).map(|x| x.to_string());
+    /// assert_eq!(num_strings.tree_fold1(|x, y| format!("f({}, {})", x, y)),
+    ///     Some(String::from("f(f(f(1, 2), f(3, 4)), f(f(5, 6), 7))")));
+    ///
+    /// // Like fold1, an empty iterator produces None
+    /// assert_eq!((0..0).tree_fold1(|x, y| x * y), None);
+    ///
+    /// // tree_fold1 matches fold1 for associative operations...
+    /// assert_eq!((0..10).tree_fold1(|x, y| x + y),
+    ///     (0..10).fold1(|x, y| x + y));
+    /// // ...but not for non-associative ones
+    /// assert_ne!((0..10).tree_fold1(|x, y| x - y),
+    ///     (0..10).fold1(|x, y| x - y));
+    /// ```
+    fn tree_fold1<F>(mut self, mut f: F) -> Option<Self::Item>
+        where F: FnMut(Self::Item, Self::Item) -> Self::Item,
+              Self: Sized,
+    {
+        type State<T> = Result<T, Option<T>>;
+
+        fn inner0<T, II, FF>(it: &mut II, f: &mut FF) -> State<T>
+            where
+                II: Iterator<Item = T>,
+                FF: FnMut(T, T) -> T
+        {
+            // This function could be replaced with `it.next().ok_or(None)`,
+            // but half the useful tree_fold1 work is combining adjacent items,
+            // so put that in a form that LLVM is more likely to optimize well.
+
+            let a =
+                if let Some(v) = it.next() { v }
+                else { return Err(None) };
+            let b =
+                if let Some(v) = it.next() { v }
+                else { return Err(Some(a)) };
             Ok(f(a, b))
         }
 
@@ -1826,6 +3954,70 @@ pub trait Itertools : Iterator {
         }
     }
 
+    /// Split the elements into chunks of `chunk_size`, fold each chunk with
+    /// `fold_chunk` in parallel, using `rayon`, and combine the results of
+    /// the chunks in order with `combine`.
+    ///
+    /// This is a simple map-reduce for a plain iterator, which isn't a
+    /// rayon parallel iterator: the chunks are read from the iterator on a
+    /// background thread, like [`.readahead()`](#method.readahead) does, so
+    /// that the next chunks are read while the previous ones are folded.
+    /// They are folded in parallel as many at a time as there are threads in
+    /// the current rayon thread pool. If the iterator is empty, return
+    /// `None`.
+    ///
+    /// `combine` is called on the calling thread with the results of
+    /// consecutive chunks, from the first to the last, so it doesn't need to
+    /// be commutative.
+    ///
+    /// **Panics** if `chunk_size` is zero.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let total = (1..1001u64).par_fold_chunks(100,
+    ///                                          |chunk| chunk.iter().sum::<u64>(),
+    ///                                          |a, b| a + b);
+    /// assert_eq!(total, Some(500500));
+    ///
+    /// let text = "the quick brown fox".split(' ').par_fold_chunks(
+    ///     2, |words| words.join("-"), |a, b| a + "-" + &b);
+    /// assert_eq!(text.unwrap(), "the-quick-brown-fox");
+    /// ```
+    #[cfg(all(feature = "use_std", feature = "rayon"))]
+    fn par_fold_chunks<B, F, C>(self, chunk_size: usize, fold_chunk: F, mut combine: C)
+        -> Option<B>
+        where Self: Sized + Send + 'static,
+              Self::Item: Send + 'static,
+              F: Fn(Vec<Self::Item>) -> B + Send + Sync,
+              C: FnMut(B, B) -> B,
+              B: Send,
+    {
+        use rayon::prelude::*;
+
+        assert!(chunk_size != 0, "par_fold_chunks: the chunk size must be non-zero");
+        let n_chunks = rayon::current_num_threads();
+        // read the next batch of chunks while the current one is folded
+        let mut chunks = self.batching(move |iter| {
+            let chunk: Vec<_> = iter.take(chunk_size).collect();
+            if chunk.is_empty() { None } else { Some(chunk) }
+        }).readahead(n_chunks);
+        let mut acc = None;
+        loop {
+            let batch: Vec<_> = chunks.by_ref().take(n_chunks).collect();
+            if batch.is_empty() {
+                return acc;
+            }
+            let results: Vec<B> = batch.into_par_iter().map(&fold_chunk).collect();
+            for result in results {
+                acc = Some(match acc {
+                    Some(acc) => combine(acc, result),
+                    None => result,
+                });
+            }
+        }
+    }
+
     /// An iterator method that applies a function, producing a single, final value.
     ///
     /// `fold_while()` is basically equivalent to `fold()` but with additional support for
@@ -1868,16 +4060,59 @@ pub trait Itertools : Iterator {
     #[deprecated(note="Use .try_fold() instead", since="0.8")]
     fn fold_while<B, F>(&mut self, init: B, mut f: F) -> FoldWhile<B>
         where Self: Sized,
-              F: FnMut(B, Self::Item) -> FoldWhile<B>
+              F: FnMut(B, Self::Item) -> FoldWhile<B>
+    {
+        let mut acc = init;
+        while let Some(item) = self.next() {
+            match f(acc, item) {
+                FoldWhile::Continue(res) => acc = res,
+                res @ FoldWhile::Done(_) => return res,
+            }
+        }
+        FoldWhile::Continue(acc)
+    }
+
+    /// Sum the values that `f` computes from each element.
+    ///
+    /// The closure receives each element by reference, so a field can be
+    /// read without moving or cloning the element.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// struct Order { item: String, price: u32 }
+    ///
+    /// let orders = vec![Order { item: "tea".into(), price: 3 },
+    ///                   Order { item: "cake".into(), price: 5 }];
+    /// let total: u32 = orders.iter().sum_by(|order| order.price);
+    /// assert_eq!(total, 8);
+    /// ```
+    fn sum_by<S, F>(self, mut f: F) -> S
+        where Self: Sized,
+              F: FnMut(&Self::Item) -> S,
+              S: ::std::iter::Sum<S>,
+    {
+        self.map(move |elt| f(&elt)).sum()
+    }
+
+    /// Multiply the values that `f` computes from each element.
+    ///
+    /// The closure receives each element by reference, so a field can be
+    /// read without moving or cloning the element.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let words = vec!["ab", "cde", "f"];
+    /// let product: usize = words.into_iter().product_by(|word| word.len());
+    /// assert_eq!(product, 6);
+    /// ```
+    fn product_by<P, F>(self, mut f: F) -> P
+        where Self: Sized,
+              F: FnMut(&Self::Item) -> P,
+              P: ::std::iter::Product<P>,
     {
-        let mut acc = init;
-        while let Some(item) = self.next() {
-            match f(acc, item) {
-                FoldWhile::Continue(res) => acc = res,
-                res @ FoldWhile::Done(_) => return res,
-            }
-        }
-        FoldWhile::Continue(acc)
+        self.map(move |elt| f(&elt)).product()
     }
 
     /// Sort all iterator elements into a new iterator in ascending order.
@@ -1954,86 +4189,478 @@ pub trait Itertools : Iterator {
     /// ```
     /// use itertools::Itertools;
     ///
-    /// // sort people in descending order by age
-    /// let people = vec![("Jane", 20), ("John", 18), ("Jill", 30), ("Jack", 27)];
-    ///
-    /// let oldest_people_first = people
-    ///     .into_iter()
-    ///     .sorted_by_key(|x| -x.1)
-    ///     .map(|(person, _age)| person);
+    /// // sort people in descending order by age
+    /// let people = vec![("Jane", 20), ("John", 18), ("Jill", 30), ("Jack", 27)];
+    ///
+    /// let oldest_people_first = people
+    ///     .into_iter()
+    ///     .sorted_by_key(|x| -x.1)
+    ///     .map(|(person, _age)| person);
+    ///
+    /// itertools::assert_equal(oldest_people_first,
+    ///                         vec!["Jill", "Jack", "Jane", "John"]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn sorted_by_key<K, F>(self, f: F) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              K: Ord,
+              F: FnMut(&Self::Item) -> K,
+    {
+        let mut v = Vec::from_iter(self);
+        v.sort_by_key(f);
+        v.into_iter()
+    }
+
+    /// Return the `k` smallest elements of the iterator, sorted in
+    /// ascending order, or all of them if there are fewer.
+    ///
+    /// Unlike `.sorted().take(k)`, the elements are selected in a bounded
+    /// binary heap: at most `k` elements are stored, and the running time is
+    /// *O(n log k)*. The order of equal elements is unspecified.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let numbers = vec![6, 9, 1, 14, 0, 4, 8, 7, 11, 2, 10, 3, 13, 12, 5];
+    ///
+    /// itertools::assert_equal(numbers.iter().k_smallest(5), vec![&0, &1, &2, &3, &4]);
+    /// itertools::assert_equal((0..3).k_smallest(5), vec![0, 1, 2]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn k_smallest(self, k: usize) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              Self::Item: Ord,
+    {
+        self.k_smallest_by(k, Ord::cmp)
+    }
+
+    /// Return the `k` smallest elements of the iterator with respect to the
+    /// comparison function `cmp`, sorted in ascending order.
+    ///
+    /// See [`.k_smallest()`](#method.k_smallest) for more information.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let words = vec!["kiwi", "apple", "fig", "banana", "pear"];
+    /// let shortest = words.into_iter().k_smallest_by(2, |a, b| a.len().cmp(&b.len()));
+    ///
+    /// itertools::assert_equal(shortest, vec!["fig", "kiwi"]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn k_smallest_by<F>(self, k: usize, cmp: F) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        k_smallest::k_smallest_by(self, k, cmp).into_iter()
+    }
+
+    /// Return the `k` elements of the iterator that give the smallest values
+    /// from the key function `f`, sorted by ascending key.
+    ///
+    /// See [`.k_smallest()`](#method.k_smallest) for more information.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let temperatures = vec![("mon", 12), ("tue", 7), ("wed", 15), ("thu", 9)];
+    /// let coldest = temperatures.into_iter().k_smallest_by_key(2, |&(_, t)| t);
+    ///
+    /// itertools::assert_equal(coldest, vec![("tue", 7), ("thu", 9)]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn k_smallest_by_key<K, F>(self, k: usize, mut f: F) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              K: Ord,
+              F: FnMut(&Self::Item) -> K,
+    {
+        self.k_smallest_by(k, |a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Return the `k` largest elements of the iterator, sorted in
+    /// descending order, or all of them if there are fewer.
+    ///
+    /// See [`.k_smallest()`](#method.k_smallest) for more information.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let numbers = vec![6, 9, 1, 14, 0, 4, 8, 7, 11, 2, 10, 3, 13, 12, 5];
+    ///
+    /// itertools::assert_equal(numbers.iter().k_largest(3), vec![&14, &13, &12]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn k_largest(self, k: usize) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              Self::Item: Ord,
+    {
+        self.k_largest_by(k, Ord::cmp)
+    }
+
+    /// Return the `k` largest elements of the iterator with respect to the
+    /// comparison function `cmp`, sorted in descending order.
+    ///
+    /// See [`.k_smallest()`](#method.k_smallest) for more information.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let words = vec!["kiwi", "apple", "fig", "banana", "pear"];
+    /// let longest = words.into_iter().k_largest_by(2, |a, b| a.len().cmp(&b.len()));
+    ///
+    /// itertools::assert_equal(longest, vec!["banana", "apple"]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn k_largest_by<F>(self, k: usize, mut cmp: F) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        self.k_smallest_by(k, |a, b| cmp(b, a))
+    }
+
+    /// Return the `k` elements of the iterator that give the largest values
+    /// from the key function `f`, sorted by descending key.
+    ///
+    /// See [`.k_smallest()`](#method.k_smallest) for more information.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let temperatures = vec![("mon", 12), ("tue", 7), ("wed", 15), ("thu", 9)];
+    /// let warmest = temperatures.into_iter().k_largest_by_key(2, |&(_, t)| t);
+    ///
+    /// itertools::assert_equal(warmest, vec![("wed", 15), ("mon", 12)]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn k_largest_by_key<K, F>(self, k: usize, mut f: F) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              K: Ord,
+              F: FnMut(&Self::Item) -> K,
+    {
+        self.k_smallest_by(k, |a, b| f(b).cmp(&f(a)))
+    }
+
+    /// Collect all iterator elements into one of two
+    /// partitions. Unlike `Iterator::partition`, each partition may
+    /// have a distinct type.
+    ///
+    /// ```
+    /// use itertools::{Itertools, Either};
+    ///
+    /// let successes_and_failures = vec![Ok(1), Err(false), Err(true), Ok(2)];
+    ///
+    /// let (successes, failures): (Vec<_>, Vec<_>) = successes_and_failures
+    ///     .into_iter()
+    ///     .partition_map(|r| {
+    ///         match r {
+    ///             Ok(v) => Either::Left(v),
+    ///             Err(v) => Either::Right(v),
+    ///         }
+    ///     });
+    ///
+    /// assert_eq!(successes, [1, 2]);
+    /// assert_eq!(failures, [false, true]);
+    /// ```
+    fn partition_map<A, B, F, L, R>(self, mut predicate: F) -> (A, B)
+        where Self: Sized,
+              F: FnMut(Self::Item) -> Either<L, R>,
+              A: Default + Extend<L>,
+              B: Default + Extend<R>,
+    {
+        let mut left = A::default();
+        let mut right = B::default();
+
+        self.for_each(|val| match predicate(val) {
+            Either::Left(v) => left.extend(Some(v)),
+            Either::Right(v) => right.extend(Some(v)),
+        });
+
+        (left, right)
+    }
+
+    /// Converts an iterator of tuples into a tuple of containers.
+    ///
+    /// It consumes an entire iterator of n-ary tuples, producing `n` collections, one for each
+    /// column, for tuples of up to 12 elements. Each collection only needs to implement
+    /// `Default` and `Extend`, like for [`.partition_map()`](#method.partition_map).
+    ///
+    /// This function is, in some sense, the opposite of [`multizip`](fn.multizip.html).
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let inputs = vec![(1, 2, 3), (4, 5, 6), (7, 8, 9)];
+    ///
+    /// let (a, b, c): (Vec<_>, Vec<_>, Vec<_>) = inputs
+    ///     .into_iter()
+    ///     .multiunzip();
+    ///
+    /// assert_eq!(a, vec![1, 4, 7]);
+    /// assert_eq!(b, vec![2, 5, 8]);
+    /// assert_eq!(c, vec![3, 6, 9]);
+    /// ```
+    fn multiunzip<FromI>(self) -> FromI
+        where Self: Sized + MultiUnzip<FromI>,
+    {
+        MultiUnzip::multiunzip(self)
+    }
+
+    /// Return a `HashMap` of keys mapped to `Vec`s of values. Keys and values
+    /// are taken from `(Key, Value)` tuple pairs yielded by the input iterator.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![(0, 10), (2, 12), (3, 13), (0, 20), (3, 33), (2, 42)];
+    /// let lookup = data.into_iter().into_group_map();
+    ///
+    /// assert_eq!(lookup[&0], vec![10, 20]);
+    /// assert_eq!(lookup.get(&1), None);
+    /// assert_eq!(lookup[&2], vec![12, 42]);
+    /// assert_eq!(lookup[&3], vec![13, 33]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn into_group_map<K, V>(self) -> HashMap<K, Vec<V>>
+        where Self: Iterator<Item=(K, V)> + Sized,
+              K: Hash + Eq,
+    {
+        group_map::into_group_map(self)
+    }
+
+    /// Return a `HashMap` of the distinct elements of the iterator, mapped
+    /// to the number of times each of them occurs.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let counts = "hello world".chars().counts();
+    ///
+    /// assert_eq!(counts[&'l'], 3);
+    /// assert_eq!(counts[&'o'], 2);
+    /// assert_eq!(counts[&'h'], 1);
+    /// assert_eq!(counts.get(&'z'), None);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn counts(self) -> HashMap<Self::Item, usize>
+        where Self: Sized,
+              Self::Item: Eq + Hash,
+    {
+        let mut counts = HashMap::new();
+        self.for_each(|item| *counts.entry(item).or_insert(0) += 1);
+        counts
+    }
+
+    /// Return a `HashMap` of the keys that `f` computes for the elements of
+    /// the iterator, mapped to the number of elements with each key.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let words = vec!["apple", "kiwi", "banana", "fig", "pear"];
+    /// let by_len = words.into_iter().counts_by(|word| word.len());
+    ///
+    /// assert_eq!(by_len[&4], 2);
+    /// assert_eq!(by_len[&3], 1);
+    /// assert_eq!(by_len.get(&2), None);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn counts_by<K, F>(self, f: F) -> HashMap<K, usize>
+        where Self: Sized,
+              K: Eq + Hash,
+              F: FnMut(Self::Item) -> K,
+    {
+        self.map(f).counts()
+    }
+
+    /// Return a `HashMap` of the elements indexed by the key that `key`
+    /// computes for each of them.
+    ///
+    /// Unlike `.into_group_map()`, a key is expected to be unique: if two
+    /// elements have the same key, the indexing stops and returns a
+    /// [`DuplicateKeyError`](struct.DuplicateKeyError.html) with the key and
+    /// both elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let users = vec![(1, "ann"), (2, "bob"), (3, "cid")];
+    /// let by_id = users.into_iter().index_by(|user| user.0).unwrap();
+    /// assert_eq!(by_id[&2], (2, "bob"));
+    ///
+    /// let corrupted = vec![(1, "ann"), (2, "bob"), (1, "abe")];
+    /// let err = corrupted.into_iter().index_by(|user| user.0).unwrap_err();
+    /// assert_eq!(err.key, 1);
+    /// assert_eq!(err.first, (1, "ann"));
+    /// assert_eq!(err.second, (1, "abe"));
+    /// assert_eq!(err.to_string(), "duplicate key: 1");
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn index_by<K, F>(self, key: F)
+        -> Result<HashMap<K, Self::Item>, DuplicateKeyError<K, Self::Item>>
+        where Self: Sized,
+              K: Hash + Eq,
+              F: FnMut(&Self::Item) -> K,
+    {
+        group_map::index_by(self, key)
+    }
+
+    /// Return a `HashMap` of keys mapped to values, taken from `(Key, Value)`
+    /// tuple pairs yielded by the input iterator, with an explicit `policy`
+    /// for the keys that appear more than once.
+    ///
+    /// The policy is [`KeepFirst`](struct.KeepFirst.html),
+    /// [`KeepLast`](struct.KeepLast.html) or a closure
+    /// `FnMut(&K, V, V) -> V` merging the value already in the map with the
+    /// new one: see [`LookupPolicy`](trait.LookupPolicy.html).
+    ///
+    /// ```
+    /// use itertools::{Itertools, KeepFirst, KeepLast};
+    ///
+    /// let data = vec![("a", 1), ("b", 2), ("a", 3)];
+    ///
+    /// let first = data.clone().into_iter().into_lookup(KeepFirst);
+    /// assert_eq!(first["a"], 1);
+    ///
+    /// let last = data.clone().into_iter().into_lookup(KeepLast);
+    /// assert_eq!(last["a"], 3);
+    ///
+    /// let sums = data.into_iter().into_lookup(|_: &&str, old, new| old + new);
+    /// assert_eq!(sums["a"], 4);
+    /// assert_eq!(sums["b"], 2);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn into_lookup<K, V, P>(self, policy: P) -> HashMap<K, V>
+        where Self: Iterator<Item=(K, V)> + Sized,
+              K: Hash + Eq,
+              P: LookupPolicy<K, V>,
+    {
+        group_map::into_lookup(self, policy)
+    }
+
+    /// Constructs a `GroupingMap` to be used later with one of the efficient
+    /// group-and-fold operations it allows to perform.
+    ///
+    /// The input iterator must yield item in the form of `(K, V)` where the
+    /// value of type `K` will be used as key to identify the groups and the
+    /// value of type `V` as value for the folding operation.
+    ///
+    /// See [`GroupingMap`](./structs/struct.GroupingMap.html) for more informations
+    /// on what operations are available.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![(0, 10), (2, 12), (3, 13), (0, 20), (3, 33), (2, 42)];
+    /// let lookup = data.into_iter()
+    ///     .into_grouping_map()
+    ///     .fold(0, |acc, _key, val| acc + val);
+    ///
+    /// assert_eq!(lookup[&0], 30);
+    /// assert_eq!(lookup.get(&1), None);
+    /// assert_eq!(lookup[&2], 54);
+    /// assert_eq!(lookup[&3], 46);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn into_grouping_map<K, V>(self) -> GroupingMap<Self>
+        where Self: Iterator<Item=(K, V)> + Sized,
+    {
+        grouping_map::new(self)
+    }
+
+    /// Constructs a `GroupingRuns` to be used later with one of the lazy
+    /// group-and-fold operations it allows to perform, on a source whose
+    /// elements are sorted, or at least clustered, by key.
+    ///
+    /// The input iterator must yield item in the form of `(K, V)`, like for
+    /// [`.into_grouping_map()`](#method.into_grouping_map). But instead of
+    /// storing every group in a `HashMap`, the result of each run of equal
+    /// keys is yielded as soon as the run ends, in constant memory.
+    ///
+    /// See [`GroupingRuns`](./structs/struct.GroupingRuns.html) for more informations
+    /// on what operations are available.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![(0, 10), (0, 20), (2, 12), (3, 13), (3, 33)];
+    /// let sums = data.into_iter()
+    ///     .into_grouping_runs()
+    ///     .fold_with(|_key, _val| 0, |acc, _key, val| acc + val);
     ///
-    /// itertools::assert_equal(oldest_people_first,
-    ///                         vec!["Jill", "Jack", "Jane", "John"]);
+    /// itertools::assert_equal(sums, vec![(0, 30), (2, 12), (3, 46)]);
     /// ```
-    #[cfg(feature = "use_std")]
-    fn sorted_by_key<K, F>(self, f: F) -> VecIntoIter<Self::Item>
-        where Self: Sized,
-              K: Ord,
-              F: FnMut(&Self::Item) -> K,
+    fn into_grouping_runs<K, V>(self) -> GroupingRuns<Self>
+        where Self: Iterator<Item=(K, V)> + Sized,
+              K: PartialEq,
     {
-        let mut v = Vec::from_iter(self);
-        v.sort_by_key(f);
-        v.into_iter()
+        grouping_runs::new(self)
     }
 
-    /// Collect all iterator elements into one of two
-    /// partitions. Unlike `Iterator::partition`, each partition may
-    /// have a distinct type.
+    /// Constructs a `GroupingMap` like
+    /// [`.into_grouping_map()`](#method.into_grouping_map), whose resulting
+    /// `HashMap`s use `hasher` instead of the default `RandomState`.
+    ///
+    /// A faster, non-cryptographic hasher can make a big difference when
+    /// grouping many small keys.
     ///
     /// ```
-    /// use itertools::{Itertools, Either};
+    /// use itertools::Itertools;
+    /// use std::collections::HashMap;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
     ///
-    /// let successes_and_failures = vec![Ok(1), Err(false), Err(true), Ok(2)];
+    /// type Hasher = BuildHasherDefault<DefaultHasher>;
     ///
-    /// let (successes, failures): (Vec<_>, Vec<_>) = successes_and_failures
-    ///     .into_iter()
-    ///     .partition_map(|r| {
-    ///         match r {
-    ///             Ok(v) => Either::Left(v),
-    ///             Err(v) => Either::Right(v),
-    ///         }
-    ///     });
+    /// let data = vec![(0, 10), (2, 12), (3, 13), (0, 20), (3, 33), (2, 42)];
+    /// let lookup: HashMap<_, _, Hasher> = data.into_iter()
+    ///     .into_grouping_map_with_hasher(Hasher::default())
+    ///     .fold(0, |acc, _key, val| acc + val);
     ///
-    /// assert_eq!(successes, [1, 2]);
-    /// assert_eq!(failures, [false, true]);
+    /// assert_eq!(lookup[&0], 30);
+    /// assert_eq!(lookup[&2], 54);
+    /// assert_eq!(lookup[&3], 46);
     /// ```
-    fn partition_map<A, B, F, L, R>(self, mut predicate: F) -> (A, B)
-        where Self: Sized,
-              F: FnMut(Self::Item) -> Either<L, R>,
-              A: Default + Extend<L>,
-              B: Default + Extend<R>,
+    #[cfg(feature = "use_std")]
+    fn into_grouping_map_with_hasher<K, V, S>(self, hasher: S) -> GroupingMap<Self, S>
+        where Self: Iterator<Item=(K, V)> + Sized,
+              S: BuildHasher,
     {
-        let mut left = A::default();
-        let mut right = B::default();
-
-        self.for_each(|val| match predicate(val) {
-            Either::Left(v) => left.extend(Some(v)),
-            Either::Right(v) => right.extend(Some(v)),
-        });
-
-        (left, right)
+        grouping_map::new_with_hasher(self, hasher)
     }
 
-    /// Return a `HashMap` of keys mapped to `Vec`s of values. Keys and values
-    /// are taken from `(Key, Value)` tuple pairs yielded by the input iterator.
+    /// Constructs a `GroupingMap` to be used later with one of the efficient
+    /// group-and-fold operations it allows to perform.
+    ///
+    /// The values from this iterator will be used as values for the folding operation
+    /// while the keys will be obtained from the values by calling `key_mapper`.
+    ///
+    /// See [`GroupingMap`](./structs/struct.GroupingMap.html) for more informations
+    /// on what operations are available.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let data = vec![(0, 10), (2, 12), (3, 13), (0, 20), (3, 33), (2, 42)];
-    /// let lookup = data.into_iter().into_group_map();
+    /// #[derive(Debug)]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
     ///
-    /// assert_eq!(lookup[&0], vec![10, 20]);
+    /// let data = vec![Point { x: 0, y: 10 }, Point { x: 2, y: 12 }, Point { x: 0, y: 20 }];
+    /// let lookup = data.into_iter()
+    ///     .into_grouping_map_by(|p| p.x)
+    ///     .fold(0, |acc, _key, p| acc + p.y);
+    ///
+    /// assert_eq!(lookup[&0], 30);
     /// assert_eq!(lookup.get(&1), None);
-    /// assert_eq!(lookup[&2], vec![12, 42]);
-    /// assert_eq!(lookup[&3], vec![13, 33]);
+    /// assert_eq!(lookup[&2], 12);
     /// ```
     #[cfg(feature = "use_std")]
-    fn into_group_map<K, V>(self) -> HashMap<K, Vec<V>>
-        where Self: Iterator<Item=(K, V)> + Sized,
-              K: Hash + Eq,
+    fn into_grouping_map_by<K, V, F>(self, key_mapper: F) -> GroupingMapBy<Self, F>
+        where Self: Iterator<Item=V> + Sized,
+              F: FnMut(&V) -> K,
     {
-        group_map::into_group_map(self)
+        grouping_map::new(grouping_map::new_map_for_grouping(self, key_mapper))
     }
 
     /// Return the minimum and maximum elements in the iterator.
@@ -2112,6 +4739,337 @@ pub trait Itertools : Iterator {
         )
     }
 
+    /// Return the element that gives the minimum value from the `key`
+    /// function, together with that value, or `None` if the iterator is
+    /// empty.
+    ///
+    /// The key is computed once per element, and the minimal one is handed
+    /// back rather than having to be computed again by the caller.
+    ///
+    /// If several elements are equally minimum, the first one is returned,
+    /// like `Iterator::min_by_key()`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let words = ["apple", "fig", "banana", "kiwi"];
+    /// assert_eq!(words.iter().min_by_key_with(|w| w.len()), Some((3, &"fig")));
+    /// assert_eq!(words[..0].iter().min_by_key_with(|w| w.len()), None);
+    /// ```
+    fn min_by_key_with<K, F>(mut self, mut key: F) -> Option<(K, Self::Item)>
+        where Self: Sized, K: Ord, F: FnMut(&Self::Item) -> K
+    {
+        let first = self.next()?;
+        let first_key = key(&first);
+        Some(self.fold((first_key, first), |(min_key, min), elt| {
+            let elt_key = key(&elt);
+            if elt_key < min_key {
+                (elt_key, elt)
+            } else {
+                (min_key, min)
+            }
+        }))
+    }
+
+    /// Return the element that gives the maximum value from the `key`
+    /// function, together with that value, or `None` if the iterator is
+    /// empty.
+    ///
+    /// The key is computed once per element, and the maximal one is handed
+    /// back rather than having to be computed again by the caller.
+    ///
+    /// If several elements are equally maximum, the last one is returned,
+    /// like `Iterator::max_by_key()`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let words = ["apple", "fig", "banana", "cherry"];
+    /// assert_eq!(words.iter().max_by_key_with(|w| w.len()), Some((6, &"cherry")));
+    /// assert_eq!(words[..0].iter().max_by_key_with(|w| w.len()), None);
+    /// ```
+    fn max_by_key_with<K, F>(mut self, mut key: F) -> Option<(K, Self::Item)>
+        where Self: Sized, K: Ord, F: FnMut(&Self::Item) -> K
+    {
+        let first = self.next()?;
+        let first_key = key(&first);
+        Some(self.fold((first_key, first), |(max_key, max), elt| {
+            let elt_key = key(&elt);
+            if elt_key < max_key {
+                (max_key, max)
+            } else {
+                (elt_key, elt)
+            }
+        }))
+    }
+
+    /// Return the position of the maximum element in the iterator.
+    ///
+    /// If several elements are equally maximum, the position of the
+    /// last of them is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a: [i32; 0] = [];
+    /// assert_eq!(a.iter().position_max(), None);
+    ///
+    /// let a = [-3, 0, 1, 5, -10];
+    /// assert_eq!(a.iter().position_max(), Some(3));
+    ///
+    /// let a = [1, 1, -1, -1];
+    /// assert_eq!(a.iter().position_max(), Some(1));
+    /// ```
+    fn position_max(self) -> Option<usize>
+        where Self: Sized, Self::Item: Ord
+    {
+        self.enumerate()
+            .max_by(|x, y| Ord::cmp(&x.1, &y.1))
+            .map(|x| x.0)
+    }
+
+    /// Return the position of the maximum element in the iterator, as
+    /// determined by the specified function.
+    ///
+    /// If several elements are equally maximum, the position of the
+    /// last of them is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = [-3_i32, 0, 1, 5, -10];
+    /// assert_eq!(a.iter().position_max_by_key(|x| x.abs()), Some(4));
+    ///
+    /// let a = [1_i32, 1, -1, -1];
+    /// assert_eq!(a.iter().position_max_by_key(|x| x.abs()), Some(3));
+    /// ```
+    fn position_max_by_key<K, F>(self, mut key: F) -> Option<usize>
+        where Self: Sized, K: Ord, F: FnMut(&Self::Item) -> K
+    {
+        self.enumerate()
+            .max_by(|x, y| Ord::cmp(&key(&x.1), &key(&y.1)))
+            .map(|x| x.0)
+    }
+
+    /// Return the position of the maximum element in the iterator, as
+    /// determined by the specified comparison function.
+    ///
+    /// If several elements are equally maximum, the position of the
+    /// last of them is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = [-3_i32, 0, 1, 5, -10];
+    /// assert_eq!(a.iter().position_max_by(|x, y| x.cmp(y)), Some(3));
+    ///
+    /// let a = [1_i32, 1, -1, -1];
+    /// assert_eq!(a.iter().position_max_by(|x, y| x.cmp(y)), Some(1));
+    /// ```
+    fn position_max_by<F>(self, mut compare: F) -> Option<usize>
+        where Self: Sized, F: FnMut(&Self::Item, &Self::Item) -> Ordering
+    {
+        self.enumerate()
+            .max_by(|x, y| compare(&x.1, &y.1))
+            .map(|x| x.0)
+    }
+
+    /// Return the position of the minimum element in the iterator.
+    ///
+    /// If several elements are equally minimum, the position of the
+    /// first of them is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a: [i32; 0] = [];
+    /// assert_eq!(a.iter().position_min(), None);
+    ///
+    /// let a = [-3, 0, 1, 5, -10];
+    /// assert_eq!(a.iter().position_min(), Some(4));
+    ///
+    /// let a = [1, 1, -1, -1];
+    /// assert_eq!(a.iter().position_min(), Some(2));
+    /// ```
+    fn position_min(self) -> Option<usize>
+        where Self: Sized, Self::Item: Ord
+    {
+        self.enumerate()
+            .min_by(|x, y| Ord::cmp(&x.1, &y.1))
+            .map(|x| x.0)
+    }
+
+    /// Return the position of the minimum element in the iterator, as
+    /// determined by the specified function.
+    ///
+    /// If several elements are equally minimum, the position of the
+    /// first of them is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = [-3_i32, 0, 1, 5, -10];
+    /// assert_eq!(a.iter().position_min_by_key(|x| x.abs()), Some(1));
+    ///
+    /// let a = [1_i32, 1, -1, -1];
+    /// assert_eq!(a.iter().position_min_by_key(|x| x.abs()), Some(0));
+    /// ```
+    fn position_min_by_key<K, F>(self, mut key: F) -> Option<usize>
+        where Self: Sized, K: Ord, F: FnMut(&Self::Item) -> K
+    {
+        self.enumerate()
+            .min_by(|x, y| Ord::cmp(&key(&x.1), &key(&y.1)))
+            .map(|x| x.0)
+    }
+
+    /// Return the position of the minimum element in the iterator, as
+    /// determined by the specified comparison function.
+    ///
+    /// If several elements are equally minimum, the position of the
+    /// first of them is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = [-3_i32, 0, 1, 5, -10];
+    /// assert_eq!(a.iter().position_min_by(|x, y| x.cmp(y)), Some(4));
+    ///
+    /// let a = [1_i32, 1, -1, -1];
+    /// assert_eq!(a.iter().position_min_by(|x, y| x.cmp(y)), Some(2));
+    /// ```
+    fn position_min_by<F>(self, mut compare: F) -> Option<usize>
+        where Self: Sized, F: FnMut(&Self::Item, &Self::Item) -> Ordering
+    {
+        self.enumerate()
+            .min_by(|x, y| compare(&x.1, &y.1))
+            .map(|x| x.0)
+    }
+
+    /// Return the positions of the minimum and maximum elements in
+    /// the iterator.
+    ///
+    /// The return type `MinMaxResult` is an enum of three variants:
+    ///
+    /// - `NoElements` if the iterator is empty.
+    /// - `OneElement(xpos)` if the iterator has exactly one element.
+    /// - `MinMax(xpos, ypos)` is returned otherwise, where the
+    ///   element at `xpos` ≤ the element at `ypos`. While the
+    ///   referenced elements themselves may be equal, `xpos` cannot
+    ///   be equal to `ypos`.
+    ///
+    /// On an iterator of length `n`, `position_minmax` does `1.5 * n`
+    /// comparisons, and so is faster than calling `position_min` and
+    /// `position_max` separately which does `2 * n` comparisons.
+    ///
+    /// For the minimum, if several elements are equally minimum, the
+    /// position of the first of them is returned. For the maximum, if
+    /// several elements are equally maximum, the position of the last
+    /// of them is returned.
+    ///
+    /// The elements can be floats but no particular result is guaranteed
+    /// if an element is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use itertools::MinMaxResult::{NoElements, OneElement, MinMax};
+    ///
+    /// let a: [i32; 0] = [];
+    /// assert_eq!(a.iter().position_minmax(), NoElements);
+    ///
+    /// let a = [10];
+    /// assert_eq!(a.iter().position_minmax(), OneElement(0));
+    ///
+    /// let a = [-3, 0, 1, 5, -10];
+    /// assert_eq!(a.iter().position_minmax(), MinMax(4, 3));
+    ///
+    /// let a = [1, 1, -1, -1];
+    /// assert_eq!(a.iter().position_minmax(), MinMax(2, 1));
+    /// ```
+    fn position_minmax(self) -> MinMaxResult<usize>
+        where Self: Sized, Self::Item: PartialOrd
+    {
+        minmax::minmax_impl(self.enumerate(), |_| (), |x, y, _, _| x.1 < y.1)
+            .map(|(i, _)| i)
+    }
+
+    /// Return the positions of the minimum and maximum elements of an
+    /// iterator, as determined by the specified function.
+    ///
+    /// The return value is a variant of `MinMaxResult` like for
+    /// [`position_minmax`](#method.position_minmax).
+    ///
+    /// For the minimum, if several elements are equally minimum, the
+    /// position of the first of them is returned. For the maximum, if
+    /// several elements are equally maximum, the position of the last
+    /// of them is returned.
+    ///
+    /// The keys can be floats but no particular result is guaranteed
+    /// if a key is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use itertools::MinMaxResult::MinMax;
+    ///
+    /// let a = [-3_i32, 0, 1, 5, -10];
+    /// assert_eq!(a.iter().position_minmax_by_key(|x| x.abs()), MinMax(1, 4));
+    ///
+    /// let a = [1_i32, 1, -1, -1];
+    /// assert_eq!(a.iter().position_minmax_by_key(|x| x.abs()), MinMax(0, 3));
+    /// ```
+    fn position_minmax_by_key<K, F>(self, mut key: F) -> MinMaxResult<usize>
+        where Self: Sized, K: PartialOrd, F: FnMut(&Self::Item) -> K
+    {
+        minmax::minmax_impl(self.enumerate(), |x| key(&x.1), |_, _, xk, yk| xk < yk)
+            .map(|(i, _)| i)
+    }
+
+    /// Return the positions of the minimum and maximum elements of an
+    /// iterator, as determined by the specified comparison function.
+    ///
+    /// The return value is a variant of `MinMaxResult` like for
+    /// [`position_minmax`](#method.position_minmax).
+    ///
+    /// For the minimum, if several elements are equally minimum, the
+    /// position of the first of them is returned. For the maximum, if
+    /// several elements are equally maximum, the position of the last
+    /// of them is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use itertools::MinMaxResult::MinMax;
+    ///
+    /// let a = [-3_i32, 0, 1, 5, -10];
+    /// assert_eq!(a.iter().position_minmax_by(|x, y| x.cmp(y)), MinMax(4, 3));
+    ///
+    /// let a = [1_i32, 1, -1, -1];
+    /// assert_eq!(a.iter().position_minmax_by(|x, y| x.cmp(y)), MinMax(2, 1));
+    /// ```
+    fn position_minmax_by<F>(self, mut compare: F) -> MinMaxResult<usize>
+        where Self: Sized, F: FnMut(&Self::Item, &Self::Item) -> Ordering
+    {
+        minmax::minmax_impl(self.enumerate(), |_| (), |x, y, _, _| {
+            Ordering::Less == compare(&x.1, &y.1)
+        }).map(|(i, _)| i)
+    }
+
     /// If the iterator yields exactly one element, that element will be returned, otherwise
     /// an error will be returned containing an iterator that has the same output as the input
     /// iterator.
@@ -2147,6 +5105,68 @@ pub trait Itertools : Iterator {
             None => Err(ExactlyOneError::new((None, None), self)),
         }
     }
+
+    /// Return a [`NonEmpty`](struct.NonEmpty.html) wrapper if the iterator
+    /// yields at least one element, or `None` if it is empty.
+    ///
+    /// The first element is consumed to check for it, and kept in the
+    /// wrapper. Its reductions like `.min()`, `.max()`, `.fold1()` or
+    /// `.last()` return the value itself rather than an `Option`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let scores = vec![7, 3, 9];
+    /// if let Some(scores) = scores.into_iter().at_least_one() {
+    ///     assert_eq!(scores.clone().min(), 3);
+    ///     assert_eq!(scores.clone().minmax(), (3, 9));
+    ///     assert_eq!(scores.fold1(|a, b| a + b), 19);
+    /// }
+    ///
+    /// assert!((0..0).at_least_one().is_none());
+    /// ```
+    fn at_least_one(self) -> Option<NonEmpty<Self>>
+        where Self: Sized,
+    {
+        non_empty::at_least_one(self)
+    }
+
+    /// Return `true` if the iterator yields exactly `n` elements.
+    ///
+    /// Unlike `.count() == n`, this consumes at most `n + 1` elements, so it
+    /// can be used on unbounded iterators.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert!((0..3).exactly_n(3));
+    /// assert!(!(0..3).exactly_n(2));
+    /// assert!(!(0..).exactly_n(5));
+    /// ```
+    fn exactly_n(&mut self, n: usize) -> bool
+        where Self: Sized,
+    {
+        self.take(n).count() == n && self.next().is_none()
+    }
+
+    /// Return `true` if the iterator yields exactly `n` elements for which
+    /// `pred` returns `true`.
+    ///
+    /// This consumes elements only until the `n + 1`th matching element, so
+    /// it returns `false` early as soon as there are too many.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert!((0..10).exactly_n_where(5, |x| x % 2 == 0));
+    /// assert!(!(0..).exactly_n_where(2, |&x| x > 100));
+    /// ```
+    fn exactly_n_where<P>(&mut self, n: usize, mut pred: P) -> bool
+        where Self: Sized,
+              P: FnMut(&Self::Item) -> bool,
+    {
+        self.filter(&mut pred).take(n).count() == n && !self.any(|elt| pred(&elt))
+    }
 }
 
 impl<T: ?Sized> Itertools for T where T: Iterator { }
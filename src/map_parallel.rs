@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::iter::Fuse;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use size_hint;
+
+/// An iterator adaptor that applies a function on a pool of worker threads,
+/// yielding the results in the order of the input elements.
+///
+/// Iterator element type is `U`.
+///
+/// See [`.map_parallel()`](../trait.Itertools.html#method.map_parallel) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct MapParallel<I, U>
+    where I: Iterator
+{
+    iter: Fuse<I>,
+    jobs: Sender<(usize, I::Item)>,
+    results: Receiver<(usize, thread::Result<U>)>,
+    /// Results that arrived before the ones preceding them.
+    reorder: HashMap<usize, U>,
+    next_in: usize,
+    next_out: usize,
+    max_in_flight: usize,
+}
+
+impl<I, U> fmt::Debug for MapParallel<I, U>
+    where I: Iterator + fmt::Debug,
+          U: fmt::Debug,
+{
+    debug_fmt_fields!(MapParallel, iter, reorder, next_in, next_out, max_in_flight);
+}
+
+/// Create a new `MapParallel` iterator, starting its worker threads.
+pub fn map_parallel<I, F, U>(iter: I, n_threads: usize, f: F) -> MapParallel<I, U>
+    where I: Iterator,
+          I::Item: Send + 'static,
+          F: Fn(I::Item) -> U + Send + Sync + 'static,
+          U: Send + 'static,
+{
+    assert!(n_threads != 0, "map_parallel: the number of threads must be non-zero");
+    let (jobs, job_receiver) = channel::<(usize, I::Item)>();
+    let (result_sender, results) = channel();
+    let job_receiver = Arc::new(Mutex::new(job_receiver));
+    let f = Arc::new(f);
+    for _ in 0..n_threads {
+        let job_receiver = job_receiver.clone();
+        let result_sender = result_sender.clone();
+        let f = f.clone();
+        thread::spawn(move || loop {
+            // Panics are caught below, so the lock is never poisoned.
+            let job = job_receiver.lock().unwrap().recv();
+            let (index, elt) = match job {
+                Ok(job) => job,
+                // the adaptor was dropped or the input is exhausted
+                Err(_) => break,
+            };
+            let result = panic::catch_unwind(AssertUnwindSafe(|| f(elt)));
+            if result_sender.send((index, result)).is_err() {
+                break;
+            }
+        });
+    }
+    MapParallel {
+        iter: iter.fuse(),
+        jobs,
+        results,
+        reorder: HashMap::new(),
+        next_in: 0,
+        next_out: 0,
+        max_in_flight: 2 * n_threads,
+    }
+}
+
+impl<I, U> Iterator for MapParallel<I, U>
+    where I: Iterator
+{
+    type Item = U;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Keep the workers busy, without running arbitrarily far ahead.
+        while self.next_in - self.next_out < self.max_in_flight {
+            match self.iter.next() {
+                Some(elt) => {
+                    self.jobs.send((self.next_in, elt))
+                        .expect("map_parallel: worker threads stopped");
+                    self.next_in += 1;
+                }
+                None => break,
+            }
+        }
+        if self.next_out == self.next_in {
+            return None;
+        }
+        loop {
+            if let Some(result) = self.reorder.remove(&self.next_out) {
+                self.next_out += 1;
+                return Some(result);
+            }
+            let (index, result) = self.results.recv()
+                .expect("map_parallel: worker threads stopped");
+            match result {
+                Ok(result) => {
+                    self.reorder.insert(index, result);
+                }
+                Err(payload) => panic::resume_unwind(payload),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint::add_scalar(self.iter.size_hint(), self.next_in - self.next_out)
+    }
+}
+
+impl<I, U> ExactSizeIterator for MapParallel<I, U>
+    where I: ExactSizeIterator
+{}
@@ -14,6 +14,86 @@ pub enum MinMaxResult<T> {
     MinMax(T, T)
 }
 
+impl<T> MinMaxResult<T> {
+    /// Return a reference to the minimum, or `None` if there are no elements.
+    ///
+    /// ```
+    /// use itertools::MinMaxResult::{self, NoElements, OneElement, MinMax};
+    ///
+    /// let r: MinMaxResult<i32> = NoElements;
+    /// assert_eq!(r.min(), None);
+    /// assert_eq!(OneElement(1).min(), Some(&1));
+    /// assert_eq!(MinMax(1, 2).min(), Some(&1));
+    /// ```
+    pub fn min(&self) -> Option<&T> {
+        match *self {
+            MinMaxResult::NoElements => None,
+            MinMaxResult::OneElement(ref x) | MinMaxResult::MinMax(ref x, _) => Some(x),
+        }
+    }
+
+    /// Return a reference to the maximum, or `None` if there are no elements.
+    ///
+    /// ```
+    /// use itertools::MinMaxResult::{self, NoElements, OneElement, MinMax};
+    ///
+    /// let r: MinMaxResult<i32> = NoElements;
+    /// assert_eq!(r.max(), None);
+    /// assert_eq!(OneElement(1).max(), Some(&1));
+    /// assert_eq!(MinMax(1, 2).max(), Some(&2));
+    /// ```
+    pub fn max(&self) -> Option<&T> {
+        match *self {
+            MinMaxResult::NoElements => None,
+            MinMaxResult::OneElement(ref x) | MinMaxResult::MinMax(_, ref x) => Some(x),
+        }
+    }
+
+    /// Convert a `&MinMaxResult<T>` to a `MinMaxResult<&T>`.
+    pub fn as_ref(&self) -> MinMaxResult<&T> {
+        match *self {
+            MinMaxResult::NoElements => MinMaxResult::NoElements,
+            MinMaxResult::OneElement(ref x) => MinMaxResult::OneElement(x),
+            MinMaxResult::MinMax(ref x, ref y) => MinMaxResult::MinMax(x, y),
+        }
+    }
+
+    /// Apply `f` to each contained element, keeping the variant.
+    ///
+    /// Note that `f` should preserve the ordering of the elements for the
+    /// result to still be a meaningful minimum and maximum.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use itertools::MinMaxResult::MinMax;
+    ///
+    /// let r = ["apple", "fig", "banana"].iter().minmax_by_key(|s| s.len());
+    /// assert_eq!(r.map(|s| s.len()), MinMax(3, 6));
+    /// ```
+    pub fn map<U, F>(self, mut f: F) -> MinMaxResult<U>
+        where F: FnMut(T) -> U
+    {
+        match self {
+            MinMaxResult::NoElements => MinMaxResult::NoElements,
+            MinMaxResult::OneElement(x) => MinMaxResult::OneElement(f(x)),
+            MinMaxResult::MinMax(x, y) => {
+                let x = f(x);
+                MinMaxResult::MinMax(x, f(y))
+            }
+        }
+    }
+}
+
+impl<T> From<Option<(T, T)>> for MinMaxResult<T> {
+    /// `None` becomes `NoElements` and `Some((x, y))` becomes `MinMax(x, y)`.
+    fn from(o: Option<(T, T)>) -> Self {
+        match o {
+            None => MinMaxResult::NoElements,
+            Some((x, y)) => MinMaxResult::MinMax(x, y),
+        }
+    }
+}
+
 impl<T: Clone> MinMaxResult<T> {
     /// `into_option` creates an `Option` of type `(T, T)`. The returned `Option`
     /// has variant `None` if and only if the `MinMaxResult` has variant
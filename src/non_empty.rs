@@ -0,0 +1,176 @@
+use std::cmp::Ordering;
+use std::iter::{self, Chain, Once};
+
+use Itertools;
+use minmax::MinMaxResult;
+
+/// An iterator that is known to yield at least one element.
+///
+/// The reductions that return an `Option` on an `Iterator` return the value
+/// directly here, since they can't fail.
+///
+/// Use `.into_iter()` to iterate over all the elements, the first one
+/// included.
+///
+/// See [`.at_least_one()`](../trait.Itertools.html#method.at_least_one) for more information.
+#[derive(Clone, Debug)]
+pub struct NonEmpty<I>
+    where I: Iterator
+{
+    first: I::Item,
+    rest: I,
+}
+
+/// Create a new `NonEmpty` wrapper, or `None` if `iter` is empty.
+pub fn at_least_one<I>(mut iter: I) -> Option<NonEmpty<I>>
+    where I: Iterator
+{
+    let first = iter.next()?;
+    Some(NonEmpty { first, rest: iter })
+}
+
+impl<I> NonEmpty<I>
+    where I: Iterator
+{
+    /// Return a reference to the first element.
+    pub fn first(&self) -> &I::Item {
+        &self.first
+    }
+
+    /// Split into the first element and an iterator of the others.
+    pub fn into_parts(self) -> (I::Item, I) {
+        (self.first, self.rest)
+    }
+
+    /// Return the number of elements, consuming the iterator.
+    pub fn count(self) -> usize {
+        1 + self.rest.count()
+    }
+
+    /// Return the last element.
+    pub fn last(self) -> I::Item {
+        self.rest.fold(self.first, |_, elt| elt)
+    }
+
+    /// Accumulate the elements in sequence using the closure `f`, starting
+    /// from the first element.
+    ///
+    /// This is the `.fold1()` of a non-empty iterator.
+    pub fn fold1<F>(self, f: F) -> I::Item
+        where F: FnMut(I::Item, I::Item) -> I::Item
+    {
+        self.rest.fold(self.first, f)
+    }
+
+    /// Return the minimum element.
+    ///
+    /// If several elements are equally minimum, the first one is returned.
+    pub fn min(self) -> I::Item
+        where I::Item: Ord
+    {
+        self.min_by(Ord::cmp)
+    }
+
+    /// Return the maximum element.
+    ///
+    /// If several elements are equally maximum, the last one is returned.
+    pub fn max(self) -> I::Item
+        where I::Item: Ord
+    {
+        self.max_by(Ord::cmp)
+    }
+
+    /// Return the element that gives the minimum value with respect to the
+    /// `compare` function.
+    ///
+    /// If several elements are equally minimum, the first one is returned.
+    pub fn min_by<F>(self, mut compare: F) -> I::Item
+        where F: FnMut(&I::Item, &I::Item) -> Ordering
+    {
+        self.fold1(|min, elt| {
+            match compare(&elt, &min) {
+                Ordering::Less => elt,
+                _ => min,
+            }
+        })
+    }
+
+    /// Return the element that gives the maximum value with respect to the
+    /// `compare` function.
+    ///
+    /// If several elements are equally maximum, the last one is returned.
+    pub fn max_by<F>(self, mut compare: F) -> I::Item
+        where F: FnMut(&I::Item, &I::Item) -> Ordering
+    {
+        self.fold1(|max, elt| {
+            match compare(&elt, &max) {
+                Ordering::Less => max,
+                _ => elt,
+            }
+        })
+    }
+
+    /// Return the element that gives the minimum value from the `key`
+    /// function.
+    ///
+    /// If several elements are equally minimum, the first one is returned.
+    pub fn min_by_key<K, F>(self, mut key: F) -> I::Item
+        where F: FnMut(&I::Item) -> K,
+              K: Ord,
+    {
+        let first_key = key(&self.first);
+        self.rest.fold((first_key, self.first), |(min_key, min), elt| {
+            let elt_key = key(&elt);
+            if elt_key < min_key {
+                (elt_key, elt)
+            } else {
+                (min_key, min)
+            }
+        }).1
+    }
+
+    /// Return the element that gives the maximum value from the `key`
+    /// function.
+    ///
+    /// If several elements are equally maximum, the last one is returned.
+    pub fn max_by_key<K, F>(self, mut key: F) -> I::Item
+        where F: FnMut(&I::Item) -> K,
+              K: Ord,
+    {
+        let first_key = key(&self.first);
+        self.rest.fold((first_key, self.first), |(max_key, max), elt| {
+            let elt_key = key(&elt);
+            if elt_key < max_key {
+                (max_key, max)
+            } else {
+                (elt_key, elt)
+            }
+        }).1
+    }
+
+    /// Return the minimum and maximum elements, as a `(min, max)` pair.
+    ///
+    /// See [`.minmax()`](../trait.Itertools.html#method.minmax) for the
+    /// choice among equal elements. With a single element, the pair holds
+    /// that element twice.
+    pub fn minmax(self) -> (I::Item, I::Item)
+        where I::Item: PartialOrd + Clone
+    {
+        match self.into_iter().minmax() {
+            MinMaxResult::MinMax(min, max) => (min, max),
+            MinMaxResult::OneElement(elt) => (elt.clone(), elt),
+            MinMaxResult::NoElements => unreachable!(),
+        }
+    }
+}
+
+impl<I> IntoIterator for NonEmpty<I>
+    where I: Iterator
+{
+    type Item = I::Item;
+    type IntoIter = Chain<Once<I::Item>, I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        iter::once(self.first).chain(self.rest)
+    }
+}
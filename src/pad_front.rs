@@ -0,0 +1,212 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::iter::Fuse;
+use size_hint;
+
+/// The state shared by `PadFrontUsing` and `PadBothUsing`: the first `min`
+/// elements are buffered to know how many elements are missing.
+struct PadBuffered<I, F>
+    where I: Iterator
+{
+    iter: Fuse<I>,
+    min: usize,
+    /// The first elements, up to `min`, once taken from `iter`
+    buf: Option<VecDeque<I::Item>>,
+    /// The number of elements yielded
+    pos: usize,
+    /// The number of elements to fill in at the front
+    front: usize,
+    /// Whether the missing elements are split between the front and the back
+    both: bool,
+    filler: F,
+}
+
+impl<I, F> Clone for PadBuffered<I, F>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+          F: Clone,
+{
+    fn clone(&self) -> Self {
+        PadBuffered {
+            iter: self.iter.clone(),
+            min: self.min,
+            buf: self.buf.clone(),
+            pos: self.pos,
+            front: self.front,
+            both: self.both,
+            filler: self.filler.clone(),
+        }
+    }
+}
+
+impl<I, F> fmt::Debug for PadBuffered<I, F>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(PadBuffered, iter, min, buf, pos, front, both);
+}
+
+impl<I, F> PadBuffered<I, F>
+    where I: Iterator,
+          F: FnMut(usize) -> I::Item
+{
+    fn new(iter: I, min: usize, filler: F, both: bool) -> Self {
+        PadBuffered {
+            iter: iter.fuse(),
+            min,
+            buf: None,
+            pos: 0,
+            front: 0,
+            both,
+            filler,
+        }
+    }
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.buf.is_none() {
+            let buf = self.iter.by_ref().take(self.min).collect::<VecDeque<_>>();
+            let missing = self.min - buf.len();
+            self.front = if self.both { missing / 2 } else { missing };
+            self.buf = Some(buf);
+        }
+        let elt = if self.pos < self.front {
+            (self.filler)(self.pos)
+        } else if let Some(elt) = self.buf.as_mut().and_then(|buf| buf.pop_front()) {
+            elt
+        } else if let Some(elt) = self.iter.next() {
+            elt
+        } else if self.pos < self.min {
+            (self.filler)(self.pos)
+        } else {
+            return None;
+        };
+        self.pos += 1;
+        Some(elt)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.buf.as_ref().map_or(0, |buf| buf.len());
+        let front = self.front.saturating_sub(self.pos);
+        let rest = size_hint::add_scalar(self.iter.size_hint(), buffered + front);
+        let tail = self.min.saturating_sub(self.pos);
+        size_hint::max(rest, (tail, Some(tail)))
+    }
+}
+
+/// An iterator adaptor that pads a sequence to a minimum length by filling
+/// missing elements at the front using a closure.
+///
+/// Iterator element type is `I::Item`.
+///
+/// See [`.pad_front_using()`](../trait.Itertools.html#method.pad_front_using) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct PadFrontUsing<I, F>
+    where I: Iterator
+{
+    buffered: PadBuffered<I, F>,
+}
+
+impl<I, F> Clone for PadFrontUsing<I, F>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+          F: Clone,
+{
+    fn clone(&self) -> Self {
+        PadFrontUsing { buffered: self.buffered.clone() }
+    }
+}
+
+impl<I, F> fmt::Debug for PadFrontUsing<I, F>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(PadFrontUsing, buffered);
+}
+
+/// Create a new **PadFrontUsing** iterator.
+pub fn pad_front_using<I, F>(iter: I, min: usize, filler: F) -> PadFrontUsing<I, F>
+    where I: Iterator,
+          F: FnMut(usize) -> I::Item
+{
+    PadFrontUsing { buffered: PadBuffered::new(iter, min, filler, false) }
+}
+
+impl<I, F> Iterator for PadFrontUsing<I, F>
+    where I: Iterator,
+          F: FnMut(usize) -> I::Item
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        self.buffered.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.buffered.size_hint()
+    }
+}
+
+impl<I, F> ExactSizeIterator for PadFrontUsing<I, F>
+    where I: ExactSizeIterator,
+          F: FnMut(usize) -> I::Item
+{}
+
+/// An iterator adaptor that pads a sequence to a minimum length by filling
+/// missing elements at both ends using a closure.
+///
+/// Iterator element type is `I::Item`.
+///
+/// See [`.pad_both_using()`](../trait.Itertools.html#method.pad_both_using) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct PadBothUsing<I, F>
+    where I: Iterator
+{
+    buffered: PadBuffered<I, F>,
+}
+
+impl<I, F> Clone for PadBothUsing<I, F>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+          F: Clone,
+{
+    fn clone(&self) -> Self {
+        PadBothUsing { buffered: self.buffered.clone() }
+    }
+}
+
+impl<I, F> fmt::Debug for PadBothUsing<I, F>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(PadBothUsing, buffered);
+}
+
+/// Create a new **PadBothUsing** iterator.
+pub fn pad_both_using<I, F>(iter: I, min: usize, filler: F) -> PadBothUsing<I, F>
+    where I: Iterator,
+          F: FnMut(usize) -> I::Item
+{
+    PadBothUsing { buffered: PadBuffered::new(iter, min, filler, true) }
+}
+
+impl<I, F> Iterator for PadBothUsing<I, F>
+    where I: Iterator,
+          F: FnMut(usize) -> I::Item
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        self.buffered.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.buffered.size_hint()
+    }
+}
+
+impl<I, F> ExactSizeIterator for PadBothUsing<I, F>
+    where I: ExactSizeIterator,
+          F: FnMut(usize) -> I::Item
+{}
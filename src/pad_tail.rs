@@ -81,3 +81,9 @@ impl<I, F> ExactSizeIterator for PadUsing<I, F>
     where I: ExactSizeIterator,
           F: FnMut(usize) -> I::Item
 {}
+
+#[cfg(feature = "nightly")]
+unsafe impl<I, F> ::std::iter::TrustedLen for PadUsing<I, F>
+    where I: ::std::iter::TrustedLen,
+          F: FnMut(usize) -> I::Item
+{}
@@ -0,0 +1,103 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::rc::Rc;
+
+use size_hint;
+
+/// Common buffer object for the two partition halves
+struct PartitionBuffer<A, I, F> {
+    /// Elements read from `iter` for the half of `true`, not yet yielded
+    matching: VecDeque<A>,
+    /// Elements read from `iter` for the half of `false`, not yet yielded
+    rest: VecDeque<A>,
+    iter: I,
+    pred: F,
+}
+
+/// One half of an iterator pair splitting the elements of an iterator by a
+/// predicate.
+///
+/// See [`.partition_lazy()`](../trait.Itertools.html#method.partition_lazy)
+/// for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct PartitionLazy<I, F>
+    where I: Iterator
+{
+    rcbuffer: Rc<RefCell<PartitionBuffer<I::Item, I, F>>>,
+    /// The value of the predicate for the elements of this half
+    side: bool,
+}
+
+impl<I, F> fmt::Debug for PartitionLazy<I, F>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let buffer = self.rcbuffer.borrow();
+        f.debug_struct("PartitionLazy")
+            .field("side", &self.side)
+            .field("matching", &buffer.matching)
+            .field("rest", &buffer.rest)
+            .field("iter", &buffer.iter)
+            .finish()
+    }
+}
+
+pub fn new<I, F>(iter: I, pred: F) -> (PartitionLazy<I, F>, PartitionLazy<I, F>)
+    where I: Iterator,
+          F: FnMut(&I::Item) -> bool
+{
+    let buffer = PartitionBuffer {
+        matching: VecDeque::new(),
+        rest: VecDeque::new(),
+        iter,
+        pred,
+    };
+    let matching = PartitionLazy { rcbuffer: Rc::new(RefCell::new(buffer)), side: true };
+    let rest = PartitionLazy { rcbuffer: matching.rcbuffer.clone(), side: false };
+    (matching, rest)
+}
+
+impl<I, F> Iterator for PartitionLazy<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> bool
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        // once the other half is dropped, nothing needs to be kept for it
+        let alone = Rc::strong_count(&self.rcbuffer) == 1;
+        // .borrow_mut may fail here -- but only if the predicate or the
+        // iterator refer back to the partition.
+        let mut buffer = self.rcbuffer.borrow_mut();
+        let buffer = &mut *buffer;
+        let (own, other) = if self.side {
+            (&mut buffer.matching, &mut buffer.rest)
+        } else {
+            (&mut buffer.rest, &mut buffer.matching)
+        };
+        if alone {
+            other.clear();
+        }
+        if let Some(elt) = own.pop_front() {
+            return Some(elt);
+        }
+        for elt in &mut buffer.iter {
+            if (buffer.pred)(&elt) == self.side {
+                return Some(elt);
+            }
+            if !alone {
+                other.push_back(elt);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffer = self.rcbuffer.borrow();
+        let own = if self.side { &buffer.matching } else { &buffer.rest };
+        let (_, hi) = buffer.iter.size_hint();
+        size_hint::add_scalar((0, hi), own.len())
+    }
+}
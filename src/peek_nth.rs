@@ -0,0 +1,145 @@
+use std::collections::VecDeque;
+use std::iter::Fuse;
+use size_hint;
+use PeekingNext;
+
+/// See [`peek_nth()`](../fn.peek_nth.html) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct PeekNth<I>
+    where I: Iterator
+{
+    iter: Fuse<I>,
+    buf: VecDeque<I::Item>,
+}
+
+/// An iterator adaptor that allows the user to peek at the element `n`
+/// positions ahead, for any `n`, without advancing the base iterator.
+///
+/// Unlike [`multipeek()`](fn.multipeek.html), there is no peeking cursor:
+/// the elements are buffered as far as needed, and `.peek_nth(n)` always
+/// refers to the element `n` positions after the one the next call to
+/// `.next()` returns.
+///
+/// ```
+/// use itertools::peek_nth;
+///
+/// let mut iter = peek_nth(1..10);
+///
+/// assert_eq!(iter.peek_nth(2), Some(&3));
+/// assert_eq!(iter.peek(), Some(&1));
+/// assert_eq!(iter.next(), Some(1));
+/// assert_eq!(iter.peek_nth(2), Some(&4));
+///
+/// if let Some(p) = iter.peek_nth_mut(1) {
+///     *p *= 10;
+/// }
+/// assert_eq!(iter.next_if(|&x| x == 2), Some(2));
+/// assert_eq!(iter.next_if_eq(&4), None);
+/// assert_eq!(iter.next(), Some(30));
+/// assert_eq!(iter.peek_nth(100), None);
+/// ```
+pub fn peek_nth<I>(iterable: I) -> PeekNth<I::IntoIter>
+    where I: IntoIterator
+{
+    PeekNth {
+        iter: iterable.into_iter().fuse(),
+        buf: VecDeque::new(),
+    }
+}
+
+impl<I> PeekNth<I>
+    where I: Iterator
+{
+    /// Works exactly like the `.peek()` method of `std::iter::Peekable`.
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        self.peek_nth(0)
+    }
+
+    /// Works exactly like the `.peek()` method of `std::iter::Peekable`,
+    /// but returns a mutable reference to the element.
+    pub fn peek_mut(&mut self) -> Option<&mut I::Item> {
+        self.peek_nth_mut(0)
+    }
+
+    /// Return a reference to the element `n` positions ahead, `0` being the
+    /// element the next call to `.next()` returns, or `None` if the iterator
+    /// ends before it.
+    ///
+    /// The elements up to the `n`th one are buffered, without advancing the
+    /// iterator.
+    pub fn peek_nth(&mut self, n: usize) -> Option<&I::Item> {
+        self.fill(n);
+        self.buf.get(n)
+    }
+
+    /// Like [`.peek_nth()`](#method.peek_nth), but returns a mutable
+    /// reference to the element.
+    pub fn peek_nth_mut(&mut self, n: usize) -> Option<&mut I::Item> {
+        self.fill(n);
+        self.buf.get_mut(n)
+    }
+
+    /// Consume and return the next element if `func` returns `true` for it,
+    /// or leave it to be returned by the next call to `.next()` otherwise.
+    pub fn next_if<F>(&mut self, func: F) -> Option<I::Item>
+        where F: FnOnce(&I::Item) -> bool
+    {
+        match self.next() {
+            Some(item) => {
+                if func(&item) {
+                    Some(item)
+                } else {
+                    self.buf.push_front(item);
+                    None
+                }
+            }
+            None => None,
+        }
+    }
+
+    /// Consume and return the next element if it is equal to `expected`.
+    pub fn next_if_eq<T>(&mut self, expected: &T) -> Option<I::Item>
+        where T: ?Sized,
+              I::Item: PartialEq<T>,
+    {
+        self.next_if(|next| next == expected)
+    }
+
+    /// Buffer the elements up to the `n`th one, if any.
+    fn fill(&mut self, n: usize) {
+        let unbuffered = n.saturating_add(1).saturating_sub(self.buf.len());
+        self.buf.extend(self.iter.by_ref().take(unbuffered));
+    }
+}
+
+impl<I> PeekingNext for PeekNth<I>
+    where I: Iterator,
+{
+    fn peeking_next<F>(&mut self, accept: F) -> Option<Self::Item>
+        where F: FnOnce(&Self::Item) -> bool
+    {
+        self.next_if(accept)
+    }
+}
+
+impl<I> Iterator for PeekNth<I>
+    where I: Iterator
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        match self.buf.pop_front() {
+            Some(item) => Some(item),
+            None => self.iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint::add_scalar(self.iter.size_hint(), self.buf.len())
+    }
+}
+
+impl<I> ExactSizeIterator for PeekNth<I>
+    where I: ExactSizeIterator
+{}
@@ -0,0 +1,92 @@
+use std::iter::Fuse;
+
+use size_hint;
+
+/// An iterator adaptor that allows peeking at a fixed number of elements
+/// ahead, buffered in an array.
+///
+/// See [`.peek_window()`](../trait.Itertools.html#method.peek_window) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct PeekWindow<I, A>
+    where I: Iterator
+{
+    iter: Fuse<I>,
+    /// Ring buffer of the peeked elements
+    buf: A,
+    /// Index in `buf` of the first peeked element
+    start: usize,
+    /// Number of peeked elements
+    len: usize,
+}
+
+/// Create a new `PeekWindow` iterator.
+pub fn peek_window<I, A>(iter: I) -> PeekWindow<I, A>
+    where I: Iterator,
+          A: Default + AsRef<[Option<I::Item>]> + AsMut<[Option<I::Item>]>,
+{
+    let buf = A::default();
+    assert!(!buf.as_ref().is_empty(), "peek_window: the lookahead must be non-zero");
+    PeekWindow {
+        iter: iter.fuse(),
+        buf,
+        start: 0,
+        len: 0,
+    }
+}
+
+impl<I, A> PeekWindow<I, A>
+    where I: Iterator,
+          A: AsRef<[Option<I::Item>]> + AsMut<[Option<I::Item>]>,
+{
+    /// Return the number of elements that can be peeked at: the length of
+    /// the array `A`.
+    pub fn lookahead(&self) -> usize {
+        self.buf.as_ref().len()
+    }
+
+    /// Return a reference to the element `index` positions ahead, `0` being
+    /// the element the next call to `.next()` returns, or `None` if the
+    /// iterator ends before it.
+    ///
+    /// **Panics** if `index` is not less than the lookahead.
+    pub fn peek(&mut self, index: usize) -> Option<&I::Item> {
+        let cap = self.lookahead();
+        assert!(index < cap, "peek_window: index {} is out of the lookahead of {}",
+                index, cap);
+        while self.len <= index {
+            let elt = self.iter.next()?;
+            let slot = (self.start + self.len) % cap;
+            self.buf.as_mut()[slot] = Some(elt);
+            self.len += 1;
+        }
+        self.buf.as_ref()[(self.start + index) % cap].as_ref()
+    }
+}
+
+impl<I, A> Iterator for PeekWindow<I, A>
+    where I: Iterator,
+          A: AsRef<[Option<I::Item>]> + AsMut<[Option<I::Item>]>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.len == 0 {
+            return self.iter.next();
+        }
+        let cap = self.lookahead();
+        let elt = self.buf.as_mut()[self.start].take();
+        self.start = (self.start + 1) % cap;
+        self.len -= 1;
+        elt
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint::add_scalar(self.iter.size_hint(), self.len)
+    }
+}
+
+impl<I, A> ExactSizeIterator for PeekWindow<I, A>
+    where I: ExactSizeIterator,
+          A: AsRef<[Option<I::Item>]> + AsMut<[Option<I::Item>]>,
+{}
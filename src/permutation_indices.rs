@@ -0,0 +1,109 @@
+/// An iterator over the `k`-length permutations of the indices `0..n`.
+///
+/// See [`permutation_indices()`](../fn.permutation_indices.html) for more information.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct PermutationIndices {
+    n: usize,
+    k: usize,
+    /// A permutation of `0..n`, whose first `k` elements are the next output
+    indices: Vec<usize>,
+    /// For each output position, the number of choices left for it
+    cycles: Vec<usize>,
+    first: bool,
+    done: bool,
+}
+
+/// Create an iterator over the `k`-length permutations of the indices `0..n`,
+/// in lexicographic order.
+///
+/// Permuting indices rather than elements lets the caller permute items
+/// that are not `Clone`, or expensive to clone, by indexing its own storage.
+///
+/// If `k` is greater than `n`, there are no permutations. If `k` is zero,
+/// there is a single, empty one.
+///
+/// ```
+/// use itertools::permutation_indices;
+///
+/// let perms: Vec<_> = permutation_indices(3, 2).collect();
+/// assert_eq!(perms, vec![vec![0, 1], vec![0, 2], vec![1, 0],
+///                        vec![1, 2], vec![2, 0], vec![2, 1]]);
+///
+/// // permute values that can't be cloned
+/// let files = vec![String::from("a.txt"), String::from("b.txt")];
+/// for perm in permutation_indices(files.len(), files.len()) {
+///     let order: Vec<&String> = perm.iter().map(|&i| &files[i]).collect();
+///     assert_eq!(order.len(), 2);
+/// }
+/// ```
+pub fn permutation_indices(n: usize, k: usize) -> PermutationIndices {
+    PermutationIndices {
+        n,
+        k,
+        indices: (0..n).collect(),
+        cycles: if k <= n { (n - k + 1..n + 1).rev().collect() } else { Vec::new() },
+        first: true,
+        done: k > n,
+    }
+}
+
+/// The number of `k`-length permutations of `n` elements, if it fits in a
+/// `usize`.
+fn count_permutations(n: usize, k: usize) -> Option<usize> {
+    let mut count: usize = 1;
+    for x in n - k + 1..n + 1 {
+        count = count.checked_mul(x)?;
+    }
+    Some(count)
+}
+
+impl Iterator for PermutationIndices {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if self.first {
+            self.first = false;
+            return Some(self.indices[..self.k].to_vec());
+        }
+        for i in (0..self.k).rev() {
+            self.cycles[i] -= 1;
+            if self.cycles[i] == 0 {
+                // all the choices for position i were made: restore the
+                // order of the indices after it, and move on to i - 1
+                let index = self.indices.remove(i);
+                self.indices.push(index);
+                self.cycles[i] = self.n - i;
+            } else {
+                let j = self.n - self.cycles[i];
+                self.indices.swap(i, j);
+                return Some(self.indices[..self.k].to_vec());
+            }
+        }
+        self.done = true;
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        // The choices left for each position are the digits of the number
+        // of permutations after the current one, in a mixed radix.
+        let mut remaining = Some(self.first as usize);
+        for i in 0..self.k {
+            let weight = count_permutations(self.n - i - 1, self.k - i - 1);
+            remaining = remaining.and_then(|r| {
+                weight.and_then(|w| w.checked_mul(self.cycles[i] - 1))
+                      .and_then(|x| r.checked_add(x))
+            });
+        }
+        match remaining {
+            Some(remaining) => (remaining, Some(remaining)),
+            None => (!0, None),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use std::fmt;
+
+use super::lazy_buffer::LazyBuffer;
+use super::permutation_indices::{permutation_indices, PermutationIndices};
+
+/// An iterator adaptor that iterates through all the `k`-permutations of the
+/// elements from an iterator.
+///
+/// See [`.permutations()`](../trait.Itertools.html#method.permutations) for
+/// more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct Permutations<I: Iterator> {
+    vals: LazyBuffer<I>,
+    state: PermutationState,
+}
+
+#[derive(Clone, Debug)]
+enum PermutationState {
+    /// Nothing was yielded yet
+    Start { k: usize },
+    /// The source is not exhausted yet: the permutations are the first `k - 1`
+    /// elements followed by each element after them, in lexicographic order
+    Buffered { k: usize, min_n: usize },
+    /// The source is exhausted, its length is known
+    Loaded(PermutationIndices),
+    Empty,
+}
+
+impl<I> fmt::Debug for Permutations<I>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(Permutations, vals, state);
+}
+
+/// Create a new `Permutations` from an iterator.
+pub fn permutations<I: Iterator>(iter: I, k: usize) -> Permutations<I> {
+    Permutations {
+        vals: LazyBuffer::new(iter),
+        state: PermutationState::Start { k },
+    }
+}
+
+impl<I> Iterator for Permutations<I>
+    where I: Iterator,
+          I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (state, elt) = match self.state {
+            PermutationState::Start { k: 0 } => (PermutationState::Empty, Some(Vec::new())),
+            PermutationState::Start { k } => {
+                while self.vals.len() < k && self.vals.get_next() {}
+                if self.vals.len() < k {
+                    (PermutationState::Empty, None)
+                } else {
+                    let elt = (0..k).map(|i| self.vals[i].clone()).collect();
+                    (PermutationState::Buffered { k, min_n: k }, Some(elt))
+                }
+            }
+            PermutationState::Buffered { k, min_n } => {
+                if self.vals.get_next() {
+                    let elt = (0..k - 1).chain(Some(min_n))
+                        .map(|i| self.vals[i].clone())
+                        .collect();
+                    (PermutationState::Buffered { k, min_n: min_n + 1 }, Some(elt))
+                } else {
+                    // skip the permutations already yielded
+                    let n = min_n;
+                    let mut indices = permutation_indices(n, k);
+                    for _ in 0..n - k + 1 {
+                        indices.next();
+                    }
+                    let elt = pick(&self.vals, indices.next());
+                    (PermutationState::Loaded(indices), elt)
+                }
+            }
+            PermutationState::Loaded(ref mut indices) => {
+                return pick(&self.vals, indices.next());
+            }
+            PermutationState::Empty => return None,
+        };
+        self.state = state;
+        elt
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.state {
+            PermutationState::Loaded(ref indices) => indices.size_hint(),
+            PermutationState::Empty => (0, Some(0)),
+            _ => (0, None),
+        }
+    }
+}
+
+/// Map a permutation of indices over the buffered elements
+fn pick<I>(vals: &LazyBuffer<I>, indices: Option<Vec<usize>>) -> Option<Vec<I::Item>>
+    where I: Iterator,
+          I::Item: Clone,
+{
+    indices.map(|indices| indices.iter().map(|&i| vals[i].clone()).collect())
+}
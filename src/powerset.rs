@@ -0,0 +1,58 @@
+use std::fmt;
+
+use super::combinations::{combinations, Combinations};
+
+/// An iterator to iterate through the powerset of the elements from an iterator.
+///
+/// See [`.powerset()`](../trait.Itertools.html#method.powerset) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct Powerset<I: Iterator> {
+    /// The combinations of the current length, starting from 1
+    combs: Combinations<I>,
+    /// Whether the empty set, which comes first, is not yielded yet
+    first: bool,
+}
+
+impl<I> fmt::Debug for Powerset<I>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(Powerset, combs, first);
+}
+
+/// Create a new `Powerset` from a clonable iterator.
+pub fn powerset<I>(src: I) -> Powerset<I>
+    where I: Iterator,
+          I::Item: Clone,
+{
+    Powerset {
+        combs: combinations(src, 1),
+        first: true,
+    }
+}
+
+impl<I> Iterator for Powerset<I>
+    where I: Iterator,
+          I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.first {
+            // the empty set is yielded here, as the combinations of length
+            // zero of an empty source are empty
+            self.first = false;
+            Some(Vec::new())
+        } else if let Some(elt) = self.combs.next() {
+            Some(elt)
+        } else if self.combs.k() < self.combs.pool_len() {
+            // Once the combinations of a length are exhausted, the whole
+            // source is buffered: move on to the next length if any.
+            let k = self.combs.k() + 1;
+            self.combs.reset(k);
+            self.combs.next()
+        } else {
+            None
+        }
+    }
+}
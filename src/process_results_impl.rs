@@ -1,3 +1,4 @@
+use std::fmt;
 
 /// An iterator that produces only the `T` values as long as the
 /// inner iterator produces `Ok(T)`.
@@ -8,6 +9,7 @@
 #[derive(Debug)]
 pub struct ProcessResults<'a, I, E: 'a> {
     error: &'a mut Result<(), E>,
+    ok_count: &'a mut usize,
     iter: I,
 }
 
@@ -17,6 +19,50 @@ impl<'a, I, T, E> Iterator for ProcessResults<'a, I, E>
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some(Ok(x)) => {
+                *self.ok_count += 1;
+                Some(x)
+            }
+            Some(Err(e)) => {
+                *self.error = Err(e);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, hi) = self.iter.size_hint();
+        (0, hi)
+    }
+}
+
+/// An iterator that produces the `T` values of the inner iterator until it
+/// produces an error, which it stores in a slot provided by the caller.
+///
+/// See [`.capture_err()`](../trait.Itertools.html#method.capture_err) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug)]
+pub struct CaptureErr<'a, I, E: 'a> {
+    error: &'a mut Result<(), E>,
+    iter: I,
+}
+
+/// Create a new `CaptureErr` iterator.
+pub fn capture_err<'a, I, E>(iter: I, error: &'a mut Result<(), E>) -> CaptureErr<'a, I, E> {
+    CaptureErr { error, iter }
+}
+
+impl<'a, I, T, E> Iterator for CaptureErr<'a, I, E>
+    where I: Iterator<Item = Result<T, E>>
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error.is_err() {
+            return None;
+        }
         match self.iter.next() {
             Some(Ok(x)) => Some(x),
             Some(Err(e)) => {
@@ -28,6 +74,9 @@ impl<'a, I, T, E> Iterator for ProcessResults<'a, I, E>
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.error.is_err() {
+            return (0, Some(0));
+        }
         let (_, hi) = self.iter.size_hint();
         (0, hi)
     }
@@ -74,8 +123,73 @@ pub fn process_results<I, F, T, E, R>(iterable: I, processor: F) -> Result<R, E>
 {
     let iter = iterable.into_iter();
     let mut error = Ok(());
+    let mut ok_count = 0;
 
-    let result = processor(ProcessResults { error: &mut error, iter: iter });
+    let result = processor(ProcessResults { error: &mut error, ok_count: &mut ok_count, iter });
 
     error.map(|_| result)
 }
+
+/// The error returned by [`process_results_partial`](../fn.process_results_partial.html).
+///
+/// It holds the first error of the original iterable, together with the
+/// progress made before that error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProcessResultsError<E, R> {
+    /// The first error produced by the original iterable.
+    pub error: E,
+    /// The number of `Ok` values the processor received before the error.
+    pub ok_count: usize,
+    /// The value returned by the processor, computed from the values
+    /// before the error.
+    pub partial: R,
+}
+
+impl<E, R> fmt::Display for ProcessResultsError<E, R>
+    where E: fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "error after {} values: {}", self.ok_count, self.error)
+    }
+}
+
+/// Like [`process_results`](fn.process_results.html), but report the
+/// progress made when the original iterable produces an error.
+///
+/// If the original iterable produces an error at any point, the adapted
+/// iterator ends and the processor's return value is returned in an `Err`,
+/// together with the error and the number of `Ok` values received
+/// by the processor: see [`ProcessResultsError`](struct.ProcessResultsError.html).
+///
+/// Otherwise, the return value from the closure is returned wrapped
+/// inside `Ok`.
+///
+/// # Example
+///
+/// ```
+/// use itertools::process_results_partial;
+///
+/// let records = vec![Ok(3), Ok(1), Err("bad record"), Ok(2)];
+///
+/// let err = process_results_partial(records, |iter| iter.sum::<i32>()).unwrap_err();
+/// assert_eq!(err.error, "bad record");
+/// assert_eq!(err.ok_count, 2);
+/// assert_eq!(err.partial, 4);
+/// assert_eq!(err.to_string(), "error after 2 values: bad record");
+/// ```
+pub fn process_results_partial<I, F, T, E, R>(iterable: I, processor: F)
+    -> Result<R, ProcessResultsError<E, R>>
+    where I: IntoIterator<Item = Result<T, E>>,
+          F: FnOnce(ProcessResults<I::IntoIter, E>) -> R
+{
+    let iter = iterable.into_iter();
+    let mut error = Ok(());
+    let mut ok_count = 0;
+
+    let result = processor(ProcessResults { error: &mut error, ok_count: &mut ok_count, iter });
+
+    match error {
+        Ok(()) => Ok(result),
+        Err(error) => Err(ProcessResultsError { error, ok_count, partial: result }),
+    }
+}
@@ -0,0 +1,72 @@
+use std::panic;
+use std::sync::mpsc::{sync_channel, Receiver};
+use std::thread::{self, JoinHandle};
+
+use size_hint;
+
+/// An iterator adaptor that runs the underlying iterator on a background
+/// thread, buffering up to a fixed number of elements ahead of the consumer.
+///
+/// Iterator element type is `T`.
+///
+/// See [`.readahead()`](../trait.Itertools.html#method.readahead) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug)]
+pub struct ReadAhead<T> {
+    receiver: Receiver<T>,
+    producer: Option<JoinHandle<()>>,
+    hint: (usize, Option<usize>),
+    received: usize,
+}
+
+/// Create a new `ReadAhead` iterator, starting its producer thread.
+pub fn readahead<I>(iter: I, capacity: usize) -> ReadAhead<I::Item>
+    where I: Iterator + Send + 'static,
+          I::Item: Send + 'static,
+{
+    let hint = iter.size_hint();
+    let (sender, receiver) = sync_channel(capacity);
+    let producer = thread::spawn(move || {
+        for elt in iter {
+            if sender.send(elt).is_err() {
+                // the consumer was dropped
+                break;
+            }
+        }
+    });
+    ReadAhead {
+        receiver,
+        producer: Some(producer),
+        hint,
+        received: 0,
+    }
+}
+
+impl<T> Iterator for ReadAhead<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.receiver.recv() {
+            Ok(elt) => {
+                self.received += 1;
+                Some(elt)
+            }
+            Err(_) => {
+                // The producer is done: make its panic, if any, ours.
+                if let Some(producer) = self.producer.take() {
+                    if let Err(payload) = producer.join() {
+                        panic::resume_unwind(payload);
+                    }
+                }
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.producer.is_none() {
+            return (0, Some(0));
+        }
+        size_hint::sub_scalar(self.hint, self.received)
+    }
+}
@@ -52,3 +52,8 @@ impl<A> DoubleEndedIterator for RepeatN<A>
 impl<A> ExactSizeIterator for RepeatN<A>
     where A: Clone
 {}
+
+#[cfg(feature = "nightly")]
+unsafe impl<A> ::std::iter::TrustedLen for RepeatN<A>
+    where A: Clone
+{}
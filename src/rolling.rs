@@ -0,0 +1,130 @@
+use std::collections::VecDeque;
+use std::iter::Fuse;
+use std::ops::{Add, Sub};
+
+use grouping_map::ToF64;
+use size_hint;
+
+/// An iterator adaptor that yields the sum of each window of `n`
+/// consecutive elements.
+///
+/// See [`.rolling_sum()`](../trait.Itertools.html#method.rolling_sum) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct RollingSum<I>
+    where I: Iterator
+{
+    iter: Fuse<I>,
+    buf: VecDeque<I::Item>,
+    sum: Option<I::Item>,
+    n: usize,
+}
+
+/// Create a new `RollingSum` iterator.
+pub fn rolling_sum<I>(iter: I, n: usize) -> RollingSum<I>
+    where I: Iterator
+{
+    assert!(n != 0, "window size must be non-zero");
+    RollingSum {
+        iter: iter.fuse(),
+        buf: VecDeque::new(),
+        sum: None,
+        n,
+    }
+}
+
+impl<I> Iterator for RollingSum<I>
+    where I: Iterator,
+          I::Item: Clone + Add<Output = I::Item> + Sub<Output = I::Item>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let elt = self.iter.next()?;
+            // subtract the element leaving the window before adding the
+            // new one, so that the total never holds more than `n` elements
+            let mut sum = self.sum.take();
+            if self.buf.len() == self.n {
+                let old = self.buf.pop_front().unwrap();
+                sum = sum.map(|sum| sum - old);
+            }
+            let sum = match sum {
+                Some(sum) => sum + elt.clone(),
+                None => elt.clone(),
+            };
+            self.buf.push_back(elt);
+            self.sum = Some(sum);
+            if self.buf.len() == self.n {
+                return self.sum.clone();
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let missing = (self.n - 1).saturating_sub(self.buf.len());
+        size_hint::sub_scalar(self.iter.size_hint(), missing)
+    }
+}
+
+impl<I> ExactSizeIterator for RollingSum<I>
+    where I: ExactSizeIterator,
+          I::Item: Clone + Add<Output = I::Item> + Sub<Output = I::Item>,
+{}
+
+/// An iterator adaptor that yields the mean of each window of `n`
+/// consecutive elements, as an `f64`.
+///
+/// See [`.rolling_mean()`](../trait.Itertools.html#method.rolling_mean) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct RollingMean<I> {
+    iter: Fuse<I>,
+    buf: VecDeque<f64>,
+    sum: f64,
+    n: usize,
+}
+
+/// Create a new `RollingMean` iterator.
+pub fn rolling_mean<I>(iter: I, n: usize) -> RollingMean<I>
+    where I: Iterator
+{
+    assert!(n != 0, "window size must be non-zero");
+    RollingMean {
+        iter: iter.fuse(),
+        buf: VecDeque::new(),
+        sum: 0.,
+        n,
+    }
+}
+
+impl<I> Iterator for RollingMean<I>
+    where I: Iterator,
+          I::Item: ToF64,
+{
+    type Item = f64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let elt = self.iter.next()?.to_f64();
+            if self.buf.len() == self.n {
+                self.sum -= self.buf.pop_front().unwrap();
+            }
+            self.sum += elt;
+            self.buf.push_back(elt);
+            if self.buf.len() == self.n {
+                return Some(self.sum / self.n as f64);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let missing = (self.n - 1).saturating_sub(self.buf.len());
+        size_hint::sub_scalar(self.iter.size_hint(), missing)
+    }
+}
+
+impl<I> ExactSizeIterator for RollingMean<I>
+    where I: ExactSizeIterator,
+          I::Item: ToF64,
+{}
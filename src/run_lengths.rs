@@ -0,0 +1,295 @@
+use std::fmt;
+use std::iter::Fuse;
+
+use adaptors::{DedupEq, DedupPredicate};
+use size_hint;
+
+/// An iterator adaptor that yields the key and the length of each run of
+/// consecutive elements with equal keys.
+///
+/// Iterator element type is `(K, usize)`.
+///
+/// This iterator is *fused*.
+///
+/// See [`.run_lengths_by()`](../trait.Itertools.html#method.run_lengths_by) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct RunLengthsBy<I, F, K> {
+    iter: Fuse<I>,
+    f: F,
+    /// The key of the first element of the next run, if already taken
+    pending: Option<K>,
+}
+
+impl<I, F, K> fmt::Debug for RunLengthsBy<I, F, K>
+    where I: Iterator + fmt::Debug,
+          K: fmt::Debug,
+{
+    debug_fmt_fields!(RunLengthsBy, iter, pending);
+}
+
+/// Create a new `RunLengthsBy` iterator.
+pub fn run_lengths_by<I, F, K>(iter: I, f: F) -> RunLengthsBy<I, F, K>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> K,
+          K: PartialEq,
+{
+    RunLengthsBy {
+        iter: iter.fuse(),
+        f,
+        pending: None,
+    }
+}
+
+impl<I, F, K> Iterator for RunLengthsBy<I, F, K>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> K,
+          K: PartialEq,
+{
+    type Item = (K, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = match self.pending.take() {
+            Some(key) => key,
+            None => {
+                let elt = self.iter.next()?;
+                (self.f)(&elt)
+            }
+        };
+        let mut len = 1;
+        for elt in &mut self.iter {
+            let next_key = (self.f)(&elt);
+            if next_key != key {
+                self.pending = Some(next_key);
+                break;
+            }
+            len += 1;
+        }
+        Some((key, len))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = size_hint::add_scalar(self.iter.size_hint(),
+                                                   self.pending.is_some() as usize);
+        ((lower > 0) as usize, upper)
+    }
+}
+
+/// An iterator adaptor that yields the length and the first element of each
+/// run of consecutive elements that compare equal, using a comparison
+/// function.
+///
+/// Iterator element type is `(usize, I::Item)`.
+///
+/// This iterator is *fused*.
+///
+/// See [`.run_length_encode_by()`](../trait.Itertools.html#method.run_length_encode_by)
+/// for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct RunLengthEncodeBy<I, Pred>
+    where I: Iterator
+{
+    iter: Fuse<I>,
+    pred: Pred,
+    /// The first element of the next run, if already taken
+    pending: Option<I::Item>,
+}
+
+/// An iterator adaptor that yields the length and the first element of each
+/// run of consecutive equal elements.
+///
+/// See [`.run_length_encode()`](../trait.Itertools.html#method.run_length_encode)
+/// for more information.
+pub type RunLengthEncode<I> = RunLengthEncodeBy<I, DedupEq>;
+
+impl<I, Pred> fmt::Debug for RunLengthEncodeBy<I, Pred>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(RunLengthEncodeBy, iter, pending);
+}
+
+/// Create a new `RunLengthEncodeBy` iterator.
+pub fn run_length_encode_by<I, Pred>(iter: I, pred: Pred) -> RunLengthEncodeBy<I, Pred>
+    where I: Iterator,
+          Pred: DedupPredicate<I::Item>,
+{
+    RunLengthEncodeBy {
+        iter: iter.fuse(),
+        pred,
+        pending: None,
+    }
+}
+
+/// Create a new `RunLengthEncode` iterator.
+pub fn run_length_encode<I>(iter: I) -> RunLengthEncode<I>
+    where I: Iterator,
+          I::Item: PartialEq,
+{
+    run_length_encode_by(iter, DedupEq)
+}
+
+impl<I, Pred> Iterator for RunLengthEncodeBy<I, Pred>
+    where I: Iterator,
+          Pred: DedupPredicate<I::Item>,
+{
+    type Item = (usize, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = match self.pending.take() {
+            Some(elt) => elt,
+            None => self.iter.next()?,
+        };
+        let mut len = 1;
+        for elt in &mut self.iter {
+            if !self.pred.dedup_pair(&first, &elt) {
+                self.pending = Some(elt);
+                break;
+            }
+            len += 1;
+        }
+        Some((len, first))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = size_hint::add_scalar(self.iter.size_hint(),
+                                                   self.pending.is_some() as usize);
+        ((lower > 0) as usize, upper)
+    }
+}
+
+/// An iterator adaptor that yields the length and the first element of each
+/// run of consecutive elements with equal keys.
+///
+/// Iterator element type is `(usize, I::Item)`.
+///
+/// This iterator is *fused*.
+///
+/// See [`.run_length_encode_by_key()`](../trait.Itertools.html#method.run_length_encode_by_key)
+/// for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct RunLengthEncodeByKey<I, F, K>
+    where I: Iterator
+{
+    iter: Fuse<I>,
+    f: F,
+    /// The first element of the next run and its key, if already taken
+    pending: Option<(K, I::Item)>,
+}
+
+impl<I, F, K> fmt::Debug for RunLengthEncodeByKey<I, F, K>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+          K: fmt::Debug,
+{
+    debug_fmt_fields!(RunLengthEncodeByKey, iter, pending);
+}
+
+/// Create a new `RunLengthEncodeByKey` iterator.
+pub fn run_length_encode_by_key<I, F, K>(iter: I, f: F) -> RunLengthEncodeByKey<I, F, K>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> K,
+          K: PartialEq,
+{
+    RunLengthEncodeByKey {
+        iter: iter.fuse(),
+        f,
+        pending: None,
+    }
+}
+
+impl<I, F, K> Iterator for RunLengthEncodeByKey<I, F, K>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> K,
+          K: PartialEq,
+{
+    type Item = (usize, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, first) = match self.pending.take() {
+            Some(pending) => pending,
+            None => {
+                let elt = self.iter.next()?;
+                ((self.f)(&elt), elt)
+            }
+        };
+        let mut len = 1;
+        for elt in &mut self.iter {
+            let next_key = (self.f)(&elt);
+            if next_key != key {
+                self.pending = Some((next_key, elt));
+                break;
+            }
+            len += 1;
+        }
+        Some((len, first))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = size_hint::add_scalar(self.iter.size_hint(),
+                                                   self.pending.is_some() as usize);
+        ((lower > 0) as usize, upper)
+    }
+}
+
+/// An iterator adaptor that expands each pair `(n, elt)` to `n` copies of
+/// `elt`.
+///
+/// Iterator element type is `T`.
+///
+/// This iterator is *fused*.
+///
+/// See [`.run_length_decode()`](../trait.Itertools.html#method.run_length_decode)
+/// for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct RunLengthDecode<I, T> {
+    iter: Fuse<I>,
+    /// The number of copies left to yield of the current element
+    current: Option<(usize, T)>,
+}
+
+/// Create a new `RunLengthDecode` iterator.
+pub fn run_length_decode<I, T>(iter: I) -> RunLengthDecode<I, T>
+    where I: Iterator<Item = (usize, T)>,
+          T: Clone,
+{
+    RunLengthDecode {
+        iter: iter.fuse(),
+        current: None,
+    }
+}
+
+impl<I, T> Iterator for RunLengthDecode<I, T>
+    where I: Iterator<Item = (usize, T)>,
+          T: Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            match self.current.take() {
+                Some((0, _)) => {}
+                // the last copy is the element itself, it isn't cloned
+                Some((1, elt)) => return Some(elt),
+                Some((n, elt)) => {
+                    let copy = elt.clone();
+                    self.current = Some((n - 1, elt));
+                    return Some(copy);
+                }
+                None => self.current = Some(self.iter.next()?),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let current = self.current.as_ref().map_or(0, |&(n, _)| n);
+        // the pairs left may have any count, including zero
+        match self.iter.size_hint() {
+            (_, Some(0)) => (current, Some(current)),
+            _ => (current, None),
+        }
+    }
+}
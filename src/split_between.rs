@@ -0,0 +1,69 @@
+use std::fmt;
+use std::iter::Fuse;
+
+use size_hint;
+
+/// An iterator adaptor that splits the elements in chunks between the
+/// adjacent pairs for which a predicate returns `true`.
+///
+/// Iterator element type is `Vec<I::Item>`.
+///
+/// See [`.split_between()`](../trait.Itertools.html#method.split_between) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct SplitBetween<I, F>
+    where I: Iterator
+{
+    iter: Fuse<I>,
+    /// The first element of the next chunk, if already taken
+    pending: Option<I::Item>,
+    pred: F,
+}
+
+impl<I, F> fmt::Debug for SplitBetween<I, F>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(SplitBetween, iter, pending);
+}
+
+/// Create a new `SplitBetween` iterator.
+pub fn split_between<I, F>(iter: I, pred: F) -> SplitBetween<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    SplitBetween {
+        iter: iter.fuse(),
+        pending: None,
+        pred,
+    }
+}
+
+impl<I, F> Iterator for SplitBetween<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = match self.pending.take() {
+            Some(elt) => elt,
+            None => self.iter.next()?,
+        };
+        let mut chunk = vec![first];
+        for elt in &mut self.iter {
+            if (self.pred)(&chunk[chunk.len() - 1], &elt) {
+                self.pending = Some(elt);
+                break;
+            }
+            chunk.push(elt);
+        }
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = size_hint::add_scalar(self.iter.size_hint(),
+                                                   self.pending.is_some() as usize);
+        ((lower > 0) as usize, upper)
+    }
+}
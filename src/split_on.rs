@@ -0,0 +1,68 @@
+use std::fmt;
+use std::iter::Fuse;
+
+/// An iterator adaptor that splits the elements in chunks separated by the
+/// elements for which a predicate returns `true`.
+///
+/// Iterator element type is `Vec<I::Item>`.
+///
+/// This iterator is *fused*.
+///
+/// See [`.split_on()`](../trait.Itertools.html#method.split_on) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct SplitOn<I, F> {
+    iter: Fuse<I>,
+    pred: F,
+    /// Whether the last chunk was yielded
+    done: bool,
+}
+
+impl<I, F> fmt::Debug for SplitOn<I, F>
+    where I: fmt::Debug,
+{
+    debug_fmt_fields!(SplitOn, iter, done);
+}
+
+/// Create a new `SplitOn` iterator.
+pub fn split_on<I, F>(iter: I, pred: F) -> SplitOn<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> bool,
+{
+    SplitOn {
+        iter: iter.fuse(),
+        pred,
+        done: false,
+    }
+}
+
+impl<I, F> Iterator for SplitOn<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> bool,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut chunk = Vec::new();
+        for elt in &mut self.iter {
+            if (self.pred)(&elt) {
+                return Some(chunk);
+            }
+            chunk.push(elt);
+        }
+        self.done = true;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        // every element may be a separator
+        let (_, upper) = self.iter.size_hint();
+        (1, upper.and_then(|x| x.checked_add(1)))
+    }
+}
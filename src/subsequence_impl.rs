@@ -0,0 +1,47 @@
+/// Find the start of the first occurrence of `pattern` in `iter`, using the
+/// Knuth–Morris–Pratt algorithm: only the pattern is buffered, and every
+/// element of `iter` is compared a bounded number of times.
+///
+/// See [`.find_subsequence()`](../trait.Itertools.html#method.find_subsequence)
+/// for more information.
+pub fn find_subsequence<I, J>(iter: &mut I, pattern: J) -> Option<usize>
+    where I: Iterator + ?Sized,
+          J: IntoIterator,
+          I::Item: PartialEq<J::Item>,
+          J::Item: PartialEq,
+{
+    let pattern: Vec<_> = pattern.into_iter().collect();
+    if pattern.is_empty() {
+        return Some(0);
+    }
+
+    // fallback[i] is the length of the longest proper prefix of
+    // pattern[..i + 1] that is also a suffix of it.
+    let mut fallback = vec![0; pattern.len()];
+    let mut len = 0;
+    for i in 1..pattern.len() {
+        while len > 0 && pattern[i] != pattern[len] {
+            len = fallback[len - 1];
+        }
+        if pattern[i] == pattern[len] {
+            len += 1;
+        }
+        fallback[i] = len;
+    }
+
+    let mut matched = 0;
+    let mut index = 0usize;
+    for elt in iter {
+        while matched > 0 && elt != pattern[matched] {
+            matched = fallback[matched - 1];
+        }
+        if elt == pattern[matched] {
+            matched += 1;
+            if matched == pattern.len() {
+                return Some(index + 1 - matched);
+            }
+        }
+        index += 1;
+    }
+    None
+}
@@ -2,6 +2,7 @@ use super::size_hint;
 
 use std::cell::RefCell;
 use std::collections::VecDeque;
+use std::fmt;
 use std::rc::Rc;
 
 /// Common buffer object for the two tee halves
@@ -76,3 +77,282 @@ impl<I> ExactSizeIterator for Tee<I>
     where I: ExactSizeIterator,
           I::Item: Clone
 {}
+
+/// What a [`.tee_bounded()`](../trait.Itertools.html#method.tee_bounded) half
+/// does when it would get more than `capacity` elements ahead of the other
+/// half.
+///
+/// The policy no longer applies once the other half is dropped. To get an
+/// error element instead, see
+/// [`.try_tee_bounded()`](../trait.Itertools.html#method.try_tee_bounded).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TeeOverflow {
+    /// Panic.
+    Panic,
+    /// Return `None` until the other half catches up: the half is then not
+    /// fused, it yields elements again once the other half has read some.
+    Pause,
+    /// Drop the oldest element the other half has not read yet: the other
+    /// half skips it.
+    DropOldest,
+}
+
+/// Common buffer object for the two bounded tee halves
+#[derive(Debug)]
+struct TeeBoundedBuffer<A, I> {
+    backlog: VecDeque<A>,
+    iter: I,
+    /// The owner field indicates which id should read from the backlog
+    owner: bool,
+    capacity: usize,
+    overflow: TeeOverflow,
+}
+
+/// One half of an iterator pair where both return the same elements, with
+/// a bounded buffer.
+///
+/// See [`.tee_bounded()`](../trait.Itertools.html#method.tee_bounded) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug)]
+pub struct TeeBounded<I>
+    where I: Iterator
+{
+    rcbuffer: Rc<RefCell<TeeBoundedBuffer<I::Item, I>>>,
+    id: bool,
+}
+
+pub fn new_bounded<I>(iter: I, capacity: usize, overflow: TeeOverflow)
+    -> (TeeBounded<I>, TeeBounded<I>)
+    where I: Iterator
+{
+    assert!(capacity != 0, "tee_bounded: the capacity must be non-zero");
+    let buffer = TeeBoundedBuffer {
+        backlog: VecDeque::with_capacity(capacity),
+        iter,
+        owner: false,
+        capacity,
+        overflow,
+    };
+    let t1 = TeeBounded { rcbuffer: Rc::new(RefCell::new(buffer)), id: true };
+    let t2 = TeeBounded { rcbuffer: t1.rcbuffer.clone(), id: false };
+    (t1, t2)
+}
+
+impl<I> TeeBounded<I>
+    where I: Iterator,
+          I::Item: Clone
+{
+    /// Return the next element, or an error if the policy is `Pause` and
+    /// this half can't get further ahead of the other one.
+    fn try_next(&mut self) -> Option<Result<I::Item, TeeOverflowError>> {
+        let mut buffer = self.rcbuffer.borrow_mut();
+        if buffer.owner == self.id {
+            match buffer.backlog.pop_front() {
+                None => {}
+                some_elt => return some_elt.map(Ok),
+            }
+        }
+        if Rc::strong_count(&self.rcbuffer) == 1 {
+            // the other half was dropped: no one is lagging behind
+            buffer.backlog.clear();
+            return buffer.iter.next().map(Ok);
+        }
+        // this half is ahead, and the backlog of the other one is full
+        let full = buffer.owner != self.id && buffer.backlog.len() >= buffer.capacity;
+        if full && buffer.overflow == TeeOverflow::Pause {
+            return Some(Err(TeeOverflowError { capacity: buffer.capacity }));
+        }
+        let elt = buffer.iter.next()?;
+        if full {
+            if buffer.overflow == TeeOverflow::Panic {
+                panic!("tee_bounded: one half is more than {} elements ahead",
+                       buffer.capacity);
+            }
+            buffer.backlog.pop_front();
+        }
+        buffer.backlog.push_back(elt.clone());
+        buffer.owner = !self.id;
+        Some(Ok(elt))
+    }
+}
+
+impl<I> Iterator for TeeBounded<I>
+    where I: Iterator,
+          I::Item: Clone
+{
+    type Item = I::Item;
+    fn next(&mut self) -> Option<I::Item> {
+        match self.try_next() {
+            Some(Ok(elt)) => Some(elt),
+            Some(Err(_)) | None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffer = self.rcbuffer.borrow();
+        let sh = buffer.iter.size_hint();
+        let sh = if buffer.overflow == TeeOverflow::Panic || Rc::strong_count(&self.rcbuffer) == 1 {
+            sh
+        } else {
+            // the elements of the iterator may not all reach this half
+            (0, sh.1)
+        };
+
+        if buffer.owner == self.id {
+            let log_len = buffer.backlog.len();
+            size_hint::add_scalar(sh, log_len)
+        } else {
+            sh
+        }
+    }
+}
+
+/// The error element of a
+/// [`.try_tee_bounded()`](../trait.Itertools.html#method.try_tee_bounded) half
+/// that would get more than `capacity` elements ahead of the other half.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TeeOverflowError {
+    /// The capacity of the buffer shared by the two halves.
+    pub capacity: usize,
+}
+
+impl fmt::Display for TeeOverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "one half is more than {} elements ahead", self.capacity)
+    }
+}
+
+/// One half of an iterator pair where both return the same elements, with
+/// a bounded buffer, that yields an error instead of getting too far ahead.
+///
+/// See [`.try_tee_bounded()`](../trait.Itertools.html#method.try_tee_bounded) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct TryTeeBounded<I>
+    where I: Iterator
+{
+    tee: TeeBounded<I>,
+}
+
+impl<I> fmt::Debug for TryTeeBounded<I>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(TryTeeBounded, tee);
+}
+
+pub fn new_try_bounded<I>(iter: I, capacity: usize) -> (TryTeeBounded<I>, TryTeeBounded<I>)
+    where I: Iterator
+{
+    let (t1, t2) = new_bounded(iter, capacity, TeeOverflow::Pause);
+    (TryTeeBounded { tee: t1 }, TryTeeBounded { tee: t2 })
+}
+
+impl<I> Iterator for TryTeeBounded<I>
+    where I: Iterator,
+          I::Item: Clone
+{
+    type Item = Result<I::Item, TeeOverflowError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tee.try_next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // any number of errors may come before the next element
+        (0, None)
+    }
+}
+
+/// Common buffer object for the tee handles of `.tee_n()`
+#[derive(Debug)]
+struct TeeNBuffer<A, I> {
+    /// The elements not yet read by every handle, the front one being the
+    /// element of index `start` in the original iterator
+    backlog: VecDeque<A>,
+    iter: I,
+    start: usize,
+    /// The index of the next element of each handle, `!0` once dropped
+    positions: Vec<usize>,
+}
+
+impl<A, I> TeeNBuffer<A, I> {
+    /// Drop the elements every handle has read.
+    fn trim(&mut self) {
+        let min = self.positions.iter().cloned().min().unwrap_or(!0);
+        while self.start < min && self.backlog.pop_front().is_some() {
+            self.start += 1;
+        }
+    }
+}
+
+/// One of several iterators that all return the same elements.
+///
+/// See [`.tee_n()`](../trait.Itertools.html#method.tee_n) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug)]
+pub struct TeeN<I>
+    where I: Iterator
+{
+    rcbuffer: Rc<RefCell<TeeNBuffer<I::Item, I>>>,
+    id: usize,
+}
+
+pub fn new_n<I>(iter: I, n: usize) -> Vec<TeeN<I>>
+    where I: Iterator
+{
+    let buffer = TeeNBuffer {
+        backlog: VecDeque::new(),
+        iter,
+        start: 0,
+        positions: vec![0; n],
+    };
+    let rcbuffer = Rc::new(RefCell::new(buffer));
+    (0..n).map(|id| TeeN { rcbuffer: rcbuffer.clone(), id }).collect()
+}
+
+impl<I> Iterator for TeeN<I>
+    where I: Iterator,
+          I::Item: Clone
+{
+    type Item = I::Item;
+    fn next(&mut self) -> Option<I::Item> {
+        let mut guard = self.rcbuffer.borrow_mut();
+        let buffer = &mut *guard;
+        let index = buffer.positions[self.id] - buffer.start;
+        let found = buffer.backlog.get(index).cloned();
+        let elt = match found {
+            Some(elt) => elt,
+            None => {
+                let elt = buffer.iter.next()?;
+                buffer.backlog.push_back(elt.clone());
+                elt
+            }
+        };
+        buffer.positions[self.id] += 1;
+        buffer.trim();
+        Some(elt)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffer = self.rcbuffer.borrow();
+        let sh = buffer.iter.size_hint();
+        let index = buffer.positions[self.id] - buffer.start;
+        size_hint::add_scalar(sh, buffer.backlog.len() - index)
+    }
+}
+
+impl<I> ExactSizeIterator for TeeN<I>
+    where I: ExactSizeIterator,
+          I::Item: Clone
+{}
+
+impl<I> Drop for TeeN<I>
+    where I: Iterator
+{
+    fn drop(&mut self) {
+        // don't keep the elements this handle didn't read
+        if let Ok(mut buffer) = self.rcbuffer.try_borrow_mut() {
+            buffer.positions[self.id] = !0;
+            buffer.trim();
+        }
+    }
+}
@@ -1,6 +1,6 @@
 //! Some iterator that produces tuples
 
-use std::iter::Fuse;
+use std::iter::{Cycle, Fuse};
 
 /// An iterator over a incomplete tuple.
 ///
@@ -171,6 +171,62 @@ impl<I, T> Iterator for TupleWindows<I, T>
     }
 }
 
+/// An iterator over all windows, wrapping back to the first elements when the
+/// window would otherwise exceed the length of the iterator, producing tuples
+/// of a specific size.
+///
+/// See [`.circular_tuple_windows()`](../trait.Itertools.html#method.circular_tuple_windows)
+/// for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug)]
+pub struct CircularTupleWindows<I, T>
+    where I: Iterator<Item = T::Item> + Clone,
+          T: TupleCollect
+{
+    iter: TupleWindows<Cycle<I>, T>,
+    /// Number of windows left
+    len: usize,
+}
+
+/// Create a new circular tuple windows iterator.
+pub fn circular_tuple_windows<I, T>(iter: I) -> CircularTupleWindows<I, T>
+    where I: ExactSizeIterator<Item = T::Item> + Clone,
+          T: TupleCollect,
+          T::Item: Clone
+{
+    let len = iter.len();
+    CircularTupleWindows {
+        iter: tuple_windows(iter.cycle()),
+        len,
+    }
+}
+
+impl<I, T> Iterator for CircularTupleWindows<I, T>
+    where I: Iterator<Item = T::Item> + Clone,
+          T: TupleCollect + Clone,
+          T::Item: Clone
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<I, T> ExactSizeIterator for CircularTupleWindows<I, T>
+    where I: Iterator<Item = T::Item> + Clone,
+          T: TupleCollect + Clone,
+          T::Item: Clone
+{}
+
 pub trait TupleCollect: Sized {
     type Item;
     type Buffer: Default + AsRef<[Option<Self::Item>]> + AsMut<[Option<Self::Item>]>;
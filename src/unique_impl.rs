@@ -1,5 +1,5 @@
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::collections::hash_map::{Entry};
 use std::hash::Hash;
 use std::fmt;
@@ -132,3 +132,72 @@ pub fn unique<I>(iter: I) -> Unique<I>
         }
     }
 }
+
+/// Keep only the elements of `v` for which `keep_rev` is true, where
+/// `keep_rev` was computed by iterating `v` in reverse.
+fn retain_rev_mask<T>(mut v: Vec<T>, keep_rev: Vec<bool>) -> Vec<T> {
+    let mut keep = keep_rev.into_iter().rev();
+    v.retain(|_| keep.next() == Some(true));
+    v
+}
+
+pub fn unique_last<I>(iter: I) -> Vec<I::Item>
+    where I: Iterator,
+          I::Item: Eq + Hash,
+{
+    let v: Vec<_> = iter.collect();
+    let keep_rev: Vec<bool> = {
+        let mut used = HashSet::with_capacity(v.len());
+        v.iter().rev().map(|elt| used.insert(elt)).collect()
+    };
+    retain_rev_mask(v, keep_rev)
+}
+
+pub fn unique_by_last<I, V, F>(iter: I, mut f: F) -> Vec<I::Item>
+    where I: Iterator,
+          V: Eq + Hash,
+          F: FnMut(&I::Item) -> V,
+{
+    let v: Vec<_> = iter.collect();
+    let keep_rev: Vec<bool> = {
+        let mut used = HashSet::with_capacity(v.len());
+        v.iter().rev().map(|elt| used.insert(f(elt))).collect()
+    };
+    retain_rev_mask(v, keep_rev)
+}
+
+pub fn unique_with_rest<I>(iter: I) -> (Vec<I::Item>, Vec<I::Item>)
+    where I: Iterator,
+          I::Item: Eq + Hash + Clone,
+{
+    let mut used = HashSet::new();
+    let mut unique = Vec::new();
+    let mut rest = Vec::new();
+    for elt in iter {
+        if used.contains(&elt) {
+            rest.push(elt);
+        } else {
+            used.insert(elt.clone());
+            unique.push(elt);
+        }
+    }
+    (unique, rest)
+}
+
+pub fn unique_by_with_rest<I, V, F>(iter: I, mut f: F) -> (Vec<I::Item>, Vec<I::Item>)
+    where I: Iterator,
+          V: Eq + Hash,
+          F: FnMut(&I::Item) -> V,
+{
+    let mut used = HashSet::new();
+    let mut unique = Vec::new();
+    let mut rest = Vec::new();
+    for elt in iter {
+        if used.insert(f(&elt)) {
+            unique.push(elt);
+        } else {
+            rest.push(elt);
+        }
+    }
+    (unique, rest)
+}
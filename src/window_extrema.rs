@@ -0,0 +1,212 @@
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::fmt;
+use std::iter::Fuse;
+
+use size_hint;
+
+/// The state shared by the sliding window adaptors: a deque of the elements
+/// of the current window that may still become its extremum, in order.
+#[derive(Clone, Debug)]
+struct MonotonicWindow<I>
+    where I: Iterator
+{
+    iter: Fuse<I>,
+    deque: VecDeque<(usize, I::Item)>,
+    size: usize,
+    index: usize,
+}
+
+impl<I> MonotonicWindow<I>
+    where I: Iterator
+{
+    fn new(iter: I, size: usize) -> Self {
+        assert!(size != 0, "window size must be non-zero");
+        MonotonicWindow {
+            iter: iter.fuse(),
+            deque: VecDeque::new(),
+            size,
+            index: 0,
+        }
+    }
+
+    /// Advance the window and return the extremum of the window ending with
+    /// the new element; `dominates(new, old)` tells if `old` can be dropped.
+    ///
+    /// Of the elements that are not dropped, the oldest one is yielded, so
+    /// minima drop only greater elements to keep the first of equal ones,
+    /// and maxima drop equal elements too to keep the last one.
+    fn next_by<F>(&mut self, mut dominates: F) -> Option<I::Item>
+        where I::Item: Clone,
+              F: FnMut(&I::Item, &I::Item) -> bool,
+    {
+        loop {
+            let elt = self.iter.next()?;
+            let index = self.index;
+            self.index += 1;
+            while self.deque.back().map_or(false, |back| dominates(&elt, &back.1)) {
+                self.deque.pop_back();
+            }
+            self.deque.push_back((index, elt));
+            while self.deque.front().map_or(false, |front| front.0 + self.size <= index) {
+                self.deque.pop_front();
+            }
+            if index + 1 >= self.size {
+                return self.deque.front().map(|front| front.1.clone());
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // the first full window needs `size - 1` elements before it
+        let missing = (self.size - 1).saturating_sub(self.index);
+        size_hint::sub_scalar(self.iter.size_hint(), missing)
+    }
+}
+
+macro_rules! window_adaptor {
+    ($(#[$attr:meta])* $name:ident, $new:ident, $op:tt) => {
+        $(#[$attr])*
+        #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+        pub struct $name<I>
+            where I: Iterator
+        {
+            window: MonotonicWindow<I>,
+        }
+
+        impl<I> Clone for $name<I>
+            where I: Iterator + Clone,
+                  I::Item: Clone,
+        {
+            fn clone(&self) -> Self {
+                $name { window: self.window.clone() }
+            }
+        }
+
+        impl<I> fmt::Debug for $name<I>
+            where I: Iterator + fmt::Debug,
+                  I::Item: fmt::Debug,
+        {
+            debug_fmt_fields!($name, window);
+        }
+
+        /// Create a new sliding window iterator.
+        pub fn $new<I>(iter: I, n: usize) -> $name<I>
+            where I: Iterator
+        {
+            $name { window: MonotonicWindow::new(iter, n) }
+        }
+
+        impl<I> Iterator for $name<I>
+            where I: Iterator,
+                  I::Item: Clone + Ord,
+        {
+            type Item = I::Item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.window.next_by(|new, old| new $op old)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.window.size_hint()
+            }
+        }
+
+        impl<I> ExactSizeIterator for $name<I>
+            where I: ExactSizeIterator,
+                  I::Item: Clone + Ord,
+        {}
+    }
+}
+
+window_adaptor!{
+    /// An iterator adaptor that yields the minimum of each window of `n`
+    /// consecutive elements.
+    ///
+    /// See [`.window_min()`](../trait.Itertools.html#method.window_min) for more information.
+    WindowMin, window_min, <
+}
+
+window_adaptor!{
+    /// An iterator adaptor that yields the maximum of each window of `n`
+    /// consecutive elements.
+    ///
+    /// See [`.window_max()`](../trait.Itertools.html#method.window_max) for more information.
+    WindowMax, window_max, >=
+}
+
+macro_rules! window_by_adaptor {
+    ($(#[$attr:meta])* $name:ident, $new:ident, $op:tt) => {
+        $(#[$attr])*
+        #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+        pub struct $name<I, F>
+            where I: Iterator
+        {
+            window: MonotonicWindow<I>,
+            compare: F,
+        }
+
+        impl<I, F> Clone for $name<I, F>
+            where I: Iterator + Clone,
+                  I::Item: Clone,
+                  F: Clone,
+        {
+            fn clone(&self) -> Self {
+                $name { window: self.window.clone(), compare: self.compare.clone() }
+            }
+        }
+
+        impl<I, F> fmt::Debug for $name<I, F>
+            where I: Iterator + fmt::Debug,
+                  I::Item: fmt::Debug,
+        {
+            debug_fmt_fields!($name, window);
+        }
+
+        /// Create a new sliding window iterator.
+        pub fn $new<I, F>(iter: I, n: usize, compare: F) -> $name<I, F>
+            where I: Iterator
+        {
+            $name { window: MonotonicWindow::new(iter, n), compare }
+        }
+
+        impl<I, F> Iterator for $name<I, F>
+            where I: Iterator,
+                  I::Item: Clone,
+                  F: FnMut(&I::Item, &I::Item) -> Ordering,
+        {
+            type Item = I::Item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let compare = &mut self.compare;
+                self.window.next_by(|new, old| compare(new, old) $op Ordering::Equal)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.window.size_hint()
+            }
+        }
+
+        impl<I, F> ExactSizeIterator for $name<I, F>
+            where I: ExactSizeIterator,
+                  I::Item: Clone,
+                  F: FnMut(&I::Item, &I::Item) -> Ordering,
+        {}
+    }
+}
+
+window_by_adaptor!{
+    /// An iterator adaptor that yields the minimum of each window of `n`
+    /// consecutive elements, with respect to a comparison function.
+    ///
+    /// See [`.window_min_by()`](../trait.Itertools.html#method.window_min_by) for more information.
+    WindowMinBy, window_min_by, <
+}
+
+window_by_adaptor!{
+    /// An iterator adaptor that yields the maximum of each window of `n`
+    /// consecutive elements, with respect to a comparison function.
+    ///
+    /// See [`.window_max_by()`](../trait.Itertools.html#method.window_max_by) for more information.
+    WindowMaxBy, window_max_by, >=
+}
@@ -1,4 +1,5 @@
-use std::iter::{Fuse,Peekable};
+use std::iter::Fuse;
+use size_hint;
 
 /// An iterator adaptor that wraps each element in an [`Position`](../enum.Position.html).
 ///
@@ -10,7 +11,10 @@ pub struct WithPosition<I>
     where I: Iterator,
 {
     handled_first: bool,
-    peekable: Peekable<Fuse<I>>,
+    handled_last: bool,
+    iter: Fuse<I>,
+    /// The next element from the front, once peeked at
+    peeked: Option<I::Item>,
 }
 
 /// Create a new `WithPosition` iterator.
@@ -19,7 +23,9 @@ pub fn with_position<I>(iter: I) -> WithPosition<I>
 {
     WithPosition {
         handled_first: false,
-        peekable: iter.fuse().peekable(),
+        handled_last: false,
+        iter: iter.fuse(),
+        peeked: None,
     }
 }
 
@@ -51,29 +57,32 @@ impl<T> Position<T> {
     }
 }
 
+impl<T> Position<T> {
+    fn new(is_first: bool, is_last: bool, item: T) -> Self {
+        match (is_first, is_last) {
+            (true, true) => Position::Only(item),
+            (true, false) => Position::First(item),
+            (false, true) => Position::Last(item),
+            (false, false) => Position::Middle(item),
+        }
+    }
+}
+
 impl<I: Iterator> Iterator for WithPosition<I> {
     type Item = Position<I::Item>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.peekable.next() {
+        match self.peeked.take().or_else(|| self.iter.next()) {
             Some(item) => {
-                if !self.handled_first {
-                    // Haven't seen the first item yet, and there is one to give.
-                    self.handled_first = true;
-                    // Peek to see if this is also the last item,
-                    // in which case tag it as `Only`.
-                    match self.peekable.peek() {
-                        Some(_) => Some(Position::First(item)),
-                        None => Some(Position::Only(item)),
-                    }
-                } else {
-                    // Have seen the first item, and there's something left.
-                    // Peek to see if this is the last item.
-                    match self.peekable.peek() {
-                        Some(_) => Some(Position::Middle(item)),
-                        None => Some(Position::Last(item)),
-                    }
+                let is_first = !self.handled_first;
+                self.handled_first = true;
+                // Peek to see if this is the last item, unless the last
+                // item was already taken from the back.
+                if !self.handled_last && self.peeked.is_none() {
+                    self.peeked = self.iter.next();
                 }
+                let is_last = !self.handled_last && self.peeked.is_none();
+                Some(Position::new(is_first, is_last, item))
             }
             // Iterator is finished.
             None => None,
@@ -81,10 +90,36 @@ impl<I: Iterator> Iterator for WithPosition<I> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.peekable.size_hint()
+        size_hint::add_scalar(self.iter.size_hint(), self.peeked.is_some() as usize)
+    }
+}
+
+impl<I> DoubleEndedIterator for WithPosition<I>
+    where I: DoubleEndedIterator + ExactSizeIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // The peeked element comes before all those left in `iter`.
+        match self.iter.next_back().or_else(|| self.peeked.take()) {
+            Some(item) => {
+                let is_last = !self.handled_last;
+                self.handled_last = true;
+                // The length tells if this is also the first item.
+                let is_first = !self.handled_first && self.len() == 0;
+                if is_first {
+                    self.handled_first = true;
+                }
+                Some(Position::new(is_first, is_last, item))
+            }
+            None => None,
+        }
     }
 }
 
 impl<I> ExactSizeIterator for WithPosition<I>
     where I: ExactSizeIterator,
 { }
+
+#[cfg(feature = "nightly")]
+unsafe impl<I> ::std::iter::TrustedLen for WithPosition<I>
+    where I: ::std::iter::TrustedLen,
+{ }
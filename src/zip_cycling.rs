@@ -0,0 +1,94 @@
+use std::cmp;
+
+/// An iterator which iterates two other iterators simultaneously, restarting
+/// the shorter one until the longer one is exhausted.
+///
+/// This iterator is *fused*.
+///
+/// See [`.zip_cycling()`](../trait.Itertools.html#method.zip_cycling) for more information.
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ZipCycling<T, U> {
+    a: T,
+    a_orig: T,
+    a_wrapped: bool,
+    b: U,
+    b_orig: U,
+    b_wrapped: bool,
+}
+
+/// Create a new `ZipCycling` iterator.
+pub fn zip_cycling<T, U>(a: T, b: U) -> ZipCycling<T, U>
+    where T: Iterator + Clone,
+          U: Iterator + Clone
+{
+    ZipCycling {
+        a_orig: a.clone(),
+        a,
+        a_wrapped: false,
+        b_orig: b.clone(),
+        b,
+        b_wrapped: false,
+    }
+}
+
+/// Take the next element of `iter`, restarting it from `orig` if it is exhausted.
+fn next_cycling<T>(iter: &mut T, orig: &T, wrapped: &mut bool) -> Option<T::Item>
+    where T: Iterator + Clone
+{
+    match iter.next() {
+        None => {
+            *wrapped = true;
+            *iter = orig.clone();
+            iter.next()
+        }
+        elt => elt,
+    }
+}
+
+impl<T, U> Iterator for ZipCycling<T, U>
+    where T: Iterator + Clone,
+          U: Iterator + Clone
+{
+    type Item = (T::Item, U::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.a_wrapped && self.b_wrapped {
+            return None;
+        }
+        let a = next_cycling(&mut self.a, &self.a_orig, &mut self.a_wrapped);
+        let b = next_cycling(&mut self.b, &self.b_orig, &mut self.b_wrapped);
+        if !(self.a_wrapped && self.b_wrapped) {
+            if let (Some(a), Some(b)) = (a, b) {
+                return Some((a, b));
+            }
+        }
+        // both sides went around, or one of them is empty
+        self.a_wrapped = true;
+        self.b_wrapped = true;
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match (self.a_wrapped, self.b_wrapped) {
+            (true, true) => (0, Some(0)),
+            (true, false) => self.b.size_hint(),
+            (false, true) => self.a.size_hint(),
+            (false, false) => {
+                let (a_lower, a_upper) = self.a.size_hint();
+                let (b_lower, b_upper) = self.b.size_hint();
+                // a side that looks empty now may be empty from the start
+                let lower = if a_lower == 0 || b_lower == 0 {
+                    0
+                } else {
+                    cmp::max(a_lower, b_lower)
+                };
+                let upper = match (a_upper, b_upper) {
+                    (Some(x), Some(y)) => Some(cmp::max(x, y)),
+                    _ => None,
+                };
+                (lower, upper)
+            }
+        }
+    }
+}
@@ -1,3 +1,7 @@
+use std::fmt;
+
+use either::Either;
+
 use super::size_hint;
 
 /// An iterator which iterates two other iterators simultaneously
@@ -58,3 +62,110 @@ impl<I, J> ExactSizeIterator for ZipEq<I, J>
     where I: ExactSizeIterator,
           J: ExactSizeIterator
 {}
+
+#[cfg(feature = "nightly")]
+unsafe impl<I, J> ::std::iter::TrustedLen for ZipEq<I, J>
+    where I: ::std::iter::TrustedLen,
+          J: ::std::iter::TrustedLen
+{}
+
+/// An iterator which iterates two other iterators simultaneously, and
+/// reports a length mismatch as an error.
+///
+/// See [`.try_zip_eq()`](../trait.Itertools.html#method.try_zip_eq) for more information.
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct TryZipEq<I, J> {
+    a: I,
+    b: J,
+    /// Number of pairs yielded
+    len: usize,
+    done: bool,
+}
+
+/// The error yielded by [`.try_zip_eq()`](trait.Itertools.html#method.try_zip_eq)
+/// when the iterators are not of the same length.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ZipEqError<A, B> {
+    /// The number of pairs yielded before the shorter iterator ran out.
+    pub len: usize,
+    /// The first element of the longer iterator that has no counterpart:
+    /// `Left` if it is the first iterator, `Right` if it is the second one.
+    pub extra: Either<A, B>,
+}
+
+impl<A, B> fmt::Display for ZipEqError<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (longer, shorter) = match self.extra {
+            Either::Left(_) => ("first", "second"),
+            Either::Right(_) => ("second", "first"),
+        };
+        write!(f, "the {} iterator is longer: the {} one ended after {} elements",
+               longer, shorter, self.len)
+    }
+}
+
+/// Iterate `i` and `j` in lock step, yielding an error if they are not of
+/// the same length.
+///
+/// `IntoIterator` enabled version of `i.try_zip_eq(j)`.
+///
+/// ```
+/// use itertools::try_zip_eq;
+///
+/// let sums: Result<Vec<_>, _> = try_zip_eq(&[1, 2, 3], &[4, 5]).map(|r| r.map(|(a, b)| a + b)).collect();
+/// assert_eq!(sums.unwrap_err().len, 2);
+/// ```
+pub fn try_zip_eq<I, J>(i: I, j: J) -> TryZipEq<I::IntoIter, J::IntoIter>
+    where I: IntoIterator,
+          J: IntoIterator
+{
+    TryZipEq {
+        a: i.into_iter(),
+        b: j.into_iter(),
+        len: 0,
+        done: false,
+    }
+}
+
+impl<I, J> Iterator for TryZipEq<I, J>
+    where I: Iterator,
+          J: Iterator
+{
+    type Item = Result<(I::Item, J::Item), ZipEqError<I::Item, J::Item>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let extra = match (self.a.next(), self.b.next()) {
+            (Some(a), Some(b)) => {
+                self.len += 1;
+                return Some(Ok((a, b)));
+            }
+            (None, None) => {
+                self.done = true;
+                return None;
+            }
+            (Some(a), None) => Either::Left(a),
+            (None, Some(b)) => Either::Right(b),
+        };
+        self.done = true;
+        Some(Err(ZipEqError { len: self.len, extra }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        // If the lengths differ, the pairs are followed by the error,
+        // which makes as many elements as the longer iterator at most.
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(x), Some(y)) => Some(::std::cmp::max(x, y)),
+            _ => None,
+        };
+        (::std::cmp::min(a_lower, b_lower), upper)
+    }
+}
@@ -1,3 +1,5 @@
+use std::iter::Fuse;
+
 use super::size_hint;
 
 /// See [`multizip`](../fn.multizip.html) for more information.
@@ -45,6 +47,85 @@ pub fn multizip<T, U>(t: U) -> Zip<T>
     Zip::from(t)
 }
 
+/// See [`multizip_eq`](../fn.multizip_eq.html) for more information.
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ZipEqTuple<T> {
+    t: T,
+}
+
+/// An iterator that generalizes *.zip_eq()* and allows running multiple
+/// iterators of the same length in lockstep.
+///
+/// The iterator `ZipEqTuple<(I, J, ..., M)>` is formed from a tuple of
+/// iterators (or values that implement `IntoIterator`) and yields elements
+/// until all of the subiterators yield `None`.
+///
+/// **Panics** if the subiterators are not all of the same length.
+///
+/// Prefer [`izip_eq!()`] over `multizip_eq` for brevity. Prefer
+/// `multizip_eq` if a nameable type is needed.
+///
+/// [`izip_eq!()`]: macro.izip_eq.html
+///
+/// ```
+/// use itertools::multizip_eq;
+///
+/// let xs = [1, 2, 3];
+/// let ys = [4, 5, 6];
+/// let zs = [7, 8, 9];
+///
+/// let sums: Vec<_> = multizip_eq((&xs, &ys, &zs)).map(|(x, y, z)| x + y + z).collect();
+/// assert_eq!(sums, [12, 15, 18]);
+/// ```
+pub fn multizip_eq<T, U>(t: U) -> ZipEqTuple<T>
+    where ZipEqTuple<T>: From<U>,
+          ZipEqTuple<T>: Iterator,
+{
+    ZipEqTuple::from(t)
+}
+
+/// See [`multizip_longest`](../fn.multizip_longest.html) for more information.
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ZipLongestTuple<T> {
+    t: T,
+}
+
+/// An iterator that generalizes *.zip_longest()* and allows running multiple
+/// iterators in lockstep, until all of them run out.
+///
+/// The iterator `ZipLongestTuple<(Fuse<I>, Fuse<J>, ..., Fuse<M>)>` is
+/// formed from a tuple of iterators (or values that implement
+/// `IntoIterator`) and yields elements until all of the subiterators yield
+/// `None`.
+///
+/// The iterator element type is a tuple like `(Option<A>, Option<B>, ...,
+/// Option<E>)` where `A` to `E` are the element types of the subiterators:
+/// a subiterator that has run out contributes `None`.
+///
+/// Prefer [`izip_longest!()`] over `multizip_longest` for brevity. Prefer
+/// `multizip_longest` if a nameable type is needed.
+///
+/// [`izip_longest!()`]: macro.izip_longest.html
+///
+/// ```
+/// use itertools::multizip_longest;
+///
+/// let names = ["a", "b", "c"];
+/// let xs = [1, 2];
+/// let it = multizip_longest((names.iter().cloned(), xs.iter().cloned(), 0..1));
+/// itertools::assert_equal(it, vec![(Some("a"), Some(1), Some(0)),
+///                                  (Some("b"), Some(2), None),
+///                                  (Some("c"), None, None)]);
+/// ```
+pub fn multizip_longest<T, U>(t: U) -> ZipLongestTuple<T>
+    where ZipLongestTuple<T>: From<U>,
+          ZipLongestTuple<T>: Iterator,
+{
+    ZipLongestTuple::from(t)
+}
+
 macro_rules! impl_zip_iter {
     ($($B:ident),*) => (
         #[allow(non_snake_case)]
@@ -98,6 +179,128 @@ macro_rules! impl_zip_iter {
                 $B: ExactSizeIterator,
             )*
         { }
+
+        #[cfg(feature = "nightly")]
+        #[allow(non_snake_case)]
+        unsafe impl<$($B),*> ::std::iter::TrustedLen for Zip<($($B,)*)> where
+            $(
+                $B: ::std::iter::TrustedLen,
+            )*
+        { }
+
+        #[allow(non_snake_case)]
+        impl<$($B: IntoIterator),*> From<($($B,)*)> for ZipEqTuple<($($B::IntoIter,)*)> {
+            fn from(t: ($($B,)*)) -> Self {
+                let ($($B,)*) = t;
+                ZipEqTuple { t: ($($B.into_iter(),)*) }
+            }
+        }
+
+        #[allow(non_snake_case)]
+        impl<$($B),*> Iterator for ZipEqTuple<($($B,)*)>
+            where
+            $(
+                $B: Iterator,
+            )*
+        {
+            type Item = ($($B::Item,)*);
+
+            fn next(&mut self) -> Option<Self::Item>
+            {
+                let ($(ref mut $B,)*) = self.t;
+
+                // Unlike Zip, every iterator is advanced, so that a length
+                // mismatch is detected whichever iterator ends first.
+                $(
+                    let $B = $B.next();
+                )*
+                match ($($B,)*) {
+                    ($(Some($B),)*) => Some(($($B,)*)),
+                    ($($B,)*) => {
+                        if $($B.is_none())&&* {
+                            None
+                        } else {
+                            panic!("itertools: .multizip_eq() reached end of one iterator before the others")
+                        }
+                    }
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>)
+            {
+                let sh = (::std::usize::MAX, None);
+                let ($(ref $B,)*) = self.t;
+                $(
+                    let sh = size_hint::min($B.size_hint(), sh);
+                )*
+                sh
+            }
+        }
+
+        #[allow(non_snake_case)]
+        impl<$($B),*> ExactSizeIterator for ZipEqTuple<($($B,)*)> where
+            $(
+                $B: ExactSizeIterator,
+            )*
+        { }
+
+        #[cfg(feature = "nightly")]
+        #[allow(non_snake_case)]
+        unsafe impl<$($B),*> ::std::iter::TrustedLen for ZipEqTuple<($($B,)*)> where
+            $(
+                $B: ::std::iter::TrustedLen,
+            )*
+        { }
+
+        #[allow(non_snake_case)]
+        impl<$($B: IntoIterator),*> From<($($B,)*)>
+            for ZipLongestTuple<($(Fuse<$B::IntoIter>,)*)>
+        {
+            fn from(t: ($($B,)*)) -> Self {
+                let ($($B,)*) = t;
+                ZipLongestTuple { t: ($($B.into_iter().fuse(),)*) }
+            }
+        }
+
+        #[allow(non_snake_case)]
+        impl<$($B),*> Iterator for ZipLongestTuple<($(Fuse<$B>,)*)>
+            where
+            $(
+                $B: Iterator,
+            )*
+        {
+            type Item = ($(Option<$B::Item>,)*);
+
+            fn next(&mut self) -> Option<Self::Item>
+            {
+                let ($(ref mut $B,)*) = self.t;
+                $(
+                    let $B = $B.next();
+                )*
+                if $($B.is_none())&&* {
+                    None
+                } else {
+                    Some(($($B,)*))
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>)
+            {
+                let sh = (0, Some(0));
+                let ($(ref $B,)*) = self.t;
+                $(
+                    let sh = size_hint::max($B.size_hint(), sh);
+                )*
+                sh
+            }
+        }
+
+        #[allow(non_snake_case)]
+        impl<$($B),*> ExactSizeIterator for ZipLongestTuple<($(Fuse<$B>,)*)> where
+            $(
+                $B: ExactSizeIterator,
+            )*
+        { }
     );
 }
 
@@ -12,16 +12,23 @@ use std::default::Default;
 
 use quickcheck as qc;
 use std::ops::Range;
-use std::cmp::Ordering;
+use std::cmp::{self, Ordering};
+use std::collections::HashMap;
+use std::iter;
 use itertools::Itertools;
 use itertools::{
     multizip,
+    permutation_indices,
     EitherOrBoth,
+    KeepFirst,
+    KeepLast,
+    SquarePairs,
 };
 use itertools::free::{
     cloned,
     enumerate,
     multipeek,
+    peek_nth,
     put_back,
     put_back_n,
     rciter,
@@ -356,6 +363,29 @@ quickcheck! {
     fn size_product(a: Iter<u16>, b: Iter<u16>) -> bool {
         correct_size_hint(a.cartesian_product(b))
     }
+    fn size_product_cached(a: Iter<u16>, b: Iter<u16>) -> bool {
+        correct_size_hint(a.cartesian_product_cached(b))
+    }
+    fn equal_product_cached(a: Vec<u8>, b: Vec<u8>) -> bool {
+        itertools::equal(a.iter().cartesian_product_cached(b.iter()),
+                         a.iter().cartesian_product(b.iter()))
+    }
+    fn permutation_indices_are_sorted_and_distinct(n: u8, k: u8) -> () {
+        let (n, k) = ((n % 6) as usize, (k % 7) as usize);
+        let perms = permutation_indices(n, k);
+        assert!(exact_size_for_this(perms.clone()));
+        let perms = perms.collect_vec();
+        let count = if k > n { 0 } else { (n - k + 1..n + 1).product() };
+        assert_eq!(perms.len(), count);
+        for perm in &perms {
+            assert_eq!(perm.len(), k);
+            assert!(perm.iter().all(|&i| i < n));
+            assert_eq!(perm.iter().unique().count(), k);
+        }
+        for pair in perms.windows(2) {
+            assert!(pair[0] < pair[1]);
+        }
+    }
     fn size_product3(a: Iter<u16>, b: Iter<u16>, c: Iter<u16>) -> bool {
         correct_size_hint(iproduct!(a, b, c))
     }
@@ -453,6 +483,25 @@ quickcheck! {
         exact_size(it)
     }
 
+    fn size_peek_nth(a: Iter<u16, Exact>, s: u8) -> bool {
+        let mut it = peek_nth(a);
+        // peek a few times
+        for n in 0..s {
+            it.peek_nth(n as usize);
+        }
+        exact_size(it)
+    }
+
+    fn size_drop_last(a: Iter<u16, Exact>, n: u8) -> bool {
+        exact_size(a.drop_last(n as usize))
+    }
+
+    fn equal_drop_last(a: Vec<u16>, n: u8) -> bool {
+        let n = n as usize;
+        let keep = a.len().saturating_sub(n);
+        itertools::equal(a.iter().drop_last(n), &a[..keep])
+    }
+
     fn equal_merge(a: Vec<i16>, b: Vec<i16>) -> bool {
         let mut sa = a.clone();
         let mut sb = b.clone();
@@ -481,6 +530,21 @@ quickcheck! {
         correct_size_hint(izip!(filt, b.clone(), c.clone())) &&
             exact_size(izip!(a, b, c))
     }
+
+    fn size_zip_longest_macro(a: Iter<i16, Exact>, b: Iter<i16, Exact>, c: Iter<i16, Exact>) -> bool {
+        let filt = a.clone().dedup();
+        correct_size_hint(izip_longest!(filt, b.clone(), c.clone())) &&
+            exact_size(izip_longest!(a, b, c))
+    }
+
+    fn equal_zip_longest_macro(a: Vec<u8>, b: Vec<u8>) -> bool {
+        let expected = a.iter().zip_longest(&b).map(|elt| match elt {
+            EitherOrBoth::Both(x, y) => (Some(x), Some(y)),
+            EitherOrBoth::Left(x) => (Some(x), None),
+            EitherOrBoth::Right(y) => (None, Some(y)),
+        });
+        itertools::equal(izip_longest!(&a, &b), expected)
+    }
     fn equal_kmerge(a: Vec<i16>, b: Vec<i16>, c: Vec<i16>) -> bool {
         use itertools::free::kmerge;
         let mut sa = a.clone();
@@ -509,6 +573,46 @@ quickcheck! {
     }
 
     // Any number of input iterators
+    fn equal_kmerge_dedup(mut inputs: Vec<Vec<i16>>) -> bool {
+        for input in &mut inputs {
+            input.sort();
+        }
+        let mut merged = inputs.concat();
+        merged.sort();
+        merged.dedup();
+        itertools::equal(merged.into_iter(), inputs.into_iter().kmerge_dedup())
+    }
+
+    fn size_kmerge_dedup(a: Iter<i16>, b: Iter<i16>, c: Iter<i16>) -> bool {
+        correct_size_hint(vec![a, b, c].into_iter().kmerge_dedup())
+    }
+
+    fn equal_try_kmerge_by(mut inputs: Vec<Vec<i16>>) -> bool {
+        for input in &mut inputs {
+            input.sort();
+        }
+        let merged = inputs.clone().into_iter().kmerge().map(Ok::<_, ()>);
+        itertools::equal(merged, inputs.into_iter().try_kmerge_by(|x, y| Ok(x < y)))
+    }
+
+    fn try_kmerge_by_stops_at_error(mut inputs: Vec<Vec<i16>>, bad: i16) -> bool {
+        for input in &mut inputs {
+            input.sort();
+        }
+        // comparing with `bad` fails
+        let merged = inputs.into_iter()
+            .try_kmerge_by(|&x, &y| if x == bad || y == bad { Err(()) } else { Ok(x < y) })
+            .collect_vec();
+        let oks = merged.iter().take_while(|r| r.is_ok()).count();
+        oks + 1 >= merged.len() &&
+            merged[..oks].windows(2).all(|w| w[0] <= w[1])
+    }
+
+    fn size_try_kmerge_by(a: Iter<i16>, b: Iter<i16>, c: Iter<i16>, bad: i16) -> bool {
+        correct_size_hint(vec![a, b, c].into_iter()
+            .try_kmerge_by(|&x, &y| if x == bad { Err(()) } else { Ok(x < y) }))
+    }
+
     fn equal_kmerge_by_ge(mut inputs: Vec<Vec<i16>>) -> bool {
         // sort the inputs
         for input in &mut inputs {
@@ -555,6 +659,23 @@ quickcheck! {
         let b = &b[..len];
         itertools::equal(zip_eq(a, b), zip(a, b))
     }
+    fn correct_try_zip_eq(a: Vec<i32>, b: Vec<i32>) -> bool {
+        let len = std::cmp::min(a.len(), b.len());
+        let mut it = a.iter().try_zip_eq(&b);
+        let pairs_ok = itertools::equal(it.by_ref().take(len).map(Result::unwrap), zip(&a, &b));
+        let end_ok = match it.next() {
+            None => a.len() == b.len(),
+            Some(Err(err)) => err.len == len && a.len() != b.len() && match err.extra {
+                itertools::Either::Left(&x) => x == a[len],
+                itertools::Either::Right(&y) => y == b[len],
+            },
+            Some(Ok(_)) => false,
+        };
+        pairs_ok && end_ok && it.next().is_none()
+    }
+    fn size_try_zip_eq(a: Iter<i16>, b: Iter<i16>) -> bool {
+        correct_size_hint(a.try_zip_eq(b))
+    }
     fn size_zip_longest(a: Iter<i16, Exact>, b: Iter<i16, Exact>) -> bool {
         let filt = a.clone().dedup();
         let filt2 = b.clone().dedup();
@@ -562,6 +683,14 @@ quickcheck! {
         correct_size_hint(a.clone().zip_longest(filt2)) &&
             exact_size(a.zip_longest(b))
     }
+    fn size_zip_cycling(a: Iter<i16>, b: Iter<i16>) -> bool {
+        correct_size_hint(a.zip_cycling(b))
+    }
+    fn equal_zip_cycling(a: Vec<u8>, b: Vec<u8>) -> bool {
+        let len = if a.is_empty() || b.is_empty() { 0 } else { cmp::max(a.len(), b.len()) };
+        itertools::equal(a.iter().zip_cycling(&b),
+                         a.iter().cycle().zip(b.iter().cycle()).take(len))
+    }
     fn size_2_zip_longest(a: Iter<i16>, b: Iter<i16>) -> bool {
         let it = a.clone().zip_longest(b.clone());
         let jt = a.clone().zip_longest(b.clone());
@@ -593,6 +722,79 @@ quickcheck! {
     fn exact_interleave_shortest(a: Vec<()>, b: Vec<()>) -> bool {
         exact_size_for_this(a.iter().interleave_shortest(&b))
     }
+    fn size_multi_interleave(a: Iter<i16>, b: Iter<i16>, c: Iter<i16>) -> bool {
+        correct_size_hint(vec![a, b, c].into_iter().multi_interleave())
+    }
+    fn equal_multi_interleave(a: Vec<u8>, b: Vec<u8>, c: Vec<u8>) -> bool {
+        let expected = vec![(a.clone(), 1), (b.clone(), 1), (c.clone(), 1)].into_iter()
+            .multi_interleave_weighted();
+        itertools::equal(vec![a, b, c].into_iter().multi_interleave(), expected)
+    }
+    fn size_multi_interleave_shortest(a: Iter<i16>, b: Iter<i16>, c: Iter<i16>) -> bool {
+        correct_size_hint(vec![a, b, c].into_iter().multi_interleave_shortest())
+    }
+    fn equal_multi_interleave_shortest(a: Vec<u8>, b: Vec<u8>) -> bool {
+        itertools::equal(vec![a.clone(), b.clone()].into_iter().multi_interleave_shortest(),
+                         a.into_iter().interleave_shortest(b))
+    }
+    fn size_interleave_weighted(a: Iter<i16>, b: Iter<i16>, wa: u8, wb: u8) -> bool {
+        let (wa, wb) = (wa as usize % 4 + 1, wb as usize % 4 + 1);
+        correct_size_hint(a.interleave_weighted(b, wa, wb))
+    }
+    fn equal_interleave_weighted(a: Vec<u8>, b: Vec<u8>, wa: u8, wb: u8) -> bool {
+        let (wa, wb) = (wa as usize % 4 + 1, wb as usize % 4 + 1);
+        let expected = vec![(a.clone(), wa), (b.clone(), wb)].into_iter()
+            .multi_interleave_weighted();
+        itertools::equal(a.into_iter().interleave_weighted(b, wa, wb), expected)
+    }
+    fn equal_multi_interleave_weighted_1(a: Vec<u8>, b: Vec<u8>) -> bool {
+        itertools::equal(vec![(a.clone(), 1), (b.clone(), 1)].into_iter().multi_interleave_weighted(),
+                         a.into_iter().interleave(b))
+    }
+    fn size_multi_interleave_weighted(a: Iter<i16>, b: Iter<i16>, c: Iter<i16>) -> bool {
+        correct_size_hint(vec![(a, 1), (b, 2), (c, 3)].into_iter().multi_interleave_weighted())
+    }
+    fn size_stride(a: Iter<i16>, offset: u8, step: u8) -> bool {
+        correct_size_hint(a.stride(offset as usize, step as usize % 8 + 1))
+    }
+    fn exact_size_stride(a: Iter<i16, Exact>, offset: u8, step: u8) -> bool {
+        exact_size(a.stride(offset as usize, step as usize % 8 + 1))
+    }
+    fn equal_stride(a: Vec<u8>, offset: u8, step: u8, n: u8) -> bool {
+        let (offset, step, n) = (offset as usize % 16, step as usize % 8 + 1, n as usize % 8);
+        let expected = a.iter().skip(offset).enumerate()
+            .filter(|&(i, _)| i % step == 0).map(|(_, x)| x).collect_vec();
+        itertools::equal(a.iter().stride(offset, step), expected.iter().cloned()) &&
+            a.iter().stride(offset, step).nth(n) == expected.get(n).cloned() &&
+            {
+                let mut it = a.iter().stride(offset, step);
+                it.nth(n);
+                itertools::equal(it, expected.iter().cloned().skip(n + 1))
+            }
+    }
+    fn size_peek_window(a: Iter<i16>, peeks: Vec<u8>) -> bool {
+        let mut it = a.peek_window::<[_; 4]>();
+        for &i in &peeks {
+            it.peek(i as usize % 4);
+        }
+        correct_size_hint(it)
+    }
+    fn equal_peek_window(a: Vec<u8>, ops: Vec<u8>) -> bool {
+        // check the peeks against the elements of `a`, while advancing
+        let mut it = a.iter().peek_window::<[_; 3]>();
+        let mut pos = 0;
+        for &op in &ops {
+            if op % 4 == 3 {
+                if it.next() != a.get(pos) {
+                    return false;
+                }
+                pos += 1;
+            } else if it.peek(op as usize % 4) != a.get(pos + op as usize % 4).as_ref() {
+                return false;
+            }
+        }
+        itertools::equal(it, a.iter().skip(pos))
+    }
     fn size_intersperse(a: Iter<i16>, x: i16) -> bool {
         correct_size_hint(a.intersperse(x))
     }
@@ -610,6 +812,151 @@ quickcheck! {
         }
         true
     }
+    fn size_intersperse_every(a: Iter<i16>, n: u8, x: i16) -> bool {
+        correct_size_hint(a.intersperse_every(n as usize + 1, x))
+    }
+    fn equal_intersperse_every(a: Vec<i32>, n: u8, x: i32) -> bool {
+        let n = n as usize + 1;
+        let expected = a.chunks(n).map(|c| c.to_vec()).intersperse(vec![x]).concat();
+        itertools::equal(a.iter().cloned().intersperse_every(n, x), expected)
+    }
+
+    fn equal_find_subsequence(a: Vec<u8>, pattern: Vec<u8>) -> bool {
+        // small alphabet, so that matches and partial matches are common
+        let a: Vec<_> = a.iter().map(|x| x % 3).collect();
+        let pattern: Vec<_> = pattern.iter().take(6).map(|x| x % 3).collect();
+        let expected = if pattern.is_empty() {
+            Some(0)
+        } else {
+            a.windows(pattern.len()).position(|w| w == &pattern[..])
+        };
+        let mut it = a.iter().cloned();
+        let found = it.find_subsequence(pattern.iter().cloned());
+        let rest = expected.map_or(0, |i| a.len() - i - pattern.len());
+        found == expected && it.count() == rest
+    }
+
+    fn equal_window_min_max(a: Vec<u8>, n: u8) -> bool {
+        let n = n as usize % 10 + 1;
+        itertools::equal(a.iter().window_min(n),
+                         a.windows(n).map(|w| w.iter().min().unwrap())) &&
+        itertools::equal(a.iter().window_max(n),
+                         a.windows(n).map(|w| w.iter().max().unwrap()))
+    }
+
+    fn equal_window_min_max_by(a: Vec<u8>, n: u8) -> bool {
+        let n = n as usize % 10 + 1;
+        fn key(x: &&u8) -> u8 { **x % 7 }
+        itertools::equal(a.iter().window_min_by(n, |x, y| key(x).cmp(&key(y))),
+                         a.windows(n).map(|w| w.iter().min_by_key(key).unwrap())) &&
+        itertools::equal(a.iter().window_max_by(n, |x, y| key(x).cmp(&key(y))),
+                         a.windows(n).map(|w| w.iter().max_by_key(key).unwrap()))
+    }
+
+    fn equal_cumsum(a: Vec<u8>) -> bool {
+        let a: Vec<u32> = a.into_iter().map(u32::from).collect();
+        itertools::equal(a.iter().cloned().cumsum(),
+                         (1..a.len() + 1).map(|i| a[..i].iter().sum::<u32>()))
+    }
+
+    fn equal_diffs(a: Vec<i16>) -> bool {
+        let a: Vec<i32> = a.into_iter().map(i32::from).collect();
+        itertools::equal(a.iter().cloned().diffs(),
+                         a.windows(2).map(|w| w[1] - w[0]))
+    }
+
+    fn size_diffs(a: Iter<i16, Exact>) -> bool {
+        exact_size(a.diffs_by(|a, b| (*a, *b)))
+    }
+
+    fn equal_rolling_sum(a: Vec<u8>, n: u8) -> bool {
+        let n = n as usize % 10 + 1;
+        let a: Vec<i32> = a.into_iter().map(i32::from).collect();
+        itertools::equal(a.iter().cloned().rolling_sum(n),
+                         a.windows(n).map(|w| w.iter().sum::<i32>()))
+    }
+
+    fn size_rolling_mean(a: Iter<u8, Exact>, n: u8) -> bool {
+        exact_size(a.map(f64::from).rolling_mean(n as usize % 10 + 1))
+    }
+
+    fn size_window_min(a: Iter<u8, Exact>, n: u8) -> bool {
+        exact_size(a.window_min(n as usize % 10 + 1))
+    }
+
+    fn equal_cartesian_square(a: Vec<u8>, pairs: u8) -> bool {
+        let pairs = [SquarePairs::Ordered, SquarePairs::OrderedDistinct,
+                     SquarePairs::Unordered, SquarePairs::UnorderedDistinct][pairs as usize % 4];
+        let a = &a[..cmp::min(a.len(), 20)];
+        let expected = a.iter().enumerate().cartesian_product(a.iter().enumerate())
+            .filter(|&((i, _), (j, _))| match pairs {
+                SquarePairs::Ordered => true,
+                SquarePairs::OrderedDistinct => i != j,
+                SquarePairs::Unordered => i <= j,
+                SquarePairs::UnorderedDistinct => i < j,
+            })
+            .map(|((_, x), (_, y))| (x, y));
+        let it = a.iter().cartesian_square_with(pairs);
+        exact_size_for_this(it.clone()) && itertools::equal(it, expected)
+    }
+
+    fn size_cartesian_square(a: Iter<u16>, pairs: u8) -> bool {
+        let pairs = [SquarePairs::Ordered, SquarePairs::OrderedDistinct,
+                     SquarePairs::Unordered, SquarePairs::UnorderedDistinct][pairs as usize % 4];
+        correct_size_hint(a.take(20).cartesian_square_with(pairs))
+    }
+
+    fn equal_run_lengths_by(a: Vec<u8>) -> bool {
+        let expected = a.iter().group_by(|&&x| x / 64).into_iter()
+            .map(|(key, group)| (key, group.count()))
+            .collect::<Vec<_>>();
+        itertools::equal(a.iter().run_lengths_by(|&&x| x / 64), expected)
+    }
+
+    fn size_run_lengths_by(a: Iter<u8>) -> bool {
+        correct_size_hint(a.run_lengths_by(|&x| x / 64))
+    }
+
+    fn equal_run_length_encode(a: Vec<u8>) -> bool {
+        let a: Vec<u8> = a.into_iter().map(|x| x / 64).collect();
+        let expected = a.iter().group_by(|&&x| x).into_iter()
+            .map(|(_, group)| {
+                let group = group.collect::<Vec<_>>();
+                (group.len(), group[0])
+            })
+            .collect::<Vec<_>>();
+        itertools::equal(a.iter().run_length_encode(), expected)
+    }
+
+    fn equal_run_length_encode_by_key(a: Vec<u8>) -> bool {
+        let expected = a.iter().group_by(|&&x| x / 64).into_iter()
+            .map(|(_, group)| {
+                let group = group.collect::<Vec<_>>();
+                (group.len(), group[0])
+            })
+            .collect::<Vec<_>>();
+        itertools::equal(a.iter().run_length_encode_by_key(|&&x| x / 64), expected.clone()) &&
+        itertools::equal(a.iter().run_length_encode_by(|&&x, &&y| x / 64 == y / 64), expected)
+    }
+
+    fn size_run_length_encode(a: Iter<u8>) -> bool {
+        correct_size_hint(a.map(|x| x / 64).run_length_encode())
+    }
+
+    fn equal_run_length_decode(a: Vec<(u8, u8)>) -> bool {
+        let expected = a.iter().flat_map(|&(n, x)| iter::repeat(x).take(n as usize % 5))
+            .collect::<Vec<_>>();
+        itertools::equal(a.iter().map(|&(n, x)| (n as usize % 5, x)).run_length_decode(), expected)
+    }
+
+    fn run_length_round_trip(a: Vec<u8>) -> bool {
+        let a: Vec<u8> = a.into_iter().map(|x| x / 64).collect();
+        itertools::equal(a.iter().run_length_encode().run_length_decode(), &a)
+    }
+
+    fn size_run_length_decode(a: Iter<u8>) -> bool {
+        correct_size_hint(a.map(|x| (x as usize % 5, x)).run_length_decode())
+    }
 
     fn equal_combinations_2(a: Vec<u8>) -> bool {
         let mut v = Vec::new();
@@ -758,6 +1105,31 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    fn size_pad_front(it: Iter<i8, Exact>, pad: u8) -> bool {
+        exact_size(it.clone().pad_front_using(pad as usize, |_| 0)) &&
+            exact_size(it.pad_both_using(pad as usize, |_| 0))
+    }
+
+    fn correct_pad_front(v: Vec<i8>, pad: u8) -> bool {
+        let pad = pad as usize;
+        let mut expected = v.iter().rev().cloned().pad_using(pad, |_| 0).collect_vec();
+        expected.reverse();
+        itertools::equal(v.iter().cloned().pad_front_using(pad, |_| 0), expected)
+    }
+
+    fn correct_pad_both(v: Vec<i8>, pad: u8) -> bool {
+        let pad = pad as usize;
+        let padded = v.iter().cloned().pad_both_using(pad, |i| i as i8).collect_vec();
+        let front = pad.saturating_sub(v.len()) / 2;
+        padded.len() == cmp::max(pad, v.len()) &&
+            padded[front..front + v.len()] == v[..] &&
+            padded.iter().enumerate()
+                .filter(|&(i, _)| i < front || i >= front + v.len())
+                .all(|(i, &x)| x == i as i8)
+    }
+}
+
 quickcheck! {
     fn size_unique(it: Iter<i8>) -> bool {
         correct_size_hint(it.unique())
@@ -774,6 +1146,151 @@ quickcheck! {
         let rest_count = iter.count();
         assert_eq!(answer, first_count + rest_count);
     }
+
+    fn equal_unique_last(it: Vec<i8>) -> bool {
+        let mut expected = it.iter().rev().unique().collect_vec();
+        expected.reverse();
+        itertools::equal(it.iter().unique_last(), expected)
+    }
+
+    fn equal_partition_lazy(it: Vec<i8>, order: Vec<bool>) -> bool {
+        let (mut pos, mut neg) = it.iter().partition_lazy(|&&x| x >= 0);
+        let (mut got_pos, mut got_neg): (Vec<&i8>, Vec<&i8>) = (Vec::new(), Vec::new());
+        // interleave the reads of both sides in an arbitrary order
+        for read_pos in order {
+            if read_pos {
+                got_pos.extend(pos.next());
+            } else {
+                got_neg.extend(neg.next());
+            }
+        }
+        got_pos.extend(pos);
+        got_neg.extend(neg);
+        let (exp_pos, exp_neg): (Vec<&i8>, Vec<&i8>) = it.iter().partition(|&&x| x >= 0);
+        got_pos == exp_pos && got_neg == exp_neg
+    }
+
+    fn size_partition_lazy(a: Iter<i16>) -> bool {
+        let (pos, neg) = a.partition_lazy(|&x| x >= 0);
+        correct_size_hint(pos) && correct_size_hint(neg)
+    }
+
+    fn equal_unique_with_rest(it: Vec<i8>) -> bool {
+        let (unique, rest) = it.iter().unique_with_rest();
+        let expected_rest = it.iter().duplicate_positions().map(|(_, _, x)| x).collect_vec();
+        unique == it.iter().unique().collect_vec() && rest == expected_rest
+    }
+
+    fn correct_k_smallest(it: Vec<i8>, k: u8) -> bool {
+        let k = k as usize % 16;
+        let expected = it.iter().sorted().take(k).collect_vec();
+        it.iter().k_smallest(k).collect_vec() == expected
+    }
+
+    fn correct_k_largest_by_key(it: Vec<i8>, k: u8) -> bool {
+        let k = k as usize % 16;
+        fn key(x: &&i8) -> i16 { (**x as i16).abs() }
+        let expected = it.iter().map(|x| key(&x)).sorted().rev().take(k).collect_vec();
+        it.iter().k_largest_by_key(k, key).map(|x| key(&x)).collect_vec() == expected
+    }
+
+    fn correct_take_while_inclusive(it: Vec<i8>, threshold: i8) -> bool {
+        let taken = it.iter().take_while_inclusive(|&&x| x < threshold).collect_vec();
+        let head = it.iter().take_while(|&&x| x < threshold).count();
+        let expected = &it[..cmp::min(head + 1, it.len())];
+        itertools::equal(taken, expected)
+    }
+
+    fn size_take_while_inclusive(it: Iter<i8>, threshold: i8) -> bool {
+        correct_size_hint(it.take_while_inclusive(|&x| x < threshold))
+    }
+
+    fn correct_split_between(it: Vec<i8>) -> bool {
+        let chunks = it.iter().split_between(|a, b| a > b).collect_vec();
+        chunks.iter().all(|chunk| !chunk.is_empty() && chunk.windows(2).all(|w| w[0] <= w[1])) &&
+            chunks.windows(2).all(|w| w[0][w[0].len() - 1] > w[1][0]) &&
+            itertools::equal(chunks.into_iter().flat_map(|c| c), it.iter())
+    }
+
+    fn size_split_between(it: Iter<i8>) -> bool {
+        correct_size_hint(it.split_between(|a, b| a > b))
+    }
+
+    fn equal_split_on(it: Vec<i8>) -> bool {
+        let it: Vec<i8> = it.into_iter().map(|x| x % 4).collect();
+        let chunks = it.split(|&x| x == 0).map(|chunk| chunk.to_vec());
+        itertools::equal(it.iter().cloned().split_on(|&x| x == 0), chunks)
+    }
+
+    fn size_split_on(it: Iter<i8>) -> bool {
+        correct_size_hint(it.split_on(|&x| x % 4 == 0))
+    }
+
+    fn correct_counts(it: Vec<i8>) -> bool {
+        let counts = it.iter().counts();
+        counts.values().sum::<usize>() == it.len() &&
+            counts.iter().all(|(x, &count)| count == it.iter().filter(|&y| y == *x).count())
+    }
+
+    fn correct_counts_by(it: Vec<i8>) -> bool {
+        let counts = it.iter().counts_by(|x| x % 3);
+        counts == it.iter().map(|x| x % 3).counts()
+    }
+
+    fn correct_combinations_with_replacement(it: Vec<i8>) -> bool {
+        let it = &it[..it.len() % 16];
+        let expected = (0..it.len()).cartesian_product(0..it.len())
+            .filter(|&(i, j)| i <= j)
+            .map(|(i, j)| vec![&it[i], &it[j]]);
+        itertools::equal(it.iter().combinations_with_replacement(2), expected)
+    }
+
+    fn correct_permutations(it: Vec<i8>, k: u8) -> bool {
+        let it = &it[..it.len() % 7];
+        let k = k as usize % 5;
+        let expected = itertools::permutation_indices(it.len(), k)
+            .map(|perm| perm.into_iter().map(|i| &it[i]).collect_vec());
+        itertools::equal(it.iter().permutations(k), expected)
+    }
+
+    fn size_permutations(it: Vec<i8>, k: u8) -> bool {
+        let it = &it[..it.len() % 7];
+        correct_size_hint(it.iter().permutations(k as usize % 5))
+    }
+
+    fn correct_powerset(it: Vec<i8>) -> bool {
+        let it = &it[..it.len() % 8];
+        // `.combinations(0)` of an empty source yields nothing
+        let mut expected = vec![vec![]];
+        for k in 1..it.len() + 1 {
+            expected.extend(it.iter().combinations(k));
+        }
+        it.iter().powerset().count() == 1 << it.len() &&
+            itertools::equal(it.iter().powerset(), expected)
+    }
+
+    fn size_duplicates(it: Iter<i8>) -> bool {
+        correct_size_hint(it.duplicates())
+    }
+
+    fn equal_duplicates(it: Vec<i8>) -> bool {
+        let expected = it.iter().duplicate_positions()
+            .map(|(_, _, x)| x).unique().collect_vec();
+        itertools::equal(it.iter().duplicates(), expected)
+    }
+
+    fn equal_duplicates_by(it: Vec<i8>) -> bool {
+        fn abs(x: &i8) -> i16 { (*x as i16).abs() }
+        let duplicates = it.iter().duplicates_by(|x| abs(x)).collect_vec();
+        let keys = it.iter().map(abs).collect_vec();
+        itertools::equal(duplicates.into_iter().map(abs), keys.iter().duplicates().cloned())
+    }
+
+    fn equal_unique_by_with_rest(it: Vec<i8>) -> bool {
+        let (unique, rest) = it.iter().unique_by_with_rest(|x| x.abs());
+        unique.len() + rest.len() == it.len() &&
+            unique == it.iter().unique_by(|x| x.abs()).collect_vec()
+    }
 }
 
 quickcheck! {
@@ -850,6 +1367,47 @@ quickcheck! {
         }
         true
     }
+
+    fn equal_chunks_nth(a: Vec<u8>, size: u8, steps: Vec<(u8, u8)>) -> bool {
+        let size = size as usize % 8 + 1;
+        let expected: Vec<_> = a.chunks(size).collect();
+        let chunks = a.iter().chunks(size);
+        let mut it = chunks.into_iter();
+        let mut pos = 0;
+        let mut kept = Vec::new();
+        for &(skip, take) in &steps {
+            pos += skip as usize % 4;
+            let mut chunk = match it.nth(skip as usize % 4) {
+                Some(chunk) => chunk,
+                None => return pos >= expected.len(),
+            };
+            if pos >= expected.len() {
+                return false;
+            }
+            // consume part of the chunk, and keep some chunks alive
+            let take = cmp::min(take as usize % (size + 1), expected[pos].len());
+            if !itertools::equal(chunk.by_ref().take(take), &expected[pos][..take]) {
+                return false;
+            }
+            if take % 2 == 0 {
+                kept.push((pos, take, chunk));
+            }
+            pos += 1;
+            if it.len() != expected.len().saturating_sub(pos) {
+                return false;
+            }
+        }
+        let rest_ok = kept.into_iter().all(|(pos, take, chunk)| {
+            itertools::equal(chunk, &expected[pos][take..])
+        });
+        rest_ok
+    }
+
+    fn size_chunks(a: Iter<u8, Exact>, size: u8) -> bool {
+        let chunks = a.chunks(size as usize % 8 + 1);
+        let result = exact_size(chunks.into_iter());
+        result
+    }
 }
 
 quickcheck! {
@@ -877,6 +1435,26 @@ quickcheck! {
         itertools::equal(x, y)
     }
 
+    fn equal_circular_tuple_windows_3(a: Vec<u8>) -> bool {
+        let n = a.len();
+        let x = (0..n).map(|i| (&a[i], &a[(i + 1) % n], &a[(i + 2) % n]));
+        let y = a.iter().circular_tuple_windows::<(_, _, _)>();
+        itertools::equal(x, y)
+    }
+
+    fn equal_array_windows_3(a: Vec<u8>) -> bool {
+        let x = a.windows(3);
+        let y = a.iter().cloned().array_windows::<[_; 3]>().collect_vec();
+        itertools::equal(x, y.iter().map(|window| &window[..]))
+    }
+
+    fn equal_array_chunks_3(a: Vec<u8>) -> bool {
+        let mut y = a.iter().cloned().array_chunks::<[_; 3]>();
+        let x = a.chunks(3).filter(|s| s.len() == 3).collect_vec();
+        itertools::equal(x, y.by_ref().collect_vec().iter().map(|chunk| &chunk[..])) &&
+            y.remainder() == &a[a.len() / 3 * 3..]
+    }
+
     fn equal_tuples_1(a: Vec<u8>) -> bool {
         let x = a.chunks(1).map(|s| (&s[0], ));
         let y = a.iter().tuples::<(_,)>();
@@ -918,6 +1496,22 @@ quickcheck! {
     fn with_position_exact_size_2(a: Iter<u8, Exact>) -> bool {
         exact_size_for_this(a.with_position())
     }
+    fn with_position_double_ended(a: Vec<u8>, from_back: Vec<bool>) -> bool {
+        let expected: Vec<_> = a.iter().with_position().collect();
+        let mut it = a.iter().with_position();
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+        for &b in from_back.iter().chain(iter::repeat(&false)).take(a.len() + 1) {
+            let elt = if b { it.next_back() } else { it.next() };
+            match (elt, b) {
+                (Some(elt), false) => front.push(elt),
+                (Some(elt), true) => back.push(elt),
+                (None, _) => break,
+            }
+        }
+        front.extend(back.into_iter().rev());
+        front == expected
+    }
 }
 
 quickcheck! {
@@ -934,6 +1528,544 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    fn correct_into_lookup_modulo_key(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let pairs = || a.iter().map(|&i| (i % modulo, i));
+        let first = pairs().into_lookup(KeepFirst);
+        let last = pairs().into_lookup(KeepLast);
+        let sums = pairs().map(|(k, v)| (k, v as u64)).into_lookup(|_: &u8, x, y| x + y);
+        let groups = pairs().into_group_map();
+
+        assert_eq!(first.len(), groups.len());
+        assert_eq!(last, pairs().collect::<HashMap<_, _>>());
+        assert_eq!(sums.len(), groups.len());
+        for (key, vals) in groups {
+            assert_eq!(first[&key], vals[0]);
+            assert_eq!(last[&key], vals[vals.len() - 1]);
+            assert_eq!(sums[&key], vals.iter().map(|&v| v as u64).sum::<u64>());
+        }
+    }
+}
+
+quickcheck! {
+    fn correct_index_by_modulo_key(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        match a.iter().cloned().index_by(|&i| i % modulo) {
+            Ok(lookup) => {
+                assert_eq!(lookup.len(), a.len());
+                for (&key, &val) in lookup.iter() {
+                    assert_eq!(val % modulo, key);
+                }
+            }
+            Err(err) => {
+                assert_eq!(err.first % modulo, err.key);
+                assert_eq!(err.second % modulo, err.key);
+                // the error is reported at the first repeated key
+                let second = a.iter().enumerate()
+                    .position(|(i, &x)| a[..i].iter().any(|&y| y % modulo == x % modulo))
+                    .unwrap();
+                assert_eq!(a[second], err.second);
+                assert_eq!(a.iter().find(|&&x| x % modulo == err.key), Some(&err.first));
+            }
+        }
+    }
+}
+
+quickcheck! {
+    fn correct_grouping_map_by_aggregate_modulo_key(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo < 2 { 2 } else { modulo } as u64; // Avoid `% 0`
+        let lookup = a.iter()
+            .map(|&b| (b as u64 % modulo, b as u64))
+            .into_grouping_map()
+            .aggregate(|acc, &key, val| {
+                assert!(val % modulo == key);
+                if val % (modulo - 1) == 0 {
+                    None
+                } else if let Some(acc) = acc {
+                    Some(acc + val)
+                } else {
+                    Some(val)
+                }
+            });
+
+        let group_map_lookup = a.iter()
+            .map(|&b| b as u64)
+            .map(|i| (i % modulo, i))
+            .into_group_map()
+            .into_iter()
+            .filter_map(|(key, vals)| {
+                vals.into_iter().fold(None, |acc, val| {
+                    if val % (modulo - 1) == 0 {
+                        None
+                    } else if let Some(acc) = acc {
+                        Some(acc + val)
+                    } else {
+                        Some(val)
+                    }
+                }).map(|new_val| (key, new_val))
+            })
+            .collect::<HashMap<_,_>>();
+        assert_eq!(lookup, group_map_lookup);
+    }
+
+    fn correct_grouping_map_by_fold_modulo_key(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo } as u64; // Avoid `% 0`
+        let lookup = a.iter().map(|&b| b as u64) // Avoid overflows
+            .map(|i| (i % modulo, i))
+            .into_grouping_map()
+            .fold(0u64, |acc, &key, val| {
+                assert!(val % modulo == key);
+                acc + val
+            });
+
+        let group_map_lookup = a.iter()
+            .map(|&b| b as u64)
+            .map(|i| (i % modulo, i))
+            .into_group_map()
+            .into_iter()
+            .map(|(key, vals)| (key, vals.into_iter().sum()))
+            .collect::<HashMap<_,_>>();
+        assert_eq!(lookup, group_map_lookup);
+    }
+
+    fn correct_grouping_map_by_fold_with_modulo_key(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo } as u64; // Avoid `% 0`
+        let lookup = a.iter().map(|&b| b as u64) // Avoid overflows
+            .map(|i| (i % modulo, i))
+            .into_grouping_map()
+            .fold_with(|&key, &val| {
+                assert!(val % modulo == key);
+                key * 1000
+            }, |acc, &key, val| {
+                assert!(val % modulo == key);
+                acc + val
+            });
+
+        let group_map_lookup = a.iter()
+            .map(|&b| b as u64)
+            .map(|i| (i % modulo, i))
+            .into_group_map()
+            .into_iter()
+            .map(|(key, vals)| (key, key * 1000 + vals.into_iter().sum::<u64>()))
+            .collect::<HashMap<_,_>>();
+        assert_eq!(lookup, group_map_lookup);
+    }
+
+    fn correct_grouping_map_try_fold_modulo_key(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let lookup = a.iter().map(|&b| (b % modulo, b))
+            .into_grouping_map()
+            .try_fold(0u8, |acc, _, val| acc.checked_add(val).ok_or(()));
+        let expected = a.iter().map(|&b| (b % modulo, b)).into_grouping_map().checked_sum();
+
+        if expected.values().any(Option::is_none) {
+            assert_eq!(lookup, Err(()));
+        } else {
+            let expected = expected.into_iter()
+                .map(|(key, sum)| (key, sum.unwrap()))
+                .collect::<HashMap<_, _>>();
+            assert_eq!(lookup, Ok(expected));
+        }
+    }
+
+    fn correct_grouping_map_by_fold_first_modulo_key(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo } as u64; // Avoid `% 0`
+        let lookup = a.iter().map(|&b| b as u64) // Avoid overflows
+            .map(|i| (i % modulo, i))
+            .into_grouping_map()
+            .fold_first(|acc, &key, val| {
+                assert!(val % modulo == key);
+                acc + val
+            });
+
+        let group_map_lookup = a.iter()
+            .map(|&b| b as u64)
+            .map(|i| (i % modulo, i))
+            .into_group_map()
+            .into_iter()
+            .map(|(key, vals)| (key, vals.into_iter().sum::<u64>()))
+            .collect::<HashMap<_,_>>();
+        assert_eq!(lookup, group_map_lookup);
+    }
+
+    fn correct_grouping_map_by_collect_modulo_key(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let lookup_grouping_map = a.iter().map(|&b| (b % modulo, b))
+            .into_grouping_map().collect::<Vec<_>>();
+        let lookup_group_map = a.iter().map(|&b| (b % modulo, b)).into_group_map();
+
+        assert_eq!(lookup_grouping_map, lookup_group_map);
+    }
+
+    fn correct_grouping_map_collect_into_sorted_vec(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let sorted = a.iter().map(|&b| (b % modulo, b))
+            .into_grouping_map().collect_into_sorted_vec::<Vec<_>>();
+        let expected = a.iter().map(|&b| (b % modulo, b))
+            .into_group_map().into_iter().sorted();
+
+        assert!(sorted.windows(2).all(|w| w[0].0 < w[1].0));
+        itertools::assert_equal(sorted, expected);
+    }
+
+    fn correct_grouping_map_sum(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo } as u64; // Avoid `% 0`
+        let lookup = a.iter().map(|&b| b as u64) // Avoid overflows
+            .map(|i| (i % modulo, i))
+            .into_grouping_map().sum();
+        let expected = a.iter().map(|&b| b as u64)
+            .map(|i| (i % modulo, i))
+            .into_group_map().into_iter()
+            .map(|(key, vals)| (key, vals.into_iter().sum::<u64>()))
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(lookup, expected);
+    }
+
+    fn correct_grouping_map_product(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo } as u64; // Avoid `% 0`
+        let lookup = a.iter().map(|&b| b as u64 % 2) // Avoid overflows
+            .map(|i| (i % modulo, i))
+            .into_grouping_map().product();
+        let expected = a.iter().map(|&b| b as u64 % 2)
+            .map(|i| (i % modulo, i))
+            .into_group_map().into_iter()
+            .map(|(key, vals)| (key, vals.into_iter().product::<u64>()))
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(lookup, expected);
+    }
+
+    fn correct_grouping_map_max_modulo_key(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let lookup = a.iter().map(|&b| (b % modulo, b))
+            .into_grouping_map().max();
+        let expected = a.iter().map(|&b| (b % modulo, b))
+            .into_group_map().into_iter()
+            .map(|(key, vals)| (key, vals.into_iter().max().unwrap()))
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(lookup, expected);
+    }
+
+    fn correct_grouping_map_max_by_key_modulo_key(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let lookup = a.iter().enumerate().map(|(i, &b)| (b % modulo, (b / 4, i)))
+            .into_grouping_map().max_by_key(|_, v| v.0);
+        let expected = a.iter().enumerate().map(|(i, &b)| (b % modulo, (b / 4, i)))
+            .into_group_map().into_iter()
+            .map(|(key, vals)| (key, vals.into_iter().max_by_key(|v| v.0).unwrap()))
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(lookup, expected);
+    }
+
+    fn correct_grouping_map_min_modulo_key(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let lookup = a.iter().map(|&b| (b % modulo, b))
+            .into_grouping_map().min();
+        let expected = a.iter().map(|&b| (b % modulo, b))
+            .into_group_map().into_iter()
+            .map(|(key, vals)| (key, vals.into_iter().min().unwrap()))
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(lookup, expected);
+    }
+
+    fn correct_grouping_map_min_by_key_modulo_key(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let lookup = a.iter().enumerate().map(|(i, &b)| (b % modulo, (b / 4, i)))
+            .into_grouping_map().min_by_key(|_, v| v.0);
+        let expected = a.iter().enumerate().map(|(i, &b)| (b % modulo, (b / 4, i)))
+            .into_group_map().into_iter()
+            .map(|(key, vals)| (key, vals.into_iter().min_by_key(|v| v.0).unwrap()))
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(lookup, expected);
+    }
+
+    fn correct_grouping_map_minmax_modulo_key(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let lookup = a.iter().map(|&b| (b % modulo, b))
+            .into_grouping_map().minmax();
+        let expected = a.iter().map(|&b| (b % modulo, b))
+            .into_group_map().into_iter()
+            .map(|(key, vals)| (key, vals.into_iter().minmax()))
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(lookup, expected);
+    }
+
+    fn correct_grouping_map_minmax_by_key_modulo_key(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let lookup = a.iter().enumerate().map(|(i, &b)| (b % modulo, (b / 4, i)))
+            .into_grouping_map().minmax_by_key(|_, v| v.0);
+        let expected = a.iter().enumerate().map(|(i, &b)| (b % modulo, (b / 4, i)))
+            .into_group_map().into_iter()
+            .map(|(key, vals)| (key, vals.into_iter().minmax_by_key(|v| v.0)))
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(lookup, expected);
+    }
+
+    fn correct_grouping_map_by_key_fn(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let lookup = a.iter().cloned()
+            .into_grouping_map_by(|&b| b % modulo).collect::<Vec<_>>();
+        let expected = a.iter().map(|&b| (b % modulo, b)).into_group_map();
+
+        assert_eq!(lookup, expected);
+    }
+
+    fn correct_grouping_map_collect_in_btree_map(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let lookup = a.iter().map(|&b| (b % modulo, b))
+            .into_grouping_map().collect_in::<Vec<_>, _>(::std::collections::BTreeMap::new());
+        let expected = a.iter().map(|&b| (b % modulo, b))
+            .into_group_map().into_iter().sorted();
+
+        itertools::assert_equal(lookup, expected);
+    }
+
+    fn correct_grouping_map_with_hasher(a: Vec<u8>, modulo: u8) -> () {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::BuildHasherDefault;
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let lookup: HashMap<_, _, BuildHasherDefault<DefaultHasher>> = a.iter().map(|&b| (b % modulo, b))
+            .into_grouping_map_with_hasher(Default::default()).collect::<Vec<_>>();
+        let expected = a.iter().map(|&b| (b % modulo, b)).into_group_map();
+
+        assert_eq!(lookup.len(), expected.len());
+        for (key, vals) in expected {
+            assert_eq!(lookup[&key], vals);
+        }
+    }
+
+    fn correct_grouping_map_mean(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let lookup = a.iter().map(|&b| (b % modulo, b))
+            .into_grouping_map().mean();
+        let expected = a.iter().map(|&b| (b % modulo, b))
+            .into_group_map().into_iter()
+            .map(|(key, vals)| {
+                let sum = vals.iter().map(|&b| b as u64).sum::<u64>();
+                (key, sum as f64 / vals.len() as f64)
+            })
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(lookup, expected);
+    }
+
+    fn correct_grouping_map_collect_into_batches(a: Vec<u8>, b: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let mut lookup = HashMap::new();
+        a.iter().map(|&x| (x % modulo, x)).into_grouping_map().collect_into::<Vec<_>, _>(&mut lookup);
+        b.iter().map(|&x| (x % modulo, x)).into_grouping_map().collect_into::<Vec<_>, _>(&mut lookup);
+        let expected = a.iter().chain(&b).map(|&x| (x % modulo, x)).into_group_map();
+
+        assert_eq!(lookup, expected);
+    }
+
+    fn correct_grouping_runs_collect(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let runs = a.iter().map(|&b| (b % modulo, b))
+            .into_grouping_runs().collect::<Vec<_>>();
+        let expected = a.iter().group_by(|&&b| b % modulo).into_iter()
+            .map(|(key, group)| (key, group.cloned().collect::<Vec<_>>()))
+            .collect_vec();
+
+        itertools::assert_equal(runs, expected);
+    }
+
+    fn correct_grouping_runs_size_hint(a: Vec<u8>, modulo: u8) -> bool {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        correct_size_hint(a.iter().map(|&b| (b % modulo, b))
+            .into_grouping_runs().fold_with(|_, _| 0, |acc, _, b| acc + b as u32))
+    }
+
+    fn correct_nested_grouping_map_collect(a: Vec<u8>, modulo: u8, modulo2: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let modulo2 = if modulo2 == 0 { 1 } else { modulo2 };
+        let lookup = a.iter().cloned()
+            .into_grouping_map_by(|&b| b % modulo)
+            .nested_by(|&b| b % modulo2)
+            .collect::<Vec<_>>();
+        let expected = a.iter().map(|&b| (b % modulo, b)).into_group_map().into_iter()
+            .map(|(key, vals)| {
+                (key, vals.into_iter().map(|b| (b % modulo2, b)).into_group_map())
+            })
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(lookup, expected);
+    }
+
+    fn correct_grouping_map_first_last(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let first = a.iter().enumerate().map(|(i, &b)| (b % modulo, i))
+            .into_grouping_map().first();
+        let last = a.iter().enumerate().map(|(i, &b)| (b % modulo, i))
+            .into_grouping_map().last();
+        let groups = a.iter().enumerate().map(|(i, &b)| (b % modulo, i)).into_group_map();
+
+        assert_eq!(first.len(), groups.len());
+        assert_eq!(last.len(), groups.len());
+        for (key, indices) in groups {
+            assert_eq!(first[&key], indices[0]);
+            assert_eq!(last[&key], indices[indices.len() - 1]);
+        }
+    }
+
+    fn correct_grouping_map_all_any(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let all = a.iter().map(|&b| (b % modulo, b))
+            .into_grouping_map().all(|_, b| b % 2 == 0);
+        let any = a.iter().map(|&b| (b % modulo, b))
+            .into_grouping_map().any(|_, b| b % 2 == 0);
+        let groups = a.iter().map(|&b| (b % modulo, b)).into_group_map();
+
+        assert_eq!(all.len(), groups.len());
+        assert_eq!(any.len(), groups.len());
+        for (key, vals) in groups {
+            assert_eq!(all[&key], vals.iter().all(|b| b % 2 == 0));
+            assert_eq!(any[&key], vals.iter().any(|b| b % 2 == 0));
+        }
+    }
+
+    fn correct_grouping_map_k_smallest_largest(a: Vec<u8>, modulo: u8, k: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let k = (k % 8) as usize;
+        let smallest = a.iter().map(|&b| (b % modulo, b))
+            .into_grouping_map().k_smallest(k);
+        let largest = a.iter().map(|&b| (b % modulo, b))
+            .into_grouping_map().k_largest(k);
+        let groups = a.iter().map(|&b| (b % modulo, b)).into_group_map();
+
+        assert_eq!(smallest.len(), groups.len());
+        assert_eq!(largest.len(), groups.len());
+        for (key, mut vals) in groups {
+            vals.sort();
+            assert_eq!(smallest[&key], vals.iter().cloned().take(k).collect_vec());
+            assert_eq!(largest[&key], vals.iter().cloned().rev().take(k).collect_vec());
+        }
+    }
+
+    fn correct_grouping_map_variance(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let lookup = a.iter().map(|&b| (b % modulo, b))
+            .into_grouping_map().variance();
+        let groups = a.iter().map(|&b| (b % modulo, b as f64)).into_group_map();
+
+        assert_eq!(lookup.len(), groups.len());
+        for (key, vals) in groups {
+            let n = vals.len() as f64;
+            let mean = vals.iter().sum::<f64>() / n;
+            let variance = vals.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n;
+            assert!((lookup[&key] - variance).abs() < 1e-6);
+        }
+    }
+
+    fn correct_grouping_map_join(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let lookup = a.iter().map(|&b| (b % modulo, b))
+            .into_grouping_map().join(", ");
+        let expected = a.iter().map(|&b| (b % modulo, b))
+            .into_group_map().into_iter()
+            .map(|(key, vals)| (key, vals.iter().join(", ")))
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(lookup, expected);
+    }
+
+    fn correct_grouping_map_partition(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let (even, odd) = a.iter().map(|&b| (b % modulo, b))
+            .into_grouping_map().partition::<Vec<_>, _>(|_, &b| b % 2 == 0);
+        let expected_even = a.iter().filter(|&&b| b % 2 == 0).map(|&b| (b % modulo, b))
+            .into_group_map();
+        let expected_odd = a.iter().filter(|&&b| b % 2 != 0).map(|&b| (b % modulo, b))
+            .into_group_map();
+
+        assert_eq!(even, expected_even);
+        assert_eq!(odd, expected_odd);
+    }
+
+    fn correct_grouping_map_collect_unique(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let lookup = a.iter().map(|&b| (b % modulo, b / 2))
+            .into_grouping_map().collect_unique::<Vec<_>>();
+        let expected = a.iter().map(|&b| (b % modulo, b / 2))
+            .into_group_map().into_iter()
+            .map(|(key, vals)| (key, vals.into_iter().unique().collect_vec()))
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(lookup, expected);
+    }
+
+    fn correct_grouping_map_counts_by(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let lookup = a.iter().map(|&b| (b % modulo, b))
+            .into_grouping_map().counts_by(|_, b| b % 3);
+        let groups = a.iter().map(|&b| (b % modulo, b)).into_group_map();
+
+        assert_eq!(lookup.len(), groups.len());
+        for (key, vals) in groups {
+            let counts = &lookup[&key];
+            assert_eq!(counts.values().sum::<usize>(), vals.len());
+            for (&r, &count) in counts {
+                assert_eq!(count, vals.iter().filter(|&&b| b % 3 == r).count());
+            }
+        }
+    }
+
+    fn correct_grouping_map_filter_map_values(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let lookup = a.iter().map(|&b| (b % modulo, b))
+            .into_grouping_map()
+            .filter(|_, &b| b % 2 == 0)
+            .map_values(|&key, b| b as u32 + key as u32)
+            .filter_map_values(|_, x| if x % 3 != 0 { Some(x * 2) } else { None })
+            .collect::<Vec<_>>();
+        let expected = a.iter().map(|&b| (b % modulo, b))
+            .filter(|&(_, b)| b % 2 == 0)
+            .map(|(key, b)| (key, b as u32 + key as u32))
+            .filter_map(|(key, x)| if x % 3 != 0 { Some((key, x * 2)) } else { None })
+            .into_group_map();
+
+        assert_eq!(lookup, expected);
+    }
+
+    fn correct_grouping_map_checked_sum(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let lookup = a.iter().map(|&b| (b % modulo, b))
+            .into_grouping_map().checked_sum();
+        let expected = a.iter().map(|&b| (b % modulo, b))
+            .into_group_map().into_iter()
+            .map(|(key, vals)| {
+                let sum = vals.iter().map(|&b| b as u64).sum::<u64>();
+                (key, if sum > 255 { None } else { Some(sum as u8) })
+            })
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(lookup, expected);
+    }
+
+    fn correct_grouping_map_checked_product(a: Vec<u8>, modulo: u8) -> () {
+        let modulo = if modulo == 0 { 1 } else { modulo }; // Avoid `% 0`
+        let lookup = a.iter().map(|&b| (b % modulo, b as u16))
+            .into_grouping_map().checked_product();
+        let expected = a.iter().map(|&b| (b % modulo, b as u16))
+            .into_group_map().into_iter()
+            .map(|(key, vals)| {
+                let product = vals.into_iter().fold(Some(1u16), |acc, b| acc.and_then(|p| p.checked_mul(b)));
+                (key, product)
+            })
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(lookup, expected);
+    }
+}
+
 /// A peculiar type: Equality compares both tuple items, but ordering only the
 /// first item.  This is so we can check the stability property easily.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -975,6 +2107,32 @@ quickcheck! {
         };
         minmax == expected
     }
+
+    fn position_minmax(a: Vec<Val>) -> bool {
+        use itertools::MinMaxResult;
+
+        let position = |elt: &Val| a.iter().position(|x| std::ptr::eq(x, elt)).unwrap();
+        let expected = match a.iter().minmax() {
+            MinMaxResult::NoElements => MinMaxResult::NoElements,
+            MinMaxResult::OneElement(_) => MinMaxResult::OneElement(0),
+            MinMaxResult::MinMax(min, max) => MinMaxResult::MinMax(position(min), position(max)),
+        };
+        a.iter().position_minmax() == expected &&
+            a.iter().position_minmax_by_key(|x| x.0) == expected &&
+            a.iter().position_minmax_by(|x, y| x.cmp(y)) == expected
+    }
+
+    fn position_min_max(a: Vec<Val>) -> bool {
+        let position = |elt: &Val| a.iter().position(|x| std::ptr::eq(x, elt)).unwrap();
+        let min = a.iter().min().map(&position);
+        let max = a.iter().max().map(&position);
+        a.iter().position_min() == min &&
+            a.iter().position_min_by_key(|x| x.0) == min &&
+            a.iter().position_min_by(|x, y| x.cmp(y)) == min &&
+            a.iter().position_max() == max &&
+            a.iter().position_max_by_key(|x| x.0) == max &&
+            a.iter().position_max_by(|x, y| x.cmp(y)) == max
+    }
 }
 
 quickcheck! {
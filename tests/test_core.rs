@@ -12,6 +12,8 @@ use core::iter;
 use it::Itertools;
 use it::interleave;
 use it::multizip;
+use it::multizip_eq;
+use it::multizip_longest;
 use it::free::put_back;
 
 #[test]
@@ -26,6 +28,109 @@ fn product2() {
     assert!(prod.next() == None);
 }
 
+#[test]
+fn product_skip_row() {
+    let mut it = (0..3).cartesian_product(0..2);
+    it.skip_row();
+    assert_eq!(it.next(), Some((1, 0)));
+    it.skip_row();
+    assert_eq!(it.size_hint(), (2, Some(2)));
+    it::assert_equal(it.by_ref(), [(2, 0), (2, 1)].iter().cloned());
+    it.skip_row();
+    assert_eq!(it.next(), None);
+
+    let mut it = (0..3).cartesian_product(0..0);
+    it.skip_row();
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn product12() {
+    let mut prod = iproduct!(0..1, 1..2, 2..3, 3..4, 4..5, 5..6,
+                             6..7, 7..8, 8..9, 9..10, 10..11, 11..13);
+    assert_eq!(prod.next(), Some((0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11)));
+    assert_eq!(prod.next(), Some((0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 12)));
+    assert_eq!(prod.next(), None);
+}
+
+#[test]
+fn cartesian_product_flat() {
+    let it = (0..2).cartesian_product(0..2).cartesian_product_flat(0..2)
+                   .cartesian_product_flat(0..2).cartesian_product_flat(0..2)
+                   .cartesian_product_flat(0..2);
+    assert!(it.clone().eq(iproduct!(0..2, 0..2, 0..2, 0..2, 0..2, 0..2)));
+    assert_eq!(it.last(), Some((1, 1, 1, 1, 1, 1)));
+}
+
+#[test]
+fn cons_tuples() {
+    let it = (0..3).zip(3..6).zip(6..9).cons_tuples();
+    assert_eq!(it.len(), 3);
+    assert!(it.rev().eq([(2, 5, 8), (1, 4, 7), (0, 3, 6)].iter().cloned()));
+
+    let it = it::cons_tuples((0..2).zip(0..2).zip(0..2).cons_tuples().zip(0..2));
+    assert!(it.eq([(0, 0, 0, 0), (1, 1, 1, 1)].iter().cloned()));
+
+    // one level of nesting is removed at a time
+    let nested = (0..2).zip(1..3).zip(2..4).zip(3..5);
+    let it = nested.clone().cons_tuples();
+    assert!(it.eq([((0, 1), 2, 3), ((1, 2), 3, 4)].iter().cloned()));
+    let it = nested.cons_tuples().cons_tuples();
+    assert!(it.rev().eq([(1, 2, 3, 4), (0, 1, 2, 3)].iter().cloned()));
+
+    let deep = (0..1).zip(1..2).zip(2..3).zip(3..4).zip(4..5).zip(5..6)
+        .zip(6..7).zip(7..8).zip(8..9).zip(9..10).zip(10..11).zip(11..12);
+    let mut it = deep.cons_tuples().cons_tuples().cons_tuples().cons_tuples()
+        .cons_tuples().cons_tuples().cons_tuples().cons_tuples().cons_tuples()
+        .cons_tuples();
+    assert_eq!(it.next(), Some((0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11)));
+}
+
+#[test]
+fn process_results_partial() {
+    use it::process_results_partial;
+
+    let ok: [Result<u32, &str>; 3] = [Ok(1), Ok(2), Ok(3)];
+    assert_eq!(process_results_partial(ok.iter().cloned(), |it| it.sum::<u32>()), Ok(6));
+
+    let bad = [Ok(1), Ok(2), Err("two"), Ok(3), Err("three")];
+    let err = process_results_partial(bad.iter().cloned(), |it| it.max()).unwrap_err();
+    assert_eq!((err.error, err.ok_count, err.partial), ("two", 2, Some(2)));
+
+    // the error is only reported if the processor reached it
+    assert_eq!(process_results_partial(bad.iter().cloned(), |it| it.take(2).count()), Ok(2));
+    let err = process_results_partial(bad.iter().cloned(), |it| it.take(3).count()).unwrap_err();
+    assert_eq!((err.ok_count, err.partial), (2, 2));
+
+    let err = process_results_partial(bad[2..].iter().cloned(), |it| it.count()).unwrap_err();
+    assert_eq!((err.error, err.ok_count, err.partial), ("two", 0, 0));
+}
+
+#[test]
+fn flat_map_ok() {
+    let input = [Ok(1), Err(0), Ok(0), Ok(2)];
+    let mut it = input.iter().cloned().flat_map_ok(|n| 0..n);
+    assert_eq!(it.size_hint(), (0, None));
+    it::assert_equal(it.by_ref().take(2), [Ok(0), Err(0)].iter().cloned());
+    it::assert_equal(it.by_ref(), [Ok(0), Ok(1)].iter().cloned());
+    assert_eq!(it.size_hint(), (0, Some(0)));
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn either_or_both_options() {
+    use it::EitherOrBoth::{self, Both, Left, Right};
+
+    let values = [Left(1), Right('a'), Both(2, 'b')];
+    for value in values.iter().cloned() {
+        let pair = value.clone().into_options();
+        assert_eq!(pair.0, value.clone().left());
+        assert_eq!(pair.1, value.clone().right());
+        assert_eq!(EitherOrBoth::from_options(pair.0, pair.1), Some(value));
+    }
+    assert_eq!(EitherOrBoth::<i32, char>::from_options(None, None), None);
+}
+
 #[test]
 fn product_temporary() {
     for (_x, _y, _z) in iproduct!(
@@ -55,6 +160,64 @@ fn izip_macro() {
     assert!(zip.next().is_none());
 }
 
+#[test]
+fn izip_eq_macro() {
+    let mut zip = izip_eq!(0..2, 0..2, 2..4i8);
+    for i in 0..2 {
+        assert!((i as usize, i, i as i8 + 2) == zip.next().unwrap());
+    }
+    assert!(zip.next().is_none());
+
+    let xs: [isize; 0] = [];
+    let mut zip = izip_eq!(0..0, &xs, 0..0, 0..0);
+    assert!(zip.next().is_none());
+}
+
+#[test]
+#[should_panic]
+fn izip_eq_macro_uneven() {
+    izip_eq!(0..3, 0..3, 0..2).count();
+}
+
+#[test]
+fn multizip_eq_same_length() {
+    let mut zip = multizip_eq((0..2, ['a', 'b'].iter().cloned(), 1..3));
+    assert_eq!(zip.size_hint(), (2, Some(2)));
+    assert_eq!(zip.next(), Some((0, 'a', 1)));
+    assert_eq!(zip.next(), Some((1, 'b', 2)));
+    assert_eq!(zip.next(), None);
+}
+
+#[test]
+#[should_panic]
+fn multizip_eq_uneven() {
+    // the mismatch is detected even when the last iterator is the longest
+    multizip_eq((0..2, 0..2, 0..3)).count();
+}
+
+#[test]
+fn izip_longest_macro() {
+    let mut zip = izip_longest!(0..1, 0..3, 0..2i8);
+    assert_eq!(zip.size_hint(), (3, Some(3)));
+    assert_eq!(zip.next(), Some((Some(0), Some(0), Some(0))));
+    assert_eq!(zip.next(), Some((None, Some(1), Some(1))));
+    assert_eq!(zip.next(), Some((None, Some(2), None)));
+    assert_eq!(zip.next(), None);
+
+    let xs: [isize; 0] = [];
+    let mut zip = izip_longest!(&xs, 0..0);
+    assert!(zip.next().is_none());
+    assert_eq!(izip_longest!(0..2).count(), 2);
+}
+
+#[test]
+fn multizip_longest_fuses() {
+    // the first iterator yields again after its first `None`
+    let flaky = [Some(1), None, Some(3)].iter().batching(|it| it.next().and_then(|&x| x));
+    let zip = multizip_longest((flaky, 0..3));
+    it::assert_equal(zip, [(Some(1), Some(0)), (None, Some(1)), (None, Some(2))].iter().cloned());
+}
+
 #[test]
 fn izip2() {
     let _zip1: iter::Zip<_, _> = izip!(1.., 2..);
@@ -245,6 +408,72 @@ fn tree_fold1() {
     }
 }
 
+#[test]
+fn try_fold1() {
+    for i in 0..10 {
+        let sum = (0..i).try_fold1(|x, y| Ok::<_, ()>(x + y));
+        assert_eq!(sum, Ok((0..i).fold1(|x, y| x + y)));
+    }
+
+    let mut iter = 1..10;
+    let checked = iter.try_fold1(|x, y| if y < 4 { Ok(x * y) } else { Err(y) });
+    assert_eq!(checked, Err(4));
+    assert_eq!(iter.next(), Some(5));
+}
+
+#[test]
+fn at_least_one() {
+    assert!((0..0).at_least_one().is_none());
+
+    let one = (5..6).at_least_one().unwrap();
+    assert_eq!(*one.first(), 5);
+    assert_eq!(one.clone().count(), 1);
+    assert_eq!(one.clone().minmax(), (5, 5));
+    assert_eq!(one.last(), 5);
+
+    let data = [(1, 'a'), (0, 'b'), (2, 'c'), (0, 'd'), (2, 'e')];
+    let many = data.iter().cloned().at_least_one().unwrap();
+    assert_eq!(many.clone().min_by_key(|x| x.0), (0, 'b'));
+    assert_eq!(many.clone().max_by_key(|x| x.0), (2, 'e'));
+    assert_eq!(many.clone().min_by(|x, y| x.0.cmp(&y.0)), (0, 'b'));
+    assert_eq!(many.clone().max_by(|x, y| x.0.cmp(&y.0)), (2, 'e'));
+    assert_eq!(many.clone().min(), (0, 'b'));
+    assert_eq!(many.clone().max(), (2, 'e'));
+    assert_eq!(many.clone().fold1(|x, y| (x.0 + y.0, y.1)), (5, 'e'));
+    assert_eq!(many.clone().count(), 5);
+    assert!(many.clone().into_iter().eq(data.iter().cloned()));
+    let (first, rest) = many.into_parts();
+    assert_eq!(first, (1, 'a'));
+    assert_eq!(rest.len(), 4);
+}
+
+#[test]
+fn exactly_n() {
+    assert!((0..0).exactly_n(0));
+    assert!((0..5).exactly_n(5));
+    assert!(!(0..5).exactly_n(4));
+    assert!(!(0..5).exactly_n(6));
+
+    let mut iter = 0..10;
+    assert!(!iter.exactly_n(3));
+    assert_eq!(iter.next(), Some(4));
+
+    let mut iter = 0..;
+    assert!(!iter.exactly_n_where(2, |&x| x % 3 == 0));
+    assert_eq!(iter.next(), Some(7));
+    assert!((0..10).exactly_n_where(3, |&x| x > 6));
+    assert!(!(0..10).exactly_n_where(4, |&x| x > 6));
+}
+
+#[test]
+fn sum_by_product_by() {
+    let pairs = [(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')];
+    assert_eq!(pairs.iter().sum_by(|&&(n, _)| n), 10);
+    assert_eq!(pairs.iter().product_by(|&&(n, _)| n), 24);
+    assert_eq!((0..0).sum_by(|&x| x), 0);
+    assert_eq!((0..0).product_by(|&x| x), 1);
+}
+
 #[test]
 fn exactly_one() {
     assert_eq!((0..10).filter(|&x| x == 2).exactly_one().unwrap(), 2);
@@ -252,3 +481,82 @@ fn exactly_one() {
     assert!((0..10).filter(|&x| x > 1 && x < 5).exactly_one().unwrap_err().eq(2..5));
     assert!((0..10).filter(|&_| false).exactly_one().unwrap_err().eq(0..0));
 }
+
+#[test]
+fn capture_err() {
+    let input = [Ok(1), Ok(2), Err("bad"), Ok(3), Err("worse")];
+    let mut error = Ok(());
+    let mut sum = 0;
+    for x in input.iter().cloned().capture_err(&mut error) {
+        sum += x;
+    }
+    assert_eq!(sum, 3);
+    assert_eq!(error, Err("bad"));
+
+    let mut error = Ok::<(), ()>(());
+    {
+        let mut iter = [Ok(1), Ok(2)].iter().cloned().capture_err(&mut error);
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), None);
+    }
+    assert_eq!(error, Ok(()));
+}
+
+#[test]
+fn min_max_by_key_with() {
+    let pairs = [(1, 'a'), (0, 'b'), (2, 'c'), (0, 'd'), (2, 'e')];
+    assert_eq!(pairs.iter().min_by_key_with(|p| p.0), Some((0, &(0, 'b'))));
+    assert_eq!(pairs.iter().max_by_key_with(|p| p.0), Some((2, &(2, 'e'))));
+
+    let mut calls = 0;
+    let _ = pairs.iter().max_by_key_with(|p| { calls += 1; p.0 });
+    assert_eq!(calls, pairs.len());
+
+    assert_eq!((0..0).min_by_key_with(|&x| x), None);
+    assert_eq!((0..0).max_by_key_with(|&x| x), None);
+}
+
+#[test]
+fn take_while_inclusive() {
+    it::assert_equal((1..10).take_while_inclusive(|&x| x < 4), 1..5);
+    it::assert_equal((1..4).take_while_inclusive(|&x| x < 10), 1..4);
+    it::assert_equal((1..4).take_while_inclusive(|_| false), 1..2);
+    it::assert_equal((0..0).take_while_inclusive(|_| false), 0..0);
+
+    let mut calls = 0;
+    {
+        let mut iter = (0..).take_while_inclusive(|&x| { calls += 1; x < 2 });
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+    assert_eq!(calls, 3);
+}
+
+#[test]
+fn cumulative_fold() {
+    it::assert_equal((1..5).cumulative_fold(0, |acc, x| acc + x), [1, 3, 6, 10].iter().cloned());
+    it::assert_equal((1..5).cumulative_fold(1, |acc, x| acc * x), [1, 2, 6, 24].iter().cloned());
+    assert_eq!((0..0).cumulative_fold(0, |acc, x| acc + x).next(), None);
+    assert_eq!((0..7).cumulative_fold(0, |acc, x| acc + x).len(), 7);
+
+    it::assert_equal((1..5).cumsum(), [1, 3, 6, 10].iter().cloned());
+    it::assert_equal([1.5, -0.5].iter().cloned().cumsum(), [1.5, 1.0].iter().cloned());
+    assert_eq!((0..0).cumsum().next(), None);
+}
+
+#[test]
+fn diffs() {
+    it::assert_equal([1, 3, 2, 2].iter().cloned().diffs(), [2, -1, 0].iter().cloned());
+    assert_eq!((0..0).diffs().next(), None);
+    assert_eq!((0..1).diffs().next(), None);
+    assert_eq!((0..7).diffs().len(), 6);
+
+    let mut it = (0..4).diffs_by(|a, b| (*a, *b));
+    assert_eq!(it.next(), Some((0, 1)));
+    assert_eq!(it.len(), 2);
+    it::assert_equal(it, [(1, 2), (2, 3)].iter().cloned());
+}
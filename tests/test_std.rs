@@ -1,10 +1,13 @@
 
 #[macro_use] extern crate itertools as it;
 extern crate permutohedron;
+#[cfg(all(feature = "rand", feature = "use_std"))]
+extern crate rand;
 
 use it::Itertools;
 use it::multizip;
 use it::multipeek;
+use it::peek_nth;
 use it::free::rciter;
 use it::free::put_back_n;
 use it::FoldWhile;
@@ -52,6 +55,20 @@ fn interleave_shortest() {
     assert_eq!(it.size_hint(), (6, Some(6)));
 }
 
+#[test]
+fn multi_interleave() {
+    let it = vec![0..3, 3..3, 6..8, 9..10].into_iter().multi_interleave();
+    assert_eq!(it.size_hint(), (6, Some(6)));
+    assert_eq!(it.collect_vec(), vec![0, 6, 9, 1, 7, 2]);
+
+    let no_iters: Vec<Vec<i32>> = Vec::new();
+    assert_eq!(no_iters.into_iter().multi_interleave().next(), None);
+
+    let mut it = vec![vec![1], vec![2, 3]].into_iter().multi_interleave();
+    assert_eq!(it.by_ref().collect_vec(), vec![1, 2, 3]);
+    assert_eq!(it.next(), None);
+}
+
 
 #[test]
 fn unique_by() {
@@ -70,6 +87,80 @@ fn unique() {
     it::assert_equal(ys.iter(), xs.iter().unique());
 }
 
+#[test]
+fn unique_last() {
+    let xs = [0, 1, 2, 3, 2, 1, 3];
+    let ys = [0, 2, 1, 3];
+    it::assert_equal(ys.iter(), xs.iter().unique_last());
+    let xs: [i32; 0] = [];
+    assert_eq!(xs.iter().unique_last().count(), 0);
+}
+
+#[test]
+fn unique_by_last() {
+    let xs = ["aaa", "bbbbb", "aa", "ccc", "bbbb", "aaaaa", "cccc"];
+    let ys = ["bbbb", "aaaaa", "cccc"];
+    it::assert_equal(ys.iter(), xs.iter().unique_by_last(|x| x[..2].to_string()));
+}
+
+#[cfg(feature = "approx_unique")]
+#[test]
+fn approx_unique_count() {
+    assert_eq!((0..0).approx_unique_count(4), 0);
+    assert_eq!(vec![7; 100].into_iter().approx_unique_count(8), 1);
+    for &n in &[10, 100, 1000, 100_000] {
+        let estimate = (0..2 * n).map(|x| x / 2).approx_unique_count(14) as f64;
+        let error = (estimate - n as f64).abs() / n as f64;
+        assert!(error < 0.05, "n = {}, estimate = {}", n, estimate);
+    }
+}
+
+#[cfg(feature = "approx_unique")]
+#[test]
+#[should_panic]
+fn approx_unique_count_precision() {
+    (0..10).approx_unique_count(3);
+}
+
+#[test]
+fn duplicate_positions() {
+    let text = "abcabba";
+    it::assert_equal(text.chars().duplicate_positions(),
+                     vec![(0, 3, 'a'), (1, 4, 'b'), (1, 5, 'b'), (0, 6, 'a')]);
+    assert_eq!("abc".chars().duplicate_positions().next(), None);
+
+    let lines = vec!["x = 1", "y = 2", "x = 3"];
+    let keys = lines.iter().map(|line| line.split(" = ").next().unwrap());
+    let messages = keys.duplicate_positions()
+        .map(|(first, i, key)| {
+            format!("duplicate key {} at line {}, first seen at line {}", key, i + 1, first + 1)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(messages, vec!["duplicate key x at line 3, first seen at line 1"]);
+}
+
+#[test]
+fn duplicates() {
+    let xs = [0, 1, 2, 3, 2, 1, 3];
+    let ys = [2, 1, 3];
+    it::assert_equal(ys.iter(), xs.iter().duplicates());
+    let ys_rev = [3, 2, 1];
+    it::assert_equal(ys_rev.iter(), xs.iter().rev().duplicates());
+
+    let xs = [0, 1, 0, 1, 0, 1];
+    it::assert_equal([0, 1].iter(), xs.iter().duplicates());
+    assert_eq!((0..5).duplicates().next(), None);
+}
+
+#[test]
+fn duplicates_by() {
+    let xs = ["aaa", "bbbbb", "aa", "ccc", "bbbb", "aaaaa", "cccc"];
+    let ys = ["aa", "bbbb", "cccc"];
+    it::assert_equal(ys.iter(), xs.iter().duplicates_by(|x| x[..2].to_string()));
+    let ys_rev = ["ccc", "aa", "bbbbb"];
+    it::assert_equal(ys_rev.iter(), xs.iter().rev().duplicates_by(|x| x[..2].to_string()));
+}
+
 #[test]
 fn intersperse() {
     let xs = ["a", "", "b", "c"];
@@ -82,6 +173,25 @@ fn intersperse() {
     assert!(it.next() == None);
 }
 
+#[test]
+fn intersperse_every() {
+    let rows = ["a", "b", "c", "d", "e"];
+    let text: String = rows.iter().cloned().intersperse_every(2, "|").collect();
+    assert_eq!(text, "ab|cd|e");
+
+    let text: String = rows.iter().cloned().intersperse_every(5, "|").collect();
+    assert_eq!(text, "abcde");
+
+    let mut it = rows[..0].iter().intersperse_every(2, &"|");
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+#[should_panic]
+fn intersperse_every_zero() {
+    (0..3).intersperse_every(0, 1);
+}
+
 #[test]
 fn dedup() {
     let xs = [0, 1, 1, 1, 2, 1, 3, 3];
@@ -162,6 +272,130 @@ fn tee() {
     it::assert_equal(t1.zip(t2), xs.iter().cloned().zip(xs.iter().cloned()));
 }
 
+#[test]
+fn tee_bounded() {
+    use it::TeeOverflow;
+
+    let xs = [0, 1, 2, 3, 4];
+    for &overflow in &[TeeOverflow::Panic, TeeOverflow::Pause, TeeOverflow::DropOldest] {
+        let (t1, t2) = xs.iter().cloned().tee_bounded(1, overflow);
+        it::assert_equal(t1.zip(t2), xs.iter().cloned().zip(xs.iter().cloned()));
+    }
+
+    let (mut t1, mut t2) = xs.iter().cloned().tee_bounded(2, TeeOverflow::Pause);
+    assert_eq!(t1.next(), Some(0));
+    assert_eq!(t1.next(), Some(1));
+    assert_eq!(t1.next(), None);
+    assert_eq!(t2.next(), Some(0));
+    assert_eq!(t2.next(), Some(1));
+    assert_eq!(t2.next(), Some(2));
+    assert_eq!(t2.next(), Some(3));
+    assert_eq!(t2.next(), None);
+    it::assert_equal(t1, 2..5);
+    it::assert_equal(t2, Some(4));
+
+    let (t1, t2) = xs.iter().cloned().tee_bounded(3, TeeOverflow::DropOldest);
+    it::assert_equal(t2, xs.iter().cloned());
+    it::assert_equal(t1, 2..5);
+}
+
+#[test]
+fn tee_n() {
+    let xs = [0, 1, 2, 3];
+    assert!((0..4).tee_n(0).is_empty());
+
+    let mut tees = xs.iter().cloned().tee_n(3);
+    assert_eq!(tees[1].next(), Some(0));
+    assert_eq!(tees[1].next(), Some(1));
+    assert_eq!(tees[0].next(), Some(0));
+    assert_eq!(tees[2].size_hint(), (4, Some(4)));
+    assert_eq!(tees[1].len(), 2);
+    assert_eq!(tees[2].next(), Some(0));
+    assert_eq!(tees[2].next(), Some(1));
+    assert_eq!(tees[2].next(), Some(2));
+    assert_eq!(tees[0].len(), 3);
+    it::assert_equal(tees.pop().unwrap(), 3..4);
+    it::assert_equal(tees.pop().unwrap(), 2..4);
+    it::assert_equal(tees.pop().unwrap(), 1..4);
+
+    let mut tees = xs.iter().cloned().tee_n(3);
+    drop(tees.remove(1));
+    let (t1, t2) = (tees.remove(0), tees.remove(0));
+    it::assert_equal(t1.zip(t2), xs.iter().cloned().zip(xs.iter().cloned()));
+
+    let tees = (0..10).tee_n(4);
+    let sums = tees.into_iter().enumerate()
+        .map(|(i, t)| t.skip(i).sum::<i32>())
+        .collect::<Vec<_>>();
+    assert_eq!(sums, vec![45, 45, 44, 42]);
+}
+
+#[test]
+#[should_panic(expected = "more than 2 elements ahead")]
+fn tee_bounded_panic() {
+    let (t1, _t2) = (0..5).tee_bounded(2, it::TeeOverflow::Panic);
+    t1.count();
+}
+
+#[test]
+fn tee_bounded_dropped_half() {
+    use it::TeeOverflow;
+
+    for &overflow in &[TeeOverflow::Panic, TeeOverflow::Pause, TeeOverflow::DropOldest] {
+        let (t1, t2) = (0..10).tee_bounded(2, overflow);
+        drop(t2);
+        assert_eq!(t1.size_hint(), (10, Some(10)));
+        it::assert_equal(t1, 0..10);
+
+        // the half left has to read its backlog first
+        let (mut t1, mut t2) = (0..10).tee_bounded(2, overflow);
+        assert_eq!(t2.next(), Some(0));
+        assert_eq!(t2.next(), Some(1));
+        drop(t2);
+        it::assert_equal(t1.by_ref(), 0..10);
+        assert_eq!(t1.next(), None);
+    }
+}
+
+#[test]
+fn partition_lazy_dropped_half() {
+    use std::rc::Rc;
+
+    // the elements hold a token, to count those still buffered
+    let token = Rc::new(());
+    let (mut evens, mut odds) = (0..10).map(|i| (i, token.clone()))
+        .partition_lazy(|&(i, _)| i % 2 == 0);
+    assert_eq!(odds.next().map(|(i, _)| i), Some(1));
+    assert_eq!(Rc::strong_count(&token), 2);
+    drop(odds);
+    it::assert_equal(evens.by_ref().map(|(i, _)| i), vec![0, 2, 4, 6, 8]);
+    assert_eq!(Rc::strong_count(&token), 1);
+}
+
+#[test]
+fn try_tee_bounded() {
+    use it::TeeOverflowError;
+
+    let error = Err(TeeOverflowError { capacity: 1 });
+    let (mut t1, mut t2) = (0..3).try_tee_bounded(1);
+    assert_eq!(t1.next(), Some(Ok(0)));
+    assert_eq!(t1.next(), Some(error));
+    assert_eq!(t1.next(), Some(error));
+    assert_eq!(t2.next(), Some(Ok(0)));
+    assert_eq!(t2.next(), Some(Ok(1)));
+    assert_eq!(t2.next(), Some(error));
+    assert_eq!(t1.next(), Some(Ok(1)));
+    assert_eq!(t1.next(), Some(Ok(2)));
+    assert_eq!(t2.next(), Some(Ok(2)));
+    assert_eq!(t2.next(), None);
+    assert_eq!(t1.next(), None);
+    assert_eq!(error.unwrap_err().to_string(), "one half is more than 1 elements ahead");
+
+    let (t1, t2) = (0..5).try_tee_bounded(2);
+    drop(t2);
+    it::assert_equal(t1, (0..5).map(Ok));
+}
+
 
 #[test]
 fn test_rciter() {
@@ -284,6 +518,125 @@ fn join() {
     assert_eq!(none.iter().join(", "), "");
 }
 
+#[cfg(feature = "int_join")]
+#[test]
+fn join_int() {
+    use std::{i64, u64};
+
+    assert_eq!(Vec::<u32>::new().iter().join_int(", "), "");
+    assert_eq!([0u8, 9, 10, 99, 100, 255].iter().join_int(","), "0,9,10,99,100,255");
+    assert_eq!([i64::MIN, -1, i64::MAX].iter().join_int(" "),
+               format!("{} -1 {}", i64::MIN, i64::MAX));
+    assert_eq!([u64::MAX].iter().join_int(" "), u64::MAX.to_string());
+
+    let numbers = (-1000..1000).map(|i| i * 7919);
+    assert_eq!(numbers.clone().join_int(", "), numbers.clone().join(", "));
+}
+
+#[test]
+fn find_subsequence() {
+    let text = "abaabababc";
+    assert_eq!(text.chars().find_subsequence("ababc".chars()), Some(5));
+    assert_eq!(text.chars().find_subsequence("abac".chars()), None);
+    assert_eq!(text.chars().find_subsequence("".chars()), Some(0));
+    assert!(text.chars().contains_subsequence("aab".chars()));
+    assert!(!"".chars().contains_subsequence("a".chars()));
+}
+
+#[test]
+fn window_extrema_ties() {
+    // compared by the first field only: the second tells equal elements apart
+    let v = vec![(2, 'a'), (1, 'b'), (1, 'c'), (3, 'd'), (1, 'e'), (3, 'f')];
+    fn cmp(x: &(i32, char), y: &(i32, char)) -> std::cmp::Ordering { x.0.cmp(&y.0) }
+    let mins = v.iter().cloned().window_min_by(3, cmp).map(|x| x.1);
+    it::assert_equal(mins, vec!['b', 'b', 'c', 'e']);
+    let maxs = v.iter().cloned().window_max_by(3, cmp).map(|x| x.1);
+    it::assert_equal(maxs, vec!['a', 'd', 'd', 'f']);
+}
+
+#[test]
+#[should_panic]
+fn window_min_zero() {
+    (0..3).window_min(0);
+}
+
+#[test]
+fn rolling_mean() {
+    let it = vec![1.5, 2.5, -1., 3.].into_iter().rolling_mean(2);
+    it::assert_equal(it, vec![2., 0.75, 1.]);
+    assert_eq!((0..3).rolling_mean(4).next(), None);
+    assert_eq!((0..3u8).rolling_mean(1).collect::<Vec<_>>(), vec![0., 1., 2.]);
+    // the total is not kept in the element type, which would overflow
+    it::assert_equal(vec![200u8, 200, 100].into_iter().rolling_mean(2), vec![200., 150.]);
+    it::assert_equal(vec![1u64 << 63, 1 << 63].into_iter().rolling_mean(2), vec![9223372036854775808.]);
+}
+
+#[test]
+fn rolling_sum_near_max() {
+    // the running total must not hold more than `n` elements at any time
+    it::assert_equal(vec![200u8, 200, 200].into_iter().rolling_sum(1), vec![200, 200, 200]);
+    it::assert_equal(vec![100u8, 150, 100, 155].into_iter().rolling_sum(2), vec![250, 250, 255]);
+}
+
+#[test]
+fn readahead() {
+    let mut it = (0..10).readahead(0);
+    assert_eq!(it.size_hint(), (10, Some(10)));
+    assert_eq!(it.next(), Some(0));
+    assert_eq!(it.size_hint(), (9, Some(9)));
+    it::assert_equal(it.by_ref(), 1..10);
+    assert_eq!(it.size_hint(), (0, Some(0)));
+    assert_eq!(it.next(), None);
+
+    // dropping the adaptor early stops the producer
+    let mut it = (0..).readahead(4);
+    assert_eq!(it.next(), Some(0));
+}
+
+#[test]
+#[should_panic(expected = "producer failed")]
+fn readahead_panic() {
+    let it = (0..10).map(|i| if i == 5 { panic!("producer failed") } else { i }).readahead(2);
+    it::assert_equal(it, 0..5);
+}
+
+#[test]
+fn map_parallel() {
+    use std::thread;
+    use std::time::Duration;
+
+    // later elements finish first, but come out in order
+    let it = (0..12u64).map_parallel(3, |i| {
+        thread::sleep(Duration::from_millis(12 - i));
+        i * 10
+    });
+    assert_eq!(it.size_hint(), (12, Some(12)));
+    it::assert_equal(it, (0..12).map(|i| i * 10));
+
+    let mut it = (0..).map_parallel(2, |i| i + 1);
+    assert_eq!(it.next(), Some(1));
+    assert_eq!(it.next(), Some(2));
+}
+
+#[test]
+#[should_panic(expected = "bad input")]
+fn map_parallel_panic() {
+    let it = (0..10).map_parallel(2, |i| if i == 7 { panic!("bad input") } else { i });
+    it.count();
+}
+
+#[test]
+#[cfg(all(feature = "rayon", feature = "use_std"))]
+fn par_fold_chunks() {
+    let digits = || (0..100).map(|i| i.to_string());
+    let expected = digits().collect::<String>();
+    for chunk_size in 1..12 {
+        let text = digits().par_fold_chunks(chunk_size, |chunk| chunk.concat(), |a, b| a + &b);
+        assert_eq!(text.unwrap(), expected);
+    }
+    assert_eq!((0..0).par_fold_chunks(4, |chunk| chunk.len(), |a, b| a + b), None);
+}
+
 #[test]
 fn sorted_by() {
     let sc = [3, 4, 1, 2].iter().cloned().sorted_by(|&a, &b| {
@@ -371,6 +724,141 @@ fn test_multipeek_peeking_next() {
     assert_eq!(mp.peek(), None);
 }
 
+#[test]
+fn test_peek_nth() {
+    let nums = vec![1u8,2,3,4,5];
+
+    let iter = peek_nth(nums.iter().map(|&x| x));
+    assert_eq!(nums, iter.collect::<Vec<_>>());
+
+    let mut iter = peek_nth(nums.iter().map(|&x| x));
+
+    assert_eq!(iter.peek_nth(0), Some(&1));
+    assert_eq!(iter.peek_nth(0), Some(&1));
+    assert_eq!(iter.next(), Some(1));
+
+    assert_eq!(iter.peek_nth(0), Some(&2));
+    assert_eq!(iter.peek_nth(1), Some(&3));
+    assert_eq!(iter.next(), Some(2));
+
+    assert_eq!(iter.peek_nth(0), Some(&3));
+    assert_eq!(iter.peek_nth(1), Some(&4));
+    assert_eq!(iter.peek_nth(2), Some(&5));
+    assert_eq!(iter.peek_nth(3), None);
+    assert_eq!(iter.len(), 3);
+
+    assert_eq!(iter.next(), Some(3));
+    assert_eq!(iter.next(), Some(4));
+
+    assert_eq!(iter.peek_nth(0), Some(&5));
+    assert_eq!(iter.peek_nth(1), None);
+    assert_eq!(iter.next(), Some(5));
+    assert_eq!(iter.next(), None);
+
+    assert_eq!(iter.peek_nth(0), None);
+    assert_eq!(iter.peek_nth(1), None);
+
+    let mut iter = peek_nth(nums.iter().map(|&x| x));
+    assert_eq!(iter.peek_nth(!0), None);
+    assert_eq!(iter.next(), Some(1));
+}
+
+#[test]
+fn test_peek_nth_peeking_next() {
+    use it::PeekingNext;
+    let nums = vec![1u8,2,3,4,5,6,7];
+    let mut iter = peek_nth(nums.iter().map(|&x| x));
+
+    assert_eq!(iter.peeking_next(|&x| x != 0), Some(1));
+    assert_eq!(iter.next(), Some(2));
+
+    assert_eq!(iter.peek_nth(0), Some(&3));
+    assert_eq!(iter.peek_nth(1), Some(&4));
+    assert_eq!(iter.peeking_next(|&x| x == 3), Some(3));
+    assert_eq!(iter.peek(), Some(&4));
+
+    assert_eq!(iter.peeking_next(|&x| x != 4), None);
+    assert_eq!(iter.peeking_next(|&x| x == 4), Some(4));
+    assert_eq!(iter.peek_nth(0), Some(&5));
+    assert_eq!(iter.peek_nth(1), Some(&6));
+
+    assert_eq!(iter.peeking_next(|&x| x != 5), None);
+    assert_eq!(iter.peek(), Some(&5));
+
+    assert_eq!(iter.peeking_next(|&x| x == 5), Some(5));
+    assert_eq!(iter.peeking_next(|&x| x == 6), Some(6));
+    assert_eq!(iter.peek_nth(0), Some(&7));
+    assert_eq!(iter.peek_nth(1), None);
+    assert_eq!(iter.next(), Some(7));
+    assert_eq!(iter.peek(), None);
+}
+
+#[test]
+fn test_peek_nth_next_if() {
+    let nums = vec![1u8, 2, 3, 4, 5, 6, 7];
+    let mut iter = peek_nth(nums.iter().map(|&x| x));
+
+    assert_eq!(iter.next_if(|&x| x != 0), Some(1));
+    assert_eq!(iter.next_if_eq(&2), Some(2));
+    assert_eq!(iter.next_if_eq(&4), None);
+    assert_eq!(iter.next_if(|&x| x % 2 == 0), None);
+
+    if let Some(x) = iter.peek_mut() {
+        *x = 40;
+    }
+    assert_eq!(iter.peek_nth(3), Some(&6));
+    assert_eq!(iter.next_if_eq(&40), Some(40));
+    assert_eq!(iter.next_if(|&x| x == 4), Some(4));
+    it::assert_equal(iter, vec![5, 6, 7]);
+}
+
+#[test]
+fn split_between() {
+    let xs: Vec<i32> = Vec::new();
+    assert_eq!(xs.into_iter().split_between(|_, _| true).next(), None);
+    it::assert_equal((0..3).split_between(|_, _| true), vec![vec![0], vec![1], vec![2]]);
+    it::assert_equal((0..3).split_between(|_, _| false), vec![vec![0, 1, 2]]);
+
+    let mut calls = Vec::new();
+    {
+        let chunks = "aabca".chars().split_between(|&a, &b| { calls.push((a, b)); a != b });
+        it::assert_equal(chunks, vec![vec!['a', 'a'], vec!['b'], vec!['c'], vec!['a']]);
+    }
+    assert_eq!(calls, vec![('a', 'a'), ('a', 'b'), ('b', 'c'), ('c', 'a')]);
+}
+
+#[test]
+fn split_on() {
+    let xs: Vec<i32> = Vec::new();
+    it::assert_equal(xs.into_iter().split_on(|_| true), vec![vec![]]);
+    it::assert_equal((0..2).split_on(|_| true), vec![vec![], vec![], vec![]]);
+    it::assert_equal((0..3).split_on(|_| false), vec![vec![0, 1, 2]]);
+
+    let mut it = (0..5).split_on(|&x| x == 2);
+    assert_eq!(it.next(), Some(vec![0, 1]));
+    assert_eq!(it.next(), Some(vec![3, 4]));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn multiunzip() {
+    let (a, b, c): (Vec<_>, Vec<_>, Vec<_>) = [(0, 1, 2), (3, 4, 5), (6, 7, 8)].iter().cloned().multiunzip();
+    assert_eq!((a, b, c), (vec![0, 3, 6], vec![1, 4, 7], vec![2, 5, 8]));
+
+    let (a,): (Vec<_>,) = vec![(1,), (2,)].into_iter().multiunzip();
+    assert_eq!(a, vec![1, 2]);
+
+    let (s, n): (String, Vec<_>) = it::multiunzip(vec![('a', 1), ('b', 2)]);
+    assert_eq!((s.as_str(), n), ("ab", vec![1, 2]));
+
+    let t: (Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>)
+        = [(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11), (12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23)]
+            .iter().cloned().multiunzip();
+    assert_eq!(t.0, vec![0, 12]);
+    assert_eq!(t.11, vec![11, 23]);
+}
+
 #[test]
 fn pad_using() {
     it::assert_equal((0..0).pad_using(1, |_| 1), 1..2);
@@ -384,6 +872,51 @@ fn pad_using() {
     it::assert_equal(r, vec![0, 1, 2]);
 }
 
+#[test]
+fn pad_front_using() {
+    it::assert_equal((0..0).pad_front_using(1, |_| 1), 1..2);
+    it::assert_equal((0..0).pad_front_using(0, |_| 1), 0..0);
+
+    let r = (0..3).pad_front_using(5, |n| n * 10);
+    it::assert_equal(r, vec![0, 10, 0, 1, 2]);
+
+    let r = (0..3).pad_front_using(1, |_| panic!());
+    it::assert_equal(r, vec![0, 1, 2]);
+
+    let mut r = (0..3).pad_front_using(5, |n| n * 10);
+    assert_eq!(r.len(), 5);
+    r.next();
+    assert_eq!(r.len(), 4);
+
+    // a clone resumes from the buffered elements
+    fn tens(n: usize) -> usize { n * 10 }
+    let mut r = (0..3).pad_front_using(5, tens);
+    r.next();
+    it::assert_equal(r.clone(), vec![10, 0, 1, 2]);
+    it::assert_equal(r, vec![10, 0, 1, 2]);
+}
+
+#[test]
+fn pad_both_using() {
+    it::assert_equal((0..0).pad_both_using(1, |_| 1), 1..2);
+    it::assert_equal((0..0).pad_both_using(2, |n| n), 0..2);
+
+    let r = (0..3).pad_both_using(4, |n| n * 10);
+    it::assert_equal(r, vec![0, 1, 2, 30]);
+
+    let r = (0..3).pad_both_using(7, |n| n * 10);
+    it::assert_equal(r, vec![0, 10, 0, 1, 2, 50, 60]);
+
+    let r = (0..3).pad_both_using(3, |_| panic!());
+    it::assert_equal(r, vec![0, 1, 2]);
+
+    fn tens(n: usize) -> usize { n * 10 }
+    let mut r = (0..3).pad_both_using(7, tens);
+    r.next();
+    it::assert_equal(r.clone(), vec![10, 0, 1, 2, 50, 60]);
+    assert!(format!("{:?}", r).starts_with("PadBothUsing { buffered: PadBuffered {"));
+}
+
 #[test]
 fn group_by() {
     for (ch1, sub) in &"AABBCCC".chars().group_by(|&x| x) {
@@ -607,6 +1140,47 @@ fn combinations_zero() {
     it::assert_equal((1..3).combinations(0), vec![vec![]]);
 }
 
+#[test]
+fn permutations() {
+    it::assert_equal((0..3).permutations(0), vec![vec![]]);
+    it::assert_equal((0..0).permutations(0), vec![vec![]]);
+    it::assert_equal((0..0).permutations(1), <Vec<Vec<_>>>::new());
+    it::assert_equal((0..2).permutations(3), <Vec<Vec<_>>>::new());
+    it::assert_equal((0..3).permutations(1), vec![vec![0], vec![1], vec![2]]);
+    it::assert_equal((0..3).permutations(3), vec![
+        vec![0, 1, 2], vec![0, 2, 1],
+        vec![1, 0, 2], vec![1, 2, 0],
+        vec![2, 0, 1], vec![2, 1, 0],
+    ]);
+
+    let mut infinite = (0..).permutations(2);
+    assert_eq!(infinite.next(), Some(vec![0, 1]));
+    assert_eq!(infinite.next(), Some(vec![0, 2]));
+    assert_eq!(infinite.next(), Some(vec![0, 3]));
+}
+
+#[test]
+fn powerset() {
+    it::assert_equal((0..0).powerset(), vec![vec![]]);
+    it::assert_equal((0..1).powerset(), vec![vec![], vec![0]]);
+    it::assert_equal((0..2).powerset(), vec![vec![], vec![0], vec![1], vec![0, 1]]);
+    it::assert_equal((0..3).powerset(), vec![
+        vec![],
+        vec![0], vec![1], vec![2],
+        vec![0, 1], vec![0, 2], vec![1, 2],
+        vec![0, 1, 2],
+    ]);
+
+    assert_eq!((0..4).powerset().count(), 1 << 4);
+    assert_eq!((0..8).powerset().count(), 1 << 8);
+    assert_eq!((0..16).powerset().count(), 1 << 16);
+
+    let mut infinite = (0..).powerset();
+    assert_eq!(infinite.next(), Some(vec![]));
+    assert_eq!(infinite.next(), Some(vec![0]));
+    assert_eq!(infinite.next(), Some(vec![1]));
+}
+
 #[test]
 fn combinations_with_replacement() {
     // Pool smaller than n
@@ -722,6 +1296,30 @@ fn minmax() {
     assert_eq!(max, &Val(0, 2));
 }
 
+#[test]
+fn minmax_result_methods() {
+    use it::MinMaxResult::{self, NoElements, OneElement, MinMax};
+
+    let none: MinMaxResult<i32> = NoElements;
+    assert_eq!((none.min(), none.max()), (None, None));
+    assert_eq!(none.map(|x| x * 2), NoElements);
+    assert_eq!(none.into_option(), None);
+
+    let one = OneElement(3);
+    assert_eq!((one.min(), one.max()), (Some(&3), Some(&3)));
+    assert_eq!(one.map(|x| x * 2), OneElement(6));
+    assert_eq!(one.as_ref(), OneElement(&3));
+    assert_eq!(one.into_option(), Some((3, 3)));
+
+    let both = (1..5).minmax();
+    assert_eq!((both.min(), both.max()), (Some(&1), Some(&4)));
+    assert_eq!(both.map(|x| x * 2), MinMax(2, 8));
+    let pair = both.into_option();
+    assert_eq!(pair, Some((1, 4)));
+    assert_eq!(MinMaxResult::from(pair), both);
+    assert_eq!(MinMaxResult::<i32>::from(None), NoElements);
+}
+
 #[test]
 fn format() {
     let data = [0, 1, 2, 3];
@@ -738,6 +1336,67 @@ fn format() {
     assert_eq!(t3, "1.10e0, 2.72e0, -2.20e1");
 }
 
+#[test]
+fn format_flags() {
+    let data = [1, 22, 333];
+    let t1 = format!("{:>4}", data.iter().format(","));
+    assert_eq!(t1, "   1,  22, 333");
+    let t2 = format!("{:<4}", data.iter().format(","));
+    assert_eq!(t2, "1   ,22  ,333 ");
+    let t3 = format!("{:+05}", data.iter().format(" "));
+    assert_eq!(t3, "+0001 +0022 +0333");
+    let t4 = format!("{:#x}", data.iter().format(" "));
+    assert_eq!(t4, "0x1 0x16 0x14d");
+
+    let dataf = [1.5, -0.25];
+    let t5 = format!("{:>8.2}", dataf.iter().format_with("", |elt, f| f(elt)));
+    assert_eq!(t5, "    1.50   -0.25");
+    let t6 = format!("{:>8.2}", dataf.iter().format_with(",", |elt, f| {
+        f(&format_args!("{}", elt))
+    }));
+    assert_eq!(t6, "1.5,-0.25");
+}
+
+#[test]
+fn join_debug() {
+    let data = vec![Some("a"), None, Some("c")];
+    assert_eq!(data.iter().join_debug(", "), r#"Some("a"), None, Some("c")"#);
+    assert_eq!(data[..0].iter().join_debug(", "), "");
+    assert_eq!(data.iter().join_debug(""), data.iter().map(|x| format!("{:?}", x)).join(""));
+}
+
+#[test]
+fn format_debug() {
+    let data = vec![vec![1, 2], vec![], vec![3]];
+    let t1 = format!("{}", data.iter().format_debug("; "));
+    assert_eq!(t1, "[1, 2]; []; [3]");
+    let t2 = format!("{}", data.iter().format_debug("; "));
+    assert_eq!(t2, data.iter().join_debug("; "));
+
+    let t3 = format!("{:>4}", [1, 22].iter().format_debug(","));
+    assert_eq!(t3, "   1,  22");
+}
+
+#[test]
+fn format_truncated() {
+    let data = [0, 1, 2, 3, 4];
+
+    let t1 = format!("{}", data.iter().format_truncated(", ", 2));
+    assert_eq!(t1, "0, 1, ... (3 more)");
+    let t2 = format!("{}", data.iter().format_truncated(", ", 5));
+    assert_eq!(t2, "0, 1, 2, 3, 4");
+    let t3 = format!("{}", data.iter().format_truncated(", ", 10));
+    assert_eq!(t3, "0, 1, 2, 3, 4");
+    let t4 = format!("{}", data.iter().format_truncated(", ", 0));
+    assert_eq!(t4, "... (5 more)");
+    let t5 = format!("{}", data[..0].iter().format_truncated(", ", 0));
+    assert_eq!(t5, "");
+
+    let dataf = [1.1, 2.71828, -22.];
+    let t6 = format!("{:.2}", dataf.iter().format_truncated("|", 2));
+    assert_eq!(t6, "1.10|2.72|... (1 more)");
+}
+
 #[test]
 fn while_some() {
     let ns = (1..10).map(|x| if x % 5 != 0 { Some(x) } else { None })
@@ -791,3 +1450,63 @@ fn tree_fold1() {
         assert_eq!(actual, expected);
     }
 }
+
+#[test]
+fn grouping_map_join() {
+    let lookup = vec![(1, ""), (2, "abc"), (1, "a"), (2, "de"), (2, "f")].into_iter()
+        .into_grouping_map()
+        .join(", ");
+    assert_eq!(lookup[&1], ", a");
+    assert_eq!(lookup[&2], "abc, de, f");
+    // the separators and elements as long as the first one fit
+    assert!(lookup[&2].capacity() >= 3 * 3 + 2 * 2);
+}
+
+#[test]
+fn grouping_map_partition() {
+    let (even, odd) = (0..10).map(|n| (n % 3, n))
+        .into_grouping_map()
+        .with_capacity(64)
+        .partition::<Vec<_>, _>(|_, &n| n % 2 == 0);
+    assert_eq!(even[&0], vec![0, 6]);
+    assert_eq!(even[&1], vec![4]);
+    assert_eq!(even[&2], vec![2, 8]);
+    assert_eq!(odd[&0], vec![3, 9]);
+    assert_eq!(odd[&1], vec![1, 7]);
+    assert_eq!(odd[&2], vec![5]);
+    assert!(even.capacity() >= 64);
+    assert!(odd.capacity() >= 64);
+
+    // keys that only ever fail the predicate are absent from the left map
+    let (small, large) = vec![(1, 1), (2, 20), (2, 30)].into_iter()
+        .into_grouping_map()
+        .partition::<Vec<_>, _>(|_, &n| n < 10);
+    assert_eq!(small.len(), 1);
+    assert_eq!(large.len(), 1);
+    assert_eq!(large[&2], vec![20, 30]);
+}
+
+#[cfg(all(feature = "rand", feature = "use_std"))]
+#[test]
+fn grouping_map_sample() {
+    use rand::SeedableRng;
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+    let lookup = (0..100).map(|n| (n % 10 < 2, n)).into_grouping_map().sample(8, &mut rng);
+    assert_eq!(lookup[&true].len(), 8);
+    assert_eq!(lookup[&false].len(), 8);
+    assert!(lookup[&true].iter().all(|n| n % 10 < 2));
+
+    // small groups are kept whole
+    let lookup = (0..10).map(|n| (n % 2, n)).into_grouping_map().sample(5, &mut rng);
+    assert_eq!(lookup[&0], vec![0, 2, 4, 6, 8]);
+
+    // each element of a group is sampled with the same probability
+    let mut counts = [0; 10];
+    for _ in 0..2000 {
+        for n in (0..10).map(|n| ((), n)).into_grouping_map().sample(3, &mut rng).remove(&()).unwrap() {
+            counts[n] += 1;
+        }
+    }
+    assert!(counts.iter().all(|&c| 500 < c && c < 700), "{:?}", counts);
+}
@@ -0,0 +1,31 @@
+//! Checks of the `TrustedLen` implementations enabled by the `nightly` feature.
+#![cfg(feature = "nightly")]
+#![feature(trusted_len)]
+
+extern crate itertools as it;
+
+use std::iter::TrustedLen;
+use it::Itertools;
+
+fn trusted_len<I: TrustedLen>(it: I) -> Vec<I::Item> {
+    let v: Vec<_> = it.collect();
+    assert_eq!(v.len(), v.capacity());
+    v
+}
+
+#[test]
+fn trusted_len_adaptors() {
+    assert_eq!(trusted_len(it::multizip((0..3, 3..6, vec![6, 7, 8]))),
+               vec![(0, 3, 6), (1, 4, 7), (2, 5, 8)]);
+    assert_eq!(trusted_len((0..3).zip_eq(3..6)), vec![(0, 3), (1, 4), (2, 5)]);
+    assert_eq!(trusted_len((0..2).interleave(5..8)), vec![0, 5, 1, 6, 7]);
+    assert_eq!(trusted_len((0..3u8).map_into::<u32>()), vec![0, 1, 2]);
+    assert_eq!(trusted_len(vec![Ok(1), Err(())].into_iter().map_results(|x| x + 1)),
+               vec![Ok(2), Err(())]);
+    assert_eq!(trusted_len((0..2).update(|x| *x += 1)), vec![1, 2]);
+    assert_eq!(trusted_len(it::repeat_n('a', 3)), vec!['a'; 3]);
+    assert_eq!(trusted_len((0..2).pad_using(3, |_| 9)), vec![0, 1, 9]);
+    assert_eq!(trusted_len((0..2).with_position().map(|p| p.into_inner())), vec![0, 1]);
+    assert_eq!(trusted_len((0..2).zip(0..2).zip(0..2).cons_tuples()),
+               vec![(0, 0, 0), (1, 1, 1)]);
+}
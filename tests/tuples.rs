@@ -63,6 +63,122 @@ fn tuple_windows() {
     assert_eq!(None, iter.next());
 }
 
+#[test]
+fn array_chunks() {
+    let v = [1, 2, 3, 4, 5];
+
+    let mut iter = v.iter().cloned().array_chunks::<[_; 1]>();
+    assert_eq!(Some([1]), iter.next());
+    assert_eq!(Some([2]), iter.next());
+
+    let mut iter = v.iter().cloned().array_chunks::<[_; 2]>();
+    assert_eq!(iter.remainder(), &[]);
+    assert_eq!(Some([1, 2]), iter.next());
+    assert_eq!(Some([3, 4]), iter.next());
+    assert_eq!(None, iter.next());
+    assert_eq!(iter.remainder(), &[5]);
+    assert_eq!(None, iter.next());
+    assert_eq!(iter.remainder(), &[5]);
+
+    let mut iter = v.iter().cloned().array_chunks::<[_; 5]>();
+    assert_eq!(Some([1, 2, 3, 4, 5]), iter.next());
+    assert_eq!(None, iter.next());
+    assert_eq!(iter.remainder(), &[]);
+
+    let mut iter = (0..40).array_chunks::<[_; 16]>();
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+    assert_eq!(iter.next().map(|chunk| chunk[15]), Some(15));
+    assert_eq!(iter.next().map(|chunk| chunk[15]), Some(31));
+    assert_eq!(None, iter.next());
+    assert_eq!(iter.remainder(), &[32, 33, 34, 35, 36, 37, 38, 39]);
+
+    // the elements don't need to implement `Default`
+    let mut v = [1, 2, 3, 4, 5];
+    {
+        let mut iter = v.iter_mut().array_chunks::<[_; 2]>();
+        for mut chunk in iter.by_ref() {
+            *chunk[1] += *chunk[0];
+        }
+        assert_eq!(iter.remainder().len(), 1);
+    }
+    assert_eq!(v, [1, 3, 3, 7, 5]);
+}
+
+#[test]
+#[should_panic]
+fn array_chunks_empty() {
+    (0..5).array_chunks::<[_; 0]>().next();
+}
+
+#[test]
+fn array_windows() {
+    let v = [1, 2, 3, 4, 5];
+
+    let iter = v.iter().cloned().array_windows::<[_; 1]>();
+    itertools::assert_equal(iter, vec![[1], [2], [3], [4], [5]]);
+
+    let mut iter = v.iter().cloned().array_windows::<[_; 2]>();
+    assert_eq!(iter.len(), 4);
+    assert_eq!(Some([1, 2]), iter.next());
+    assert_eq!(Some([2, 3]), iter.next());
+    assert_eq!(Some([3, 4]), iter.next());
+    assert_eq!(Some([4, 5]), iter.next());
+    assert_eq!(None, iter.next());
+
+    let iter = v.iter().cloned().array_windows::<[_; 5]>();
+    itertools::assert_equal(iter, vec![[1, 2, 3, 4, 5]]);
+
+    let mut iter = v.iter().cloned().array_windows::<[_; 6]>();
+    assert_eq!(iter.len(), 0);
+    assert_eq!(None, iter.next());
+
+    let mut iter = (0..20).array_windows::<[_; 16]>();
+    assert_eq!(iter.next().map(|window| window[15]), Some(15));
+    assert_eq!(iter.next().map(|window| window[0]), Some(1));
+    assert_eq!(iter.count(), 3);
+
+    // the elements don't need to implement `Default`
+    let v = [1, 2, 3];
+    let iter = v.iter().array_windows::<[&i32; 2]>();
+    itertools::assert_equal(iter, vec![[&1, &2], [&2, &3]]);
+}
+
+#[test]
+#[should_panic]
+fn array_windows_empty() {
+    (0..5).array_windows::<[_; 0]>().next();
+}
+
+#[test]
+fn circular_tuple_windows() {
+    let v = [1, 2, 3, 4, 5];
+
+    let mut iter = v.iter().cloned().circular_tuple_windows();
+    assert_eq!(Some((1,)), iter.next());
+    assert_eq!(Some((2,)), iter.next());
+    assert_eq!(Some((3,)), iter.next());
+
+    let iter = v.iter().cloned().circular_tuple_windows::<(_, _)>();
+    itertools::assert_equal(iter, vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 1)]);
+
+    let iter = v.iter().cloned().circular_tuple_windows::<(_, _, _, _)>();
+    assert_eq!(iter.len(), 5);
+    itertools::assert_equal(iter, vec![(1, 2, 3, 4), (2, 3, 4, 5), (3, 4, 5, 1),
+                                       (4, 5, 1, 2), (5, 1, 2, 3)]);
+
+    let v = [1, 2];
+    let iter = v.iter().cloned().circular_tuple_windows::<(_, _, _, _)>();
+    itertools::assert_equal(iter, vec![(1, 2, 1, 2), (2, 1, 2, 1)]);
+
+    let v = [1];
+    let iter = v.iter().cloned().circular_tuple_windows::<(_, _)>();
+    itertools::assert_equal(iter, vec![(1, 1)]);
+
+    let v: [i32; 0] = [];
+    let mut iter = v.iter().cloned().circular_tuple_windows::<(_, _)>();
+    assert_eq!(None, iter.next());
+}
+
 #[test]
 fn next_tuple() {
     let v = [1, 2, 3, 4, 5];
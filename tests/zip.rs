@@ -1,8 +1,8 @@
 extern crate itertools;
 
-use itertools::Itertools;
+use itertools::{Either, Itertools};
 use itertools::EitherOrBoth::{Both, Left, Right};
-use itertools::free::zip_eq;
+use itertools::free::{try_zip_eq, zip_eq};
 
 #[test]
 fn zip_longest_fused() {
@@ -63,3 +63,22 @@ fn zip_eq_panic2()
     zip_eq(&a, &b).count();
 }
 
+
+#[test]
+fn try_zip_eq_lengths() {
+    let a = [1, 2];
+    let b = [1, 2, 3];
+
+    let mut it = a.iter().try_zip_eq(&b);
+    assert_eq!(it.next(), Some(Ok((&1, &1))));
+    assert_eq!(it.next(), Some(Ok((&2, &2))));
+    let err = it.next().unwrap().unwrap_err();
+    assert_eq!((err.len, err.extra), (2, Either::Right(&3)));
+    assert_eq!(it.next(), None);
+
+    let a: [i32; 0] = [];
+    let err = try_zip_eq(&b, &a).next().unwrap().unwrap_err();
+    assert_eq!((err.len, err.extra), (0, Either::Left(&1)));
+
+    itertools::assert_equal(try_zip_eq(&a, &a), Vec::new());
+}